@@ -50,6 +50,10 @@ pub enum Relation {
     ArtistMembershipHistory,
     #[sea_orm(has_many = "super::artist_tag_vote::Entity")]
     ArtistTagVote,
+    #[sea_orm(has_many = "super::event_circle::Entity")]
+    EventCircle,
+    #[sea_orm(has_many = "super::event_circle_history::Entity")]
+    EventCircleHistory,
     #[sea_orm(has_many = "super::label_founder::Entity")]
     LabelFounder,
     #[sea_orm(has_many = "super::label_founder_history::Entity")]
@@ -118,6 +122,18 @@ impl Related<super::artist_tag_vote::Entity> for Entity {
     }
 }
 
+impl Related<super::event_circle::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventCircle.def()
+    }
+}
+
+impl Related<super::event_circle_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventCircleHistory.def()
+    }
+}
+
 impl Related<super::label_founder::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LabelFounder.def()
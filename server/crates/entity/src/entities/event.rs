@@ -28,16 +28,32 @@ pub struct Model {
     pub location_province: Option<String>,
     #[sea_orm(column_type = "Text", nullable)]
     pub location_city: Option<String>,
+    pub series_id: Option<i32>,
+    pub series_ordinal: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::event_alternative_name::Entity")]
     EventAlternativeName,
+    #[sea_orm(has_many = "super::event_circle::Entity")]
+    EventCircle,
+    #[sea_orm(has_many = "super::event_link::Entity")]
+    EventLink,
+    #[sea_orm(has_many = "super::event_tag_vote::Entity")]
+    EventTagVote,
     #[sea_orm(has_many = "super::release_event::Entity")]
     ReleaseEvent,
     #[sea_orm(has_many = "super::release_event_history::Entity")]
     ReleaseEventHistory,
+    #[sea_orm(
+        belongs_to = "super::event_series::Entity",
+        from = "Column::SeriesId",
+        to = "super::event_series::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    EventSeries,
 }
 
 impl Related<super::event_alternative_name::Entity> for Entity {
@@ -46,6 +62,24 @@ impl Related<super::event_alternative_name::Entity> for Entity {
     }
 }
 
+impl Related<super::event_circle::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventCircle.def()
+    }
+}
+
+impl Related<super::event_link::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventLink.def()
+    }
+}
+
+impl Related<super::event_tag_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventTagVote.def()
+    }
+}
+
 impl Related<super::release_event::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::ReleaseEvent.def()
@@ -76,4 +110,10 @@ impl Related<super::release_history::Entity> for Entity {
     }
 }
 
+impl Related<super::event_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventSeries.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
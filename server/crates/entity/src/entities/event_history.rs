@@ -28,12 +28,26 @@ pub struct Model {
     pub location_province: Option<String>,
     #[sea_orm(column_type = "Text", nullable)]
     pub location_city: Option<String>,
+    pub series_id: Option<i32>,
+    pub series_ordinal: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(has_many = "super::event_alternative_name_history::Entity")]
     EventAlternativeNameHistory,
+    #[sea_orm(has_many = "super::event_circle_history::Entity")]
+    EventCircleHistory,
+    #[sea_orm(has_many = "super::event_link_history::Entity")]
+    EventLinkHistory,
+    #[sea_orm(
+        belongs_to = "super::event_series::Entity",
+        from = "Column::SeriesId",
+        to = "super::event_series::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    EventSeries,
 }
 
 impl Related<super::event_alternative_name_history::Entity> for Entity {
@@ -42,4 +56,22 @@ impl Related<super::event_alternative_name_history::Entity> for Entity {
     }
 }
 
+impl Related<super::event_circle_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventCircleHistory.def()
+    }
+}
+
+impl Related<super::event_link_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventLinkHistory.def()
+    }
+}
+
+impl Related<super::event_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventSeries.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
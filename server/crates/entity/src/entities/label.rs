@@ -24,8 +24,14 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::label_founder::Entity")]
     LabelFounder,
+    #[sea_orm(has_many = "super::label_link::Entity")]
+    LabelLink,
     #[sea_orm(has_many = "super::label_localized_name::Entity")]
     LabelLocalizedName,
+    #[sea_orm(has_many = "super::label_relation_history::Entity")]
+    LabelRelationHistory,
+    #[sea_orm(has_many = "super::label_tag_vote::Entity")]
+    LabelTagVote,
     #[sea_orm(has_many = "super::release_catalog_number::Entity")]
     ReleaseCatalogNumber,
     #[sea_orm(has_many = "super::release_catalog_number_history::Entity")]
@@ -38,12 +44,30 @@ impl Related<super::label_founder::Entity> for Entity {
     }
 }
 
+impl Related<super::label_link::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LabelLink.def()
+    }
+}
+
 impl Related<super::label_localized_name::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LabelLocalizedName.def()
     }
 }
 
+impl Related<super::label_relation_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LabelRelationHistory.def()
+    }
+}
+
+impl Related<super::label_tag_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LabelTagVote.def()
+    }
+}
+
 impl Related<super::release_catalog_number::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::ReleaseCatalogNumber.def()
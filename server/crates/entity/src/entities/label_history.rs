@@ -24,8 +24,12 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::label_founder_history::Entity")]
     LabelFounderHistory,
+    #[sea_orm(has_many = "super::label_link_history::Entity")]
+    LabelLinkHistory,
     #[sea_orm(has_many = "super::label_localized_name_history::Entity")]
     LabelLocalizedNameHistory,
+    #[sea_orm(has_many = "super::label_relation_history::Entity")]
+    LabelRelationHistory,
 }
 
 impl Related<super::label_founder_history::Entity> for Entity {
@@ -34,12 +38,24 @@ impl Related<super::label_founder_history::Entity> for Entity {
     }
 }
 
+impl Related<super::label_link_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LabelLinkHistory.def()
+    }
+}
+
 impl Related<super::label_localized_name_history::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::LabelLocalizedNameHistory.def()
     }
 }
 
+impl Related<super::label_relation_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LabelRelationHistory.def()
+    }
+}
+
 impl Related<super::artist::Entity> for Entity {
     fn to() -> RelationDef {
         super::label_founder_history::Relation::Artist.def()
@@ -29,15 +29,29 @@ pub mod credit_role_inheritance_history;
 pub mod event;
 pub mod event_alternative_name;
 pub mod event_alternative_name_history;
+pub mod event_circle;
+pub mod event_circle_history;
 pub mod event_history;
+pub mod event_link;
+pub mod event_link_history;
+pub mod event_series;
+pub mod event_series_history;
+pub mod event_tag_vote;
+pub mod external_id;
+pub mod external_id_history;
 pub mod image;
 pub mod image_queue;
 pub mod label;
 pub mod label_founder;
 pub mod label_founder_history;
 pub mod label_history;
+pub mod label_link;
+pub mod label_link_history;
 pub mod label_localized_name;
 pub mod label_localized_name_history;
+pub mod label_relation;
+pub mod label_relation_history;
+pub mod label_tag_vote;
 pub mod language;
 pub mod release;
 pub mod release_artist;
@@ -50,6 +64,10 @@ pub mod release_disc;
 pub mod release_disc_history;
 pub mod release_event;
 pub mod release_event_history;
+pub mod release_group;
+pub mod release_group_history;
+pub mod release_group_release;
+pub mod release_group_release_history;
 pub mod release_history;
 pub mod release_image;
 pub mod release_image_queue;
@@ -75,7 +93,13 @@ pub mod song_localized_title_history;
 pub mod song_lyrics;
 pub mod song_lyrics_history;
 pub mod song_relation;
+pub mod song_source_track;
+pub mod song_source_track_history;
 pub mod song_tag_vote;
+pub mod source_work;
+pub mod source_work_history;
+pub mod source_work_track;
+pub mod source_work_track_history;
 pub mod tag;
 pub mod tag_alternative_name;
 pub mod tag_alternative_name_history;
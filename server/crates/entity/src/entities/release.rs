@@ -35,6 +35,10 @@ pub enum Relation {
     ReleaseDisc,
     #[sea_orm(has_many = "super::release_event::Entity")]
     ReleaseEvent,
+    #[sea_orm(has_many = "super::release_group_release::Entity")]
+    ReleaseGroupRelease,
+    #[sea_orm(has_many = "super::release_group_release_history::Entity")]
+    ReleaseGroupReleaseHistory,
     #[sea_orm(has_many = "super::release_image::Entity")]
     ReleaseImage,
     #[sea_orm(has_many = "super::release_image_queue::Entity")]
@@ -77,6 +81,18 @@ impl Related<super::release_event::Entity> for Entity {
     }
 }
 
+impl Related<super::release_group_release::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReleaseGroupRelease.def()
+    }
+}
+
+impl Related<super::release_group_release_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReleaseGroupReleaseHistory.def()
+    }
+}
+
 impl Related<super::release_image::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::ReleaseImage.def()
@@ -12,6 +12,11 @@ pub struct Model {
     pub release_id: i32,
     #[sea_orm(primary_key, auto_increment = false)]
     pub event_id: i32,
+    pub booth_day: Option<i16>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub booth_hall: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub booth_space: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -254,6 +254,42 @@ pub enum EntityType {
     SongLyrics,
     #[sea_orm(string_value = "CreditRole")]
     CreditRole,
+    #[sea_orm(string_value = "SourceWork")]
+    SourceWork,
+    #[sea_orm(string_value = "ReleaseGroup")]
+    ReleaseGroup,
+    #[sea_orm(string_value = "EventSeries")]
+    EventSeries,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ExternalIdSource")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+#[serde(rename_all = "lowercase")]
+pub enum ExternalIdSource {
+    #[sea_orm(string_value = "MusicBrainz")]
+    MusicBrainz,
+    #[sea_orm(string_value = "VGMdb")]
+    Vgmdb,
+    #[sea_orm(string_value = "TouhouWiki")]
+    TouhouWiki,
+    #[sea_orm(string_value = "Wikidata")]
+    Wikidata,
+    #[sea_orm(string_value = "Discogs")]
+    Discogs,
 }
 #[derive(
     Debug,
@@ -296,6 +332,68 @@ pub enum ImageQueueStatus {
     enumset :: EnumSetType,
     utoipa :: ToSchema,
 )]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "LabelRelationType"
+)]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum LabelRelationType {
+    #[sea_orm(string_value = "Parent")]
+    Parent,
+    #[sea_orm(string_value = "Imprint")]
+    Imprint,
+    #[sea_orm(string_value = "Distributor")]
+    Distributor,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "ReleaseEditionType"
+)]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum ReleaseEditionType {
+    #[sea_orm(string_value = "Original")]
+    Original,
+    #[sea_orm(string_value = "Reissue")]
+    Reissue,
+    #[sea_orm(string_value = "Remaster")]
+    Remaster,
+    #[sea_orm(string_value = "Digital")]
+    Digital,
+    #[sea_orm(string_value = "Limited")]
+    Limited,
+    #[sea_orm(string_value = "Other")]
+    Other,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "ReleaseType")]
 #[enumset(no_super_impls)]
 #[enumset(serialize_repr = "list")]
@@ -326,6 +424,32 @@ pub enum ReleaseType {
     enumset :: EnumSetType,
     utoipa :: ToSchema,
 )]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "SourceWorkKind")]
+#[enumset(no_super_impls)]
+#[enumset(serialize_repr = "list")]
+pub enum SourceWorkKind {
+    #[sea_orm(string_value = "Game")]
+    Game,
+    #[sea_orm(string_value = "MusicCd")]
+    MusicCd,
+    #[sea_orm(string_value = "Book")]
+    Book,
+    #[sea_orm(string_value = "Other")]
+    Other,
+}
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    Copy,
+    enumset :: EnumSetType,
+    utoipa :: ToSchema,
+)]
 #[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "TagRelationType")]
 #[enumset(no_super_impls)]
 #[enumset(serialize_repr = "list")]
@@ -28,10 +28,12 @@ pub enum Relation {
     SongLanguage,
     #[sea_orm(has_many = "super::song_localized_title::Entity")]
     SongLocalizedTitle,
-    #[sea_orm(has_one = "super::song_lyrics::Entity")]
+    #[sea_orm(has_many = "super::song_lyrics::Entity")]
     SongLyrics,
     #[sea_orm(has_many = "super::song_lyrics_history::Entity")]
     SongLyricsHistory,
+    #[sea_orm(has_many = "super::song_source_track::Entity")]
+    SongSourceTrack,
     #[sea_orm(has_many = "super::song_tag_vote::Entity")]
     SongTagVote,
 }
@@ -84,6 +86,12 @@ impl Related<super::song_lyrics_history::Entity> for Entity {
     }
 }
 
+impl Related<super::song_source_track::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SongSourceTrack.def()
+    }
+}
+
 impl Related<super::song_tag_vote::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::SongTagVote.def()
@@ -108,4 +116,13 @@ impl Related<super::language::Entity> for Entity {
     }
 }
 
+impl Related<super::source_work_track::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::song_source_track::Relation::SourceWorkTrack.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::song_source_track::Relation::Song.def().rev())
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -24,6 +24,8 @@ pub enum Relation {
     SongLanguageHistory,
     #[sea_orm(has_many = "super::song_localized_title_history::Entity")]
     SongLocalizedTitleHistory,
+    #[sea_orm(has_many = "super::song_source_track_history::Entity")]
+    SongSourceTrackHistory,
 }
 
 impl Related<super::song_artist_history::Entity> for Entity {
@@ -50,6 +52,12 @@ impl Related<super::song_localized_title_history::Entity> for Entity {
     }
 }
 
+impl Related<super::song_source_track_history::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SongSourceTrackHistory.def()
+    }
+}
+
 impl Related<super::artist::Entity> for Entity {
     fn to() -> RelationDef {
         super::song_artist_history::Relation::Artist.def()
@@ -25,6 +25,10 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::artist_tag_vote::Entity")]
     ArtistTagVote,
+    #[sea_orm(has_many = "super::event_tag_vote::Entity")]
+    EventTagVote,
+    #[sea_orm(has_many = "super::label_tag_vote::Entity")]
+    LabelTagVote,
     #[sea_orm(has_many = "super::release_tag_vote::Entity")]
     ReleaseTagVote,
     #[sea_orm(has_many = "super::song_tag_vote::Entity")]
@@ -41,6 +45,18 @@ impl Related<super::artist_tag_vote::Entity> for Entity {
     }
 }
 
+impl Related<super::event_tag_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventTagVote.def()
+    }
+}
+
+impl Related<super::label_tag_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LabelTagVote.def()
+    }
+}
+
 impl Related<super::release_tag_vote::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::ReleaseTagVote.def()
@@ -31,6 +31,8 @@ pub enum Relation {
     CorrectionRevision,
     #[sea_orm(has_many = "super::correction_user::Entity")]
     CorrectionUser,
+    #[sea_orm(has_many = "super::event_tag_vote::Entity")]
+    EventTagVote,
     #[sea_orm(
         belongs_to = "super::image::Entity",
         from = "Column::AvatarId",
@@ -47,6 +49,8 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Image1,
+    #[sea_orm(has_many = "super::label_tag_vote::Entity")]
+    LabelTagVote,
     #[sea_orm(has_many = "super::release_tag_vote::Entity")]
     ReleaseTagVote,
     #[sea_orm(has_many = "super::song_tag_vote::Entity")]
@@ -81,6 +85,18 @@ impl Related<super::correction_user::Entity> for Entity {
     }
 }
 
+impl Related<super::event_tag_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::EventTagVote.def()
+    }
+}
+
+impl Related<super::label_tag_vote::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::LabelTagVote.def()
+    }
+}
+
 impl Related<super::release_tag_vote::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::ReleaseTagVote.def()
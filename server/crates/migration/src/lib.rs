@@ -45,6 +45,16 @@ migrations![
     m20250928_235900_add_event_location,
     m20251001_090000_fix_event_validate_date,
     m20251202_000000_create_tag_vote,
+    m20251210_000000_create_autocomplete_index,
+    m20251215_000000_create_description_search,
+    m20251220_000000_create_label_event_tag_vote,
+    m20251225_000000_create_label_event_link,
+    m20251230_000000_create_source_work,
+    m20260105_000000_create_release_group,
+    m20260112_000000_add_event_booth,
+    m20260118_000000_create_event_series,
+    m20260124_000000_create_label_relation,
+    m20260131_000000_create_external_id,
 ];
 
 macro_rules! migration {
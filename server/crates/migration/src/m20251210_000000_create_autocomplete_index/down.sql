@@ -0,0 +1,51 @@
+DROP INDEX IF EXISTS idx_artist_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_artist_localized_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_event_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_event_alternative_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_label_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_label_localized_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_release_title_lower_prefix;
+
+DROP INDEX IF EXISTS idx_release_localized_title_lower_prefix;
+
+DROP INDEX IF EXISTS idx_song_title_lower_prefix;
+
+DROP INDEX IF EXISTS idx_song_localized_title_lower_prefix;
+
+DROP INDEX IF EXISTS idx_tag_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_tag_alternative_name_lower_prefix;
+
+DROP INDEX IF EXISTS idx_artist_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_artist_localized_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_event_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_event_alternative_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_label_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_label_localized_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_release_title_lower_trgm;
+
+DROP INDEX IF EXISTS idx_release_localized_title_lower_trgm;
+
+DROP INDEX IF EXISTS idx_song_title_lower_trgm;
+
+DROP INDEX IF EXISTS idx_song_localized_title_lower_trgm;
+
+DROP INDEX IF EXISTS idx_tag_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_tag_alternative_name_lower_trgm;
+
+DROP INDEX IF EXISTS idx_artist_text_alias_lower_trgm;
+
+DROP FUNCTION IF EXISTS artist_text_alias_search(TEXT[]);
@@ -0,0 +1 @@
+crate::migration!(m20251210_000000_create_autocomplete_index);
@@ -0,0 +1,58 @@
+-- Prefix indexes for autocomplete, `text_pattern_ops` allows `LIKE 'foo%'` to use the btree
+CREATE INDEX IF NOT EXISTS idx_artist_name_lower_prefix ON "public"."artist" (lower("name") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_artist_localized_name_lower_prefix ON "public"."artist_localized_name" (lower("name") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_event_name_lower_prefix ON "public"."event" (lower("name") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_event_alternative_name_lower_prefix ON "public"."event_alternative_name" (lower("name") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_label_name_lower_prefix ON "public"."label" (lower("name") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_label_localized_name_lower_prefix ON "public"."label_localized_name" (lower("name") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_release_title_lower_prefix ON "public"."release" (lower("title") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_release_localized_title_lower_prefix ON "public"."release_localized_title" (lower("title") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_song_title_lower_prefix ON "public"."song" (lower("title") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_song_localized_title_lower_prefix ON "public"."song_localized_title" (lower("title") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_tag_name_lower_prefix ON "public"."tag" (lower("name") text_pattern_ops);
+
+CREATE INDEX IF NOT EXISTS idx_tag_alternative_name_lower_prefix ON "public"."tag_alternative_name" (lower("name") text_pattern_ops);
+
+-- Trigram indexes on the lowered names, used as fuzzy fallback
+CREATE INDEX IF NOT EXISTS idx_artist_name_lower_trgm ON "public"."artist" USING gin (lower("name") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_artist_localized_name_lower_trgm ON "public"."artist_localized_name" USING gin (lower("name") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_event_name_lower_trgm ON "public"."event" USING gin (lower("name") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_event_alternative_name_lower_trgm ON "public"."event_alternative_name" USING gin (lower("name") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_label_name_lower_trgm ON "public"."label" USING gin (lower("name") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_label_localized_name_lower_trgm ON "public"."label_localized_name" USING gin (lower("name") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_release_title_lower_trgm ON "public"."release" USING gin (lower("title") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_release_localized_title_lower_trgm ON "public"."release_localized_title" USING gin (lower("title") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_song_title_lower_trgm ON "public"."song" USING gin (lower("title") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_song_localized_title_lower_trgm ON "public"."song_localized_title" USING gin (lower("title") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_tag_name_lower_trgm ON "public"."tag" USING gin (lower("name") gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS idx_tag_alternative_name_lower_trgm ON "public"."tag_alternative_name" USING gin (lower("name") gin_trgm_ops);
+
+-- Artist text aliases are stored as an array, which can't be trigram indexed
+-- directly. Flatten them into one lowered string so the alias lookup can be
+-- pre-filtered by the index before unnesting.
+CREATE OR REPLACE FUNCTION artist_text_alias_search(arr TEXT[]) RETURNS TEXT
+  LANGUAGE sql IMMUTABLE PARALLEL SAFE
+  AS $$ SELECT lower(array_to_string(arr, E'\n')) $$;
+
+CREATE INDEX IF NOT EXISTS idx_artist_text_alias_lower_trgm ON "public"."artist" USING gin (artist_text_alias_search("text_alias") gin_trgm_ops);
@@ -0,0 +1,11 @@
+ALTER TABLE "public"."tag" DROP COLUMN IF EXISTS "search_vector";
+
+ALTER TABLE "public"."event" DROP COLUMN IF EXISTS "search_vector";
+
+DROP FUNCTION IF EXISTS thcdb_to_tsquery(TEXT);
+
+DROP FUNCTION IF EXISTS thcdb_to_tsvector(TEXT);
+
+DROP FUNCTION IF EXISTS thcdb_cjk_segment(TEXT);
+
+DROP TEXT SEARCH CONFIGURATION IF EXISTS thcdb_multilingual;
@@ -0,0 +1 @@
+crate::migration!(m20251215_000000_create_description_search);
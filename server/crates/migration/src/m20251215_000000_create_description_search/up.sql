@@ -0,0 +1,56 @@
+CREATE EXTENSION IF NOT EXISTS unaccent;
+
+-- Descriptions are written in many languages, so no stemming is done.
+-- Words are only lowercased and stripped of accents.
+CREATE TEXT SEARCH CONFIGURATION thcdb_multilingual (COPY = simple);
+
+ALTER TEXT SEARCH CONFIGURATION thcdb_multilingual
+  ALTER MAPPING FOR hword, hword_part, word WITH unaccent, simple;
+
+-- The default parser treats a run of CJK characters as a single word, which
+-- makes words inside a sentence unsearchable. Split them into unigrams,
+-- queries are segmented the same way and matched as phrases.
+-- Ranges: kana U+3040-U+30FF, CJK ext A U+3400-U+4DBF, CJK U+4E00-U+9FFF,
+-- CJK compatibility U+F900-U+FAFF, hangul U+AC00-U+D7AF
+CREATE OR REPLACE FUNCTION thcdb_cjk_segment(input TEXT) RETURNS TEXT
+  LANGUAGE sql IMMUTABLE PARALLEL SAFE
+  AS $$
+    SELECT regexp_replace(
+      input,
+      '([぀-ヿ㐀-䶿一-鿿豈-﫿가-힯])',
+      ' \1 ',
+      'g'
+    )
+  $$;
+
+CREATE OR REPLACE FUNCTION thcdb_to_tsvector(input TEXT) RETURNS tsvector
+  LANGUAGE sql IMMUTABLE PARALLEL SAFE
+  AS $$
+    SELECT to_tsvector('thcdb_multilingual'::regconfig, thcdb_cjk_segment(coalesce(input, '')))
+  $$;
+
+CREATE OR REPLACE FUNCTION thcdb_to_tsquery(query TEXT) RETURNS tsquery
+  LANGUAGE sql IMMUTABLE PARALLEL SAFE
+  AS $$
+    SELECT CASE
+      WHEN query ~ '[぀-ヿ㐀-䶿一-鿿豈-﫿가-힯]'
+        THEN phraseto_tsquery('thcdb_multilingual'::regconfig, thcdb_cjk_segment(query))
+      ELSE websearch_to_tsquery('thcdb_multilingual'::regconfig, query)
+    END
+  $$;
+
+ALTER TABLE "public"."event"
+  ADD COLUMN "search_vector" tsvector GENERATED ALWAYS AS (
+    setweight(thcdb_to_tsvector("short_description"), 'A') ||
+    setweight(thcdb_to_tsvector("description"), 'B')
+  ) STORED;
+
+CREATE INDEX idx_event_search_vector ON "public"."event" USING gin ("search_vector");
+
+ALTER TABLE "public"."tag"
+  ADD COLUMN "search_vector" tsvector GENERATED ALWAYS AS (
+    setweight(thcdb_to_tsvector("short_description"), 'A') ||
+    setweight(thcdb_to_tsvector("description"), 'B')
+  ) STORED;
+
+CREATE INDEX idx_tag_search_vector ON "public"."tag" USING gin ("search_vector");
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS "public"."event_tag_vote";
+
+DROP TABLE IF EXISTS "public"."label_tag_vote";
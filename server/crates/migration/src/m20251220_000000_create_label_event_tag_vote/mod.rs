@@ -0,0 +1 @@
+crate::migration!(m20251220_000000_create_label_event_tag_vote);
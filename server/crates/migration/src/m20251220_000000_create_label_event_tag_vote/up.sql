@@ -0,0 +1,25 @@
+CREATE TABLE "public"."label_tag_vote" (
+  "label_id" INT NOT NULL REFERENCES public.label(id),
+  "tag_id" INT NOT NULL REFERENCES public.tag(id),
+  "user_id" INT NOT NULL REFERENCES public.user(id),
+  "score" SMALLINT NOT NULL CHECK (score IN (-3, 1, 2, 3)),
+  "voted_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (label_id, tag_id, user_id)
+);
+
+CREATE INDEX idx_label_tag_vote_tag_id ON label_tag_vote(tag_id);
+
+CREATE INDEX idx_label_tag_vote_user_id ON label_tag_vote(user_id);
+
+CREATE TABLE "public"."event_tag_vote" (
+  "event_id" INT NOT NULL REFERENCES public.event(id),
+  "tag_id" INT NOT NULL REFERENCES public.tag(id),
+  "user_id" INT NOT NULL REFERENCES public.user(id),
+  "score" SMALLINT NOT NULL CHECK (score IN (-3, 1, 2, 3)),
+  "voted_at" TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+  PRIMARY KEY (event_id, tag_id, user_id)
+);
+
+CREATE INDEX idx_event_tag_vote_tag_id ON event_tag_vote(tag_id);
+
+CREATE INDEX idx_event_tag_vote_user_id ON event_tag_vote(user_id);
@@ -0,0 +1,9 @@
+DROP INDEX IF EXISTS "public"."idx_artist_link_url";
+
+DROP TABLE IF EXISTS "public"."event_link_history";
+
+DROP TABLE IF EXISTS "public"."event_link";
+
+DROP TABLE IF EXISTS "public"."label_link_history";
+
+DROP TABLE IF EXISTS "public"."label_link";
@@ -0,0 +1 @@
+crate::migration!(m20251225_000000_create_label_event_link);
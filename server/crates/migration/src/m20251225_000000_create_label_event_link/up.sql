@@ -0,0 +1,42 @@
+CREATE TABLE "public"."label_link" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "label_id" INTEGER NOT NULL REFERENCES "public"."label" ("id"),
+  "url" TEXT NOT NULL,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_label_link_label_id ON label_link(label_id);
+
+CREATE INDEX idx_label_link_url ON label_link(url);
+
+CREATE TABLE "public"."label_link_history" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "history_id" INTEGER NOT NULL REFERENCES "public"."label_history" ("id"),
+  "url" TEXT NOT NULL,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_label_link_history_history_id ON label_link_history(history_id);
+
+CREATE TABLE "public"."event_link" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "event_id" INTEGER NOT NULL REFERENCES "public"."event" ("id"),
+  "url" TEXT NOT NULL,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_event_link_event_id ON event_link(event_id);
+
+CREATE INDEX idx_event_link_url ON event_link(url);
+
+CREATE TABLE "public"."event_link_history" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "history_id" INTEGER NOT NULL REFERENCES "public"."event_history" ("id"),
+  "url" TEXT NOT NULL,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_event_link_history_history_id ON event_link_history(history_id);
+
+-- Artists are looked up by profile url
+CREATE INDEX idx_artist_link_url ON artist_link(url);
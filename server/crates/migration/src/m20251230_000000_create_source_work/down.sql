@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS "public"."song_source_track_history";
+
+DROP TABLE IF EXISTS "public"."song_source_track";
+
+DROP TABLE IF EXISTS "public"."source_work_track_history";
+
+DROP TABLE IF EXISTS "public"."source_work_track";
+
+DROP TABLE IF EXISTS "public"."source_work_history";
+
+DROP TABLE IF EXISTS "public"."source_work";
+
+DROP TYPE IF EXISTS "public"."SourceWorkKind";
+
+-- Note: Cannot remove enum value from EntityType in PostgreSQL
@@ -0,0 +1 @@
+crate::migration!(m20251230_000000_create_source_work);
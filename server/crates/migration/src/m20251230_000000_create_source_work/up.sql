@@ -0,0 +1,90 @@
+-- Add SourceWork to EntityType enum
+DO
+$$
+BEGIN
+IF NOT EXISTS (
+  SELECT
+    1
+  FROM
+    pg_enum
+    JOIN pg_type ON pg_type.oid = pg_enum.enumtypid
+  WHERE
+    pg_type.typname = 'EntityType'
+    AND pg_enum.enumlabel = 'SourceWork'
+) THEN
+ALTER TYPE "public"."EntityType"
+ADD
+  VALUE 'SourceWork';
+
+END IF;
+
+END
+$$
+;
+
+CREATE TYPE "public"."SourceWorkKind" AS ENUM('Game', 'MusicCd', 'Book', 'Other');
+
+CREATE TABLE "public"."source_work" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "title" TEXT NOT NULL,
+  "kind" "public"."SourceWorkKind" NOT NULL,
+  "release_date" date NULL,
+  "release_date_precision" "public"."DatePrecision" NOT NULL DEFAULT 'Day',
+  "description" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_source_work_title_trgm ON source_work USING gin (lower(title) gin_trgm_ops);
+
+CREATE TABLE "public"."source_work_history" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "title" TEXT NOT NULL,
+  "kind" "public"."SourceWorkKind" NOT NULL,
+  "release_date" date NULL,
+  "release_date_precision" "public"."DatePrecision" NOT NULL DEFAULT 'Day',
+  "description" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("id")
+);
+
+CREATE TABLE "public"."source_work_track" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "source_work_id" INTEGER NOT NULL REFERENCES "public"."source_work" ("id"),
+  "track_number" TEXT NULL,
+  "title" TEXT NOT NULL,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_source_work_track_source_work_id ON source_work_track(source_work_id);
+
+-- "track_id" points at the live track a revision edits, so that applying a
+-- correction keeps the ids that songs are linked to. New tracks have none.
+CREATE TABLE "public"."source_work_track_history" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "history_id" INTEGER NOT NULL REFERENCES "public"."source_work_history" ("id"),
+  "track_id" INTEGER NULL REFERENCES "public"."source_work_track" ("id") ON DELETE SET NULL,
+  "track_number" TEXT NULL,
+  "title" TEXT NOT NULL,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_source_work_track_history_history_id ON source_work_track_history(history_id);
+
+CREATE TABLE "public"."song_source_track" (
+  "song_id" INTEGER NOT NULL REFERENCES "public"."song" ("id") ON DELETE CASCADE,
+  "source_track_id" INTEGER NOT NULL REFERENCES "public"."source_work_track" ("id") ON DELETE CASCADE,
+  PRIMARY KEY ("song_id", "source_track_id")
+);
+
+CREATE INDEX idx_song_source_track_source_track_id ON song_source_track(source_track_id);
+
+-- Song revisions must survive tracks removed from a source work, so the
+-- track title is kept in the revision and the link is cleared instead
+CREATE TABLE "public"."song_source_track_history" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "history_id" INTEGER NOT NULL REFERENCES "public"."song_history" ("id"),
+  "source_track_id" INTEGER NULL REFERENCES "public"."source_work_track" ("id") ON DELETE SET NULL,
+  "title" TEXT NOT NULL,
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_song_source_track_history_history_id ON song_source_track_history(history_id);
@@ -0,0 +1,11 @@
+DROP TABLE IF EXISTS "public"."release_group_release_history";
+
+DROP TABLE IF EXISTS "public"."release_group_release";
+
+DROP TABLE IF EXISTS "public"."release_group_history";
+
+DROP TABLE IF EXISTS "public"."release_group";
+
+DROP TYPE IF EXISTS "public"."ReleaseEditionType";
+
+-- Note: Cannot remove enum value from EntityType in PostgreSQL
@@ -0,0 +1 @@
+crate::migration!(m20260105_000000_create_release_group);
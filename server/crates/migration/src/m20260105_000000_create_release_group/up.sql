@@ -0,0 +1,60 @@
+-- Add ReleaseGroup to EntityType enum
+DO
+$$
+BEGIN
+IF NOT EXISTS (
+  SELECT
+    1
+  FROM
+    pg_enum
+    JOIN pg_type ON pg_type.oid = pg_enum.enumtypid
+  WHERE
+    pg_type.typname = 'EntityType'
+    AND pg_enum.enumlabel = 'ReleaseGroup'
+) THEN
+ALTER TYPE "public"."EntityType"
+ADD
+  VALUE 'ReleaseGroup';
+
+END IF;
+
+END
+$$
+;
+
+CREATE TYPE "public"."ReleaseEditionType" AS ENUM('Original', 'Reissue', 'Remaster', 'Digital', 'Limited', 'Other');
+
+CREATE TABLE "public"."release_group" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "title" TEXT NOT NULL,
+  "description" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_release_group_title_trgm ON release_group USING gin (lower(title) gin_trgm_ops);
+
+CREATE TABLE "public"."release_group_history" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "title" TEXT NOT NULL,
+  "description" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("id")
+);
+
+-- A release is an edition of at most one group
+CREATE TABLE "public"."release_group_release" (
+  "group_id" INTEGER NOT NULL REFERENCES "public"."release_group" ("id"),
+  "release_id" INTEGER NOT NULL REFERENCES "public"."release" ("id") ON DELETE CASCADE,
+  "edition_type" "public"."ReleaseEditionType" NOT NULL,
+  "notes" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("release_id")
+);
+
+CREATE INDEX idx_release_group_release_group_id ON release_group_release(group_id);
+
+CREATE TABLE "public"."release_group_release_history" (
+  "history_id" INTEGER NOT NULL REFERENCES "public"."release_group_history" ("id"),
+  "release_id" INTEGER NOT NULL REFERENCES "public"."release" ("id") ON DELETE CASCADE,
+  "edition_type" "public"."ReleaseEditionType" NOT NULL,
+  "notes" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("history_id", "release_id")
+);
@@ -0,0 +1,12 @@
+DROP TABLE IF EXISTS "public"."event_circle_history";
+DROP TABLE IF EXISTS "public"."event_circle";
+
+ALTER TABLE "public"."release_event_history"
+  DROP COLUMN "booth_day",
+  DROP COLUMN "booth_hall",
+  DROP COLUMN "booth_space";
+
+ALTER TABLE "public"."release_event"
+  DROP COLUMN "booth_day",
+  DROP COLUMN "booth_hall",
+  DROP COLUMN "booth_space";
@@ -0,0 +1 @@
+crate::migration!(m20260112_000000_add_event_booth);
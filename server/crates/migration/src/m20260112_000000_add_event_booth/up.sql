@@ -0,0 +1,31 @@
+-- Where a release was sold or a circle exhibited at an event, e.g.
+-- day 2, "東3ホール", "ケ-12a". Every part is optional.
+ALTER TABLE "public"."release_event"
+  ADD COLUMN "booth_day" SMALLINT NULL CHECK ("booth_day" > 0),
+  ADD COLUMN "booth_hall" TEXT NULL,
+  ADD COLUMN "booth_space" TEXT NULL;
+
+ALTER TABLE "public"."release_event_history"
+  ADD COLUMN "booth_day" SMALLINT NULL CHECK ("booth_day" > 0),
+  ADD COLUMN "booth_hall" TEXT NULL,
+  ADD COLUMN "booth_space" TEXT NULL;
+
+CREATE TABLE "public"."event_circle" (
+  "event_id" INTEGER NOT NULL REFERENCES "public"."event" ("id") ON DELETE CASCADE,
+  "artist_id" INTEGER NOT NULL REFERENCES "public"."artist" ("id") ON DELETE CASCADE,
+  "booth_day" SMALLINT NULL CHECK ("booth_day" > 0),
+  "booth_hall" TEXT NULL,
+  "booth_space" TEXT NULL,
+  PRIMARY KEY ("event_id", "artist_id")
+);
+
+CREATE INDEX idx_event_circle_artist_id ON event_circle(artist_id);
+
+CREATE TABLE "public"."event_circle_history" (
+  "history_id" INTEGER NOT NULL REFERENCES "public"."event_history" ("id"),
+  "artist_id" INTEGER NOT NULL REFERENCES "public"."artist" ("id") ON DELETE CASCADE,
+  "booth_day" SMALLINT NULL CHECK ("booth_day" > 0),
+  "booth_hall" TEXT NULL,
+  "booth_space" TEXT NULL,
+  PRIMARY KEY ("history_id", "artist_id")
+);
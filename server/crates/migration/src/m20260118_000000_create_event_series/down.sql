@@ -0,0 +1,13 @@
+ALTER TABLE "public"."event_history"
+  DROP COLUMN "series_id",
+  DROP COLUMN "series_ordinal";
+
+ALTER TABLE "public"."event"
+  DROP COLUMN "series_id",
+  DROP COLUMN "series_ordinal";
+
+DROP TABLE IF EXISTS "public"."event_series_history";
+
+DROP TABLE IF EXISTS "public"."event_series";
+
+-- Note: Cannot remove enum value from EntityType in PostgreSQL
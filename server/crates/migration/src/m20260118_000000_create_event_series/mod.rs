@@ -0,0 +1 @@
+crate::migration!(m20260118_000000_create_event_series);
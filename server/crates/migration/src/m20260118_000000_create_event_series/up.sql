@@ -0,0 +1,54 @@
+-- Add EventSeries to EntityType enum
+DO
+$$
+BEGIN
+IF NOT EXISTS (
+  SELECT
+    1
+  FROM
+    pg_enum
+    JOIN pg_type ON pg_type.oid = pg_enum.enumtypid
+  WHERE
+    pg_type.typname = 'EntityType'
+    AND pg_enum.enumlabel = 'EventSeries'
+) THEN
+ALTER TYPE "public"."EntityType"
+ADD
+  VALUE 'EventSeries';
+
+END IF;
+
+END
+$$
+;
+
+CREATE TABLE "public"."event_series" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "name" TEXT NOT NULL,
+  "description" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("id")
+);
+
+CREATE INDEX idx_event_series_name_trgm ON event_series USING gin (lower(name) gin_trgm_ops);
+
+CREATE TABLE "public"."event_series_history" (
+  "id" INTEGER NOT NULL GENERATED BY DEFAULT AS IDENTITY,
+  "name" TEXT NOT NULL,
+  "description" TEXT NOT NULL DEFAULT '',
+  PRIMARY KEY ("id")
+);
+
+-- The ordinal is the edition number within the series, e.g. 105 for
+-- Comiket 105. Editions without a number are ordered by their date.
+ALTER TABLE "public"."event"
+  ADD COLUMN "series_id" INTEGER NULL REFERENCES "public"."event_series" ("id") ON DELETE SET NULL,
+  ADD COLUMN "series_ordinal" INTEGER NULL CHECK ("series_ordinal" > 0);
+
+CREATE INDEX idx_event_series_id ON event(series_id);
+
+CREATE UNIQUE INDEX idx_event_series_ordinal ON event(series_id, series_ordinal)
+WHERE series_ordinal IS NOT NULL;
+
+ALTER TABLE "public"."event_history"
+  ADD COLUMN "series_id" INTEGER NULL REFERENCES "public"."event_series" ("id") ON DELETE SET NULL,
+  ADD COLUMN "series_ordinal" INTEGER NULL CHECK ("series_ordinal" > 0);
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS "public"."label_relation_history";
+
+DROP TABLE IF EXISTS "public"."label_relation";
+
+DROP TYPE IF EXISTS "public"."LabelRelationType";
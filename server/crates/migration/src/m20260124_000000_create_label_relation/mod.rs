@@ -0,0 +1 @@
+crate::migration!(m20260124_000000_create_label_relation);
@@ -0,0 +1,22 @@
+-- A relation points from a label to the label above it:
+-- - Parent: the related label owns this one
+-- - Imprint: this label is an imprint of the related one
+-- - Distributor: the related label distributes this one's releases
+CREATE TYPE "public"."LabelRelationType" AS ENUM('Parent', 'Imprint', 'Distributor');
+
+CREATE TABLE "public"."label_relation" (
+  "label_id" INTEGER NOT NULL REFERENCES "public"."label" ("id") ON DELETE CASCADE,
+  "related_label_id" INTEGER NOT NULL REFERENCES "public"."label" ("id") ON DELETE CASCADE,
+  "type" "public"."LabelRelationType" NOT NULL,
+  PRIMARY KEY ("label_id", "related_label_id", "type"),
+  CHECK (label_id <> related_label_id)
+);
+
+CREATE INDEX idx_label_relation_related_label_id ON label_relation(related_label_id);
+
+CREATE TABLE "public"."label_relation_history" (
+  "history_id" INTEGER NOT NULL REFERENCES "public"."label_history" ("id"),
+  "related_label_id" INTEGER NOT NULL REFERENCES "public"."label" ("id") ON DELETE CASCADE,
+  "type" "public"."LabelRelationType" NOT NULL,
+  PRIMARY KEY ("history_id", "related_label_id", "type")
+);
@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS "public"."external_id_history";
+
+DROP TABLE IF EXISTS "public"."external_id";
+
+DROP TYPE IF EXISTS "public"."ExternalIdSource";
@@ -0,0 +1 @@
+crate::migration!(m20260131_000000_create_external_id);
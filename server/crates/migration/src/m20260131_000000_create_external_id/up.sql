@@ -0,0 +1,25 @@
+-- Identifiers of entities in other databases. Like corrections, rows point
+-- to any entity through its type and id, so there is no foreign key on the
+-- entity itself.
+CREATE TYPE "public"."ExternalIdSource" AS ENUM('MusicBrainz', 'VGMdb', 'TouhouWiki', 'Wikidata', 'Discogs');
+
+CREATE TABLE "public"."external_id" (
+  "id" SERIAL PRIMARY KEY,
+  "entity_type" "public"."EntityType" NOT NULL,
+  "entity_id" INTEGER NOT NULL,
+  "source" "public"."ExternalIdSource" NOT NULL,
+  "value" TEXT NOT NULL,
+  UNIQUE ("entity_type", "entity_id", "source", "value")
+);
+
+-- Reverse lookup, from an id of another database to thcdb entities
+CREATE INDEX idx_external_id_source_value ON external_id(source, value);
+
+CREATE TABLE "public"."external_id_history" (
+  "id" SERIAL PRIMARY KEY,
+  "entity_type" "public"."EntityType" NOT NULL,
+  "history_id" INTEGER NOT NULL,
+  "source" "public"."ExternalIdSource" NOT NULL,
+  "value" TEXT NOT NULL,
+  UNIQUE ("entity_type", "history_id", "source", "value")
+);
@@ -3,10 +3,11 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use axum::extract::Request;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::get;
-use axum::{Json, Router};
+use axum::{Json, Router, ServiceExt};
 use flow::{Pipe, TapMut};
 use headers::authorization::{Basic, Credentials};
 use maud::{DOCTYPE, html};
@@ -21,7 +22,7 @@ use utoipa_axum::routes;
 use utoipa_scalar::{Scalar, Servable};
 
 use crate::constant::r#gen::{KT_CONSTANTS, TS_CONSTANTS};
-use crate::constant::{IMAGE_DIR, PUBLIC_DIR};
+use crate::constant::{DUMP_DIR, IMAGE_DIR, PUBLIC_DIR};
 use crate::feature;
 use crate::feature::artist::find::CommonFilter as ArtistCommonFilter;
 use crate::infra::state::AppState;
@@ -34,12 +35,15 @@ mod correction;
 mod credit_role;
 mod error;
 mod event;
+mod event_series;
 mod extract;
 mod label;
 mod middleware;
 mod release;
+mod release_group;
 mod song;
 mod song_lyrics;
+mod source_work;
 pub mod state;
 mod tag;
 mod user;
@@ -309,11 +313,16 @@ pub async fn listen(
 ) -> Result<(), Box<dyn std::error::Error>> {
     let state = ArcAppState::new(state);
 
-    let app = router(state);
+    let app = tower::Layer::layer(
+        &axum::middleware::map_request(middleware::rewrite_event_ics),
+        router(state),
+    );
 
     axum::serve(
         listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
+        ServiceExt::<Request>::into_make_service_with_connect_info::<
+            SocketAddr,
+        >(app),
     )
     .with_graceful_shutdown(async {
         match signal::ctrl_c().await {
@@ -344,6 +353,7 @@ fn router(state: ArcAppState) -> Router {
     Router::new()
         .route("/", get(home_page))
         .merge(doc_router)
+        .merge(feature::page::router())
         .merge(static_dir())
         .merge(constant_files())
         .pipe(|this| append_global_middlewares(this, &state))
@@ -376,11 +386,17 @@ async fn home_page(session: AuthSession) -> impl IntoResponse {
 
 fn static_dir() -> Router<ArcAppState> {
     let image_path = PathBuf::from_iter([PUBLIC_DIR, IMAGE_DIR]);
+    let dump_path = PathBuf::from_iter([PUBLIC_DIR, DUMP_DIR]);
 
-    Router::new().nest_service(
-        &format!("/{}", image_path.to_string_lossy()),
-        ServeDir::new(&image_path),
-    )
+    Router::new()
+        .nest_service(
+            &format!("/{}", image_path.to_string_lossy()),
+            ServeDir::new(&image_path),
+        )
+        .nest_service(
+            &format!("/{}", dump_path.to_string_lossy()),
+            ServeDir::new(&dump_path),
+        )
 }
 
 fn constant_files<S: Clone + Send + Sync + 'static>() -> Router<S> {
@@ -10,6 +10,9 @@ impl ApproveCorrectionContext for SeaOrmTxRepo {
     type TagRepo = Self;
     type SongLyricsRepo = Self;
     type CreditRoleRepo = Self;
+    type SourceWorkRepo = Self;
+    type ReleaseGroupRepo = Self;
+    type EventSeriesRepo = Self;
 
     fn artist_repo(self) -> Self::ArtistRepo {
         self
@@ -42,4 +45,16 @@ impl ApproveCorrectionContext for SeaOrmTxRepo {
     fn credit_role_repo(self) -> Self::CreditRoleRepo {
         self
     }
+
+    fn source_work_repo(self) -> Self::SourceWorkRepo {
+        self
+    }
+
+    fn release_group_repo(self) -> Self::ReleaseGroupRepo {
+        self
+    }
+
+    fn event_series_repo(self) -> Self::EventSeriesRepo {
+        self
+    }
 }
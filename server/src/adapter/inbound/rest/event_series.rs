@@ -0,0 +1,68 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::adapter::inbound::rest::AppRouter;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::application::correction::{
+    CorrectionSubmissionResult, NewCorrectionDto,
+};
+use crate::application::event_series::{CreateError, UpsertCorrectionError};
+use crate::domain::event_series::NewEventSeries;
+
+const TAG: &str = "Event Series";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_private(|r| {
+            r.routes(routes!(create_event_series))
+                .routes(routes!(update_event_series))
+        })
+        .finish()
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/event-series",
+    request_body = NewCorrectionDto<NewEventSeries>,
+    responses(
+		(status = 200, body = Data<CorrectionSubmissionResult>),
+    ),
+)]
+async fn create_event_series(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::EventSeriesService>,
+    Json(dto): Json<NewCorrectionDto<NewEventSeries>>,
+) -> Result<Data<CorrectionSubmissionResult>, CreateError> {
+    let result = service.create(dto.with_author(user)).await?;
+
+    Ok(Data::from(result))
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/event-series/{id}",
+    request_body = NewCorrectionDto<NewEventSeries>,
+    responses(
+		(status = 200, body = Data<CorrectionSubmissionResult>),
+    ),
+)]
+async fn update_event_series(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::EventSeriesService>,
+    Path(event_series_id): Path<i32>,
+    Json(input): Json<NewCorrectionDto<NewEventSeries>>,
+) -> Result<Data<CorrectionSubmissionResult>, UpsertCorrectionError> {
+    let result = service
+        .upsert_correction(event_series_id, input.with_author(user))
+        .await?;
+
+    Ok(Data::from(result))
+}
@@ -49,6 +49,34 @@ where
         .layer(cors_layer())
 }
 
+/// Serves `/event/{id}.ics` from `/event/{id}/calendar.ics`, since routes
+/// can't have a parameter followed by a suffix. This has to run before
+/// routing, so it wraps the whole router instead of being a route layer.
+pub async fn rewrite_event_ics(mut req: Request) -> Request {
+    if let Some(path) = event_ics_target(req.uri().path()) {
+        let path_and_query = match req.uri().query() {
+            Some(query) => format!("{path}?{query}"),
+            None => path,
+        };
+        let mut parts = req.uri().clone().into_parts();
+        if let Ok(path_and_query) = path_and_query.parse() {
+            parts.path_and_query = Some(path_and_query);
+            if let Ok(uri) = http::Uri::from_parts(parts) {
+                *req.uri_mut() = uri;
+            }
+        }
+    }
+
+    req
+}
+
+fn event_ics_target(path: &str) -> Option<String> {
+    let id = path.strip_prefix("/event/")?.strip_suffix(".ics")?;
+
+    (!id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+        .then(|| format!("/event/{id}/calendar.ics"))
+}
+
 fn auth_layer(state: &ArcAppState) -> impl AxumLayerBounds {
     let pool = state.redis_pool();
     let session_store = RedisStore::new(pool);
@@ -131,3 +159,20 @@ pub fn limit_layer(
         config: governor_conf,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_event_ics_paths() {
+        assert_eq!(
+            event_ics_target("/event/42.ics").as_deref(),
+            Some("/event/42/calendar.ics")
+        );
+        assert_eq!(event_ics_target("/event/calendar.ics"), None);
+        assert_eq!(event_ics_target("/event/.ics"), None);
+        assert_eq!(event_ics_target("/artist/42.ics"), None);
+        assert_eq!(event_ics_target("/event/42"), None);
+    }
+}
@@ -0,0 +1,68 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::adapter::inbound::rest::AppRouter;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::application::correction::{
+    CorrectionSubmissionResult, NewCorrectionDto,
+};
+use crate::application::release_group::{CreateError, UpsertCorrectionError};
+use crate::domain::release_group::NewReleaseGroup;
+
+const TAG: &str = "Release Group";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_private(|r| {
+            r.routes(routes!(create_release_group))
+                .routes(routes!(update_release_group))
+        })
+        .finish()
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/release-group",
+    request_body = NewCorrectionDto<NewReleaseGroup>,
+    responses(
+		(status = 200, body = Data<CorrectionSubmissionResult>),
+    ),
+)]
+async fn create_release_group(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReleaseGroupService>,
+    Json(dto): Json<NewCorrectionDto<NewReleaseGroup>>,
+) -> Result<Data<CorrectionSubmissionResult>, CreateError> {
+    let result = service.create(dto.with_author(user)).await?;
+
+    Ok(Data::from(result))
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/release-group/{id}",
+    request_body = NewCorrectionDto<NewReleaseGroup>,
+    responses(
+		(status = 200, body = Data<CorrectionSubmissionResult>),
+    ),
+)]
+async fn update_release_group(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::ReleaseGroupService>,
+    Path(release_group_id): Path<i32>,
+    Json(input): Json<NewCorrectionDto<NewReleaseGroup>>,
+) -> Result<Data<CorrectionSubmissionResult>, UpsertCorrectionError> {
+    let result = service
+        .upsert_correction(release_group_id, input.with_author(user))
+        .await?;
+
+    Ok(Data::from(result))
+}
@@ -0,0 +1,68 @@
+use axum::Json;
+use axum::extract::{Path, State};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::extract::CurrentUser;
+use super::state::{
+    ArcAppState, {self},
+};
+use crate::adapter::inbound::rest::AppRouter;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::application::correction::{
+    CorrectionSubmissionResult, NewCorrectionDto,
+};
+use crate::application::source_work::{CreateError, UpsertCorrectionError};
+use crate::domain::source_work::NewSourceWork;
+
+const TAG: &str = "Source Work";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_private(|r| {
+            r.routes(routes!(create_source_work))
+                .routes(routes!(update_source_work))
+        })
+        .finish()
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/source-work",
+    request_body = NewCorrectionDto<NewSourceWork>,
+    responses(
+		(status = 200, body = Data<CorrectionSubmissionResult>),
+    ),
+)]
+async fn create_source_work(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::SourceWorkService>,
+    Json(dto): Json<NewCorrectionDto<NewSourceWork>>,
+) -> Result<Data<CorrectionSubmissionResult>, CreateError> {
+    let result = service.create(dto.with_author(user)).await?;
+
+    Ok(Data::from(result))
+}
+
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/source-work/{id}",
+    request_body = NewCorrectionDto<NewSourceWork>,
+    responses(
+		(status = 200, body = Data<CorrectionSubmissionResult>),
+    ),
+)]
+async fn update_source_work(
+    CurrentUser(user): CurrentUser,
+    State(service): State<state::SourceWorkService>,
+    Path(source_work_id): Path<i32>,
+    Json(input): Json<NewCorrectionDto<NewSourceWork>>,
+) -> Result<Data<CorrectionSubmissionResult>, UpsertCorrectionError> {
+    let result = service
+        .upsert_correction(source_work_id, input.with_author(user))
+        .await?;
+
+    Ok(Data::from(result))
+}
@@ -101,6 +101,39 @@ impl FromRef<ArcAppState> for SongLyricsService {
     }
 }
 
+pub(crate) type EventSeriesService =
+    application::event_series::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for EventSeriesService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+        }
+    }
+}
+
+pub(crate) type ReleaseGroupService =
+    application::release_group::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for ReleaseGroupService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+        }
+    }
+}
+
+pub(crate) type SourceWorkService =
+    application::source_work::Service<SeaOrmRepository>;
+
+impl FromRef<ArcAppState> for SourceWorkService {
+    fn from_ref(input: &ArcAppState) -> Self {
+        Self {
+            repo: input.sea_orm_repo.clone(),
+        }
+    }
+}
+
 pub(crate) type TagService = application::tag::Service<SeaOrmRepository>;
 
 impl FromRef<ArcAppState> for TagService {
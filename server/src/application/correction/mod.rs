@@ -8,7 +8,7 @@ use crate::domain::correction::{
 };
 use crate::domain::model::{CorrectionApprover, UserRoleEnum};
 use crate::domain::user::User;
-use crate::domain::{Transaction, TransactionManager};
+use crate::domain::{Transaction, TransactionManager, credit_role, label};
 use crate::infra;
 use crate::infra::error::Error as InfraError;
 
@@ -30,11 +30,42 @@ pub enum Error {
     )]
     NotFound,
     #[snafu(transparent)]
+    CreditRoleValidation {
+        source: credit_role::ValidationError,
+    },
+    #[snafu(transparent)]
+    LabelValidation { source: label::ValidationError },
+    #[snafu(transparent)]
     Infra { source: infra::Error },
     #[snafu(transparent)]
     Unauthorized { source: Unauthorized },
 }
 
+impl Error {
+    /// Domain errors raised while applying a correction reach the service
+    /// boxed in [`InfraError::Internal`], this recovers the ones caused by
+    /// the submitted data
+    fn from_approval(err: InfraError) -> Self {
+        let InfraError::Internal { source } = err else {
+            return Self::Infra { source: err };
+        };
+
+        let source = match source.downcast::<credit_role::ValidationError>() {
+            Ok(source) => {
+                return Self::CreditRoleValidation { source: *source };
+            }
+            Err(source) => source,
+        };
+
+        match source.downcast::<label::ValidationError>() {
+            Ok(source) => Self::LabelValidation { source: *source },
+            Err(source) => Self::Infra {
+                source: InfraError::Internal { source },
+            },
+        }
+    }
+}
+
 impl<A> From<A> for Error
 where
     A: Into<infra::Error>,
@@ -138,7 +169,10 @@ where
 
         let tx_repo = self.repo.begin().await?;
 
-        tx_repo.approve(correction_id, approver, context).await?;
+        tx_repo
+            .approve(correction_id, approver, context)
+            .await
+            .map_err(Error::from_approval)?;
 
         tx_repo.commit().await?;
 
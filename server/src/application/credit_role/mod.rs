@@ -6,7 +6,7 @@ use crate::domain::TransactionManager;
 use crate::domain::correction::{
     NewCorrection, NewCorrectionMeta, {self},
 };
-use crate::domain::credit_role::{NewCreditRole, TxRepo};
+use crate::domain::credit_role::{NewCreditRole, TxRepo, ValidationError};
 
 #[derive(Clone)]
 pub struct Service<R> {
@@ -35,6 +35,8 @@ where
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
 
 pub enum UpsertCorrectionError {
+    #[snafu(transparent)]
+    Validation { source: ValidationError },
     #[snafu(transparent)]
     Infra { source: crate::infra::Error },
     #[snafu(transparent)]
@@ -108,6 +110,12 @@ where
     ) -> Result<CorrectionSubmissionResult, UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        let descendant_ids = tx_repo.find_descendant_ids(id).await?;
+        correction
+            .data
+            .validate_hierarchy(id, &descendant_ids)
+            .map_err(|source| UpsertCorrectionError::Validation { source })?;
+
         let history_id = tx_repo.create_history(&correction.data).await?;
         let correction_service = super::correction::Service::new(tx_repo);
 
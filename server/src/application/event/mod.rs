@@ -4,7 +4,7 @@ use macros::{ApiError, IntoErrorSchema};
 
 use crate::application::correction::CorrectionSubmissionResult;
 use crate::domain::correction::{self, NewCorrection, NewCorrectionMeta};
-use crate::domain::event::NewEvent;
+use crate::domain::event::{NewEvent, SeriesError};
 use crate::domain::{TransactionManager, ValidationError, event};
 use crate::infra;
 
@@ -16,6 +16,10 @@ pub struct Service<R> {
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
 #[snafu(module)]
 pub enum CreateError {
+    #[snafu(transparent)]
+    Series {
+        source: ValidationError<SeriesError>,
+    },
     #[snafu(transparent)]
     Correction {
         source: crate::application::correction::Error,
@@ -40,6 +44,10 @@ where
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
 #[snafu(module)]
 pub enum UpsertCorrectionError {
+    #[snafu(transparent)]
+    Series {
+        source: ValidationError<SeriesError>,
+    },
     #[snafu(transparent)]
     Correction {
         source: crate::application::correction::Error,
@@ -74,6 +82,9 @@ where
 
         let tx_repo = self.repo.begin().await?;
 
+        validate_series::<_, CreateError>(&tx_repo, &correction.data, None)
+            .await?;
+
         // TODO: Create entity in event repo, create correction in correction repo
         let entity_id =
             event::TxRepo::create(&tx_repo, &correction.data).await?;
@@ -125,6 +136,13 @@ where
 
         let tx_repo = self.repo.begin().await?;
 
+        validate_series::<_, UpsertCorrectionError>(
+            &tx_repo,
+            &correction.data,
+            Some(entity_id),
+        )
+        .await?;
+
         let history_id = tx_repo.create_history(&correction.data).await?;
 
         let correction_service = super::correction::Service::new(tx_repo);
@@ -163,3 +181,40 @@ where
         })
     }
 }
+
+/// `event_id` is the event being edited, which may keep its own edition
+async fn validate_series<TR, E>(
+    repo: &TR,
+    data: &NewEvent,
+    event_id: Option<i32>,
+) -> Result<(), E>
+where
+    TR: event::TxRepo,
+    E: From<ValidationError<SeriesError>>
+        + From<Box<dyn std::error::Error + Send + Sync>>,
+{
+    let Some(series) = &data.series else {
+        return Ok(());
+    };
+
+    if !repo.series_exists(series.series_id).await? {
+        return Err(ValidationError::from(SeriesError::UnknownSeries {
+            id: series.series_id,
+        })
+        .into());
+    }
+
+    if let Some(ordinal) = series.ordinal
+        && let Some(edition_id) =
+            repo.find_series_edition(series.series_id, ordinal).await?
+        && Some(edition_id) != event_id
+    {
+        return Err(ValidationError::from(SeriesError::OrdinalTaken {
+            ordinal,
+            event_id: edition_id,
+        })
+        .into());
+    }
+
+    Ok(())
+}
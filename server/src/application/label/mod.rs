@@ -4,7 +4,7 @@ use macros::{ApiError, IntoErrorSchema};
 use crate::application::correction::CorrectionSubmissionResult;
 use crate::domain::TransactionManager;
 use crate::domain::correction::{self, NewCorrection, NewCorrectionMeta};
-use crate::domain::label::{NewLabel, TxRepo};
+use crate::domain::label::{NewLabel, TxRepo, ValidationError};
 use crate::infra;
 
 #[derive(Clone)]
@@ -33,6 +33,8 @@ where
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
 pub enum UpsertCorrectionError {
+    #[snafu(transparent)]
+    Validation { source: ValidationError },
     #[snafu(transparent)]
     Correction {
         source: crate::application::correction::Error,
@@ -105,10 +107,17 @@ where
     ) -> Result<CorrectionSubmissionResult, UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        let descendant_ids = tx_repo.find_descendant_ids(id).await?;
+        correction
+            .data
+            .validate_hierarchy(id, &descendant_ids)
+            .map_err(|source| UpsertCorrectionError::Validation { source })?;
+
         // Create label history from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo.clone());
+        let correction_service =
+            super::correction::Service::new(tx_repo.clone());
 
         correction_service
             .upsert(NewCorrectionMeta::<NewLabel> {
@@ -5,11 +5,14 @@ pub mod correction;
 pub mod credit_role;
 pub mod error;
 pub mod event;
+pub mod event_series;
 pub mod label;
 pub mod release;
+pub mod release_group;
 pub mod release_image;
 pub mod song;
 pub mod song_lyrics;
+pub mod source_work;
 pub mod tag;
 pub mod user_image;
 pub mod user_profile;
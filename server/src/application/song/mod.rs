@@ -6,7 +6,7 @@ use crate::domain::TransactionManager;
 use crate::domain::correction::{
     NewCorrection, NewCorrectionMeta, {self},
 };
-use crate::domain::song::{NewSong, TxRepo};
+use crate::domain::song::{NewSong, TxRepo, ValidationError};
 
 #[derive(Clone)]
 pub struct Service<R> {
@@ -16,6 +16,8 @@ pub struct Service<R> {
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
 
 pub enum CreateError {
+    #[snafu(transparent)]
+    Validation { source: ValidationError },
     #[snafu(transparent)]
     Correction {
         source: crate::application::correction::Error,
@@ -36,6 +38,8 @@ where
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
 
 pub enum UpsertCorrectionError {
+    #[snafu(transparent)]
+    Validation { source: ValidationError },
     #[snafu(transparent)]
     Correction {
         source: crate::application::correction::Error,
@@ -64,6 +68,16 @@ where
     ) -> Result<CorrectionSubmissionResult, CreateError> {
         let tx_repo = self.repo.begin().await?;
 
+        let source_tracks = tx_repo
+            .find_existing_source_tracks(
+                correction.data.source_tracks.as_deref().unwrap_or_default(),
+            )
+            .await?;
+        correction
+            .data
+            .validate_source_tracks(&source_tracks)
+            .map_err(|source| CreateError::Validation { source })?;
+
         let entity_id = TxRepo::create(&tx_repo, &correction.data).await?;
         let history_id = tx_repo.create_history(&correction.data).await?;
 
@@ -109,6 +123,16 @@ where
     ) -> Result<CorrectionSubmissionResult, UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        let source_tracks = tx_repo
+            .find_existing_source_tracks(
+                correction.data.source_tracks.as_deref().unwrap_or_default(),
+            )
+            .await?;
+        correction
+            .data
+            .validate_source_tracks(&source_tracks)
+            .map_err(|source| UpsertCorrectionError::Validation { source })?;
+
         // Create song history from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
@@ -6,7 +6,7 @@ use crate::domain::TransactionManager;
 use crate::domain::correction::{
     NewCorrection, NewCorrectionMeta, {self},
 };
-use crate::domain::tag::{NewTag, TxRepo};
+use crate::domain::tag::{NewTag, TxRepo, ValidationError};
 
 #[derive(Clone)]
 pub struct Service<R> {
@@ -27,6 +27,8 @@ pub enum CreateError {
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
 
 pub enum UpsertCorrectionError {
+    #[snafu(transparent)]
+    Validation { source: ValidationError },
     #[snafu(transparent)]
     Correction {
         source: crate::application::correction::Error,
@@ -115,10 +117,17 @@ where
     ) -> Result<CorrectionSubmissionResult, UpsertCorrectionError> {
         let tx_repo = self.repo.begin().await?;
 
+        let descendant_ids = tx_repo.find_descendant_ids(id).await?;
+        correction
+            .data
+            .validate_hierarchy(id, &descendant_ids)
+            .map_err(|source| UpsertCorrectionError::Validation { source })?;
+
         // Create tag history from the data
         let history_id = tx_repo.create_history(&correction.data).await?;
 
-        let correction_service = super::correction::Service::new(tx_repo.clone());
+        let correction_service =
+            super::correction::Service::new(tx_repo.clone());
 
         correction_service
             .upsert(NewCorrectionMeta::<NewTag> {
@@ -3,8 +3,14 @@ pub mod r#gen;
 
 pub const PUBLIC_DIR: &str = "public";
 pub const IMAGE_DIR: &str = "image";
+pub const DUMP_DIR: &str = "dump";
+/// Dumps are written here and moved into [`DUMP_DIR`] once complete, it is
+/// not served
+pub const DUMP_STAGING_DIR: &str = "dump_staging";
 
 pub const ADMIN_USERNAME: &str = "Admin";
+/// Author of corrections submitted by importers
+pub const IMPORT_BOT_USERNAME: &str = "ImportBot";
 
 pub use share::*;
 
@@ -3,7 +3,9 @@ use macros::AutoMapper;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::domain::shared::{DateWithPrecision, LocalizedName, Location};
+use crate::domain::shared::{
+    DateWithPrecision, ExternalId, Link, LocalizedName, Location,
+};
 
 mod new_artist;
 pub use new_artist::*;
@@ -36,7 +38,8 @@ pub struct Artist {
 
     /// List of id of artist aliases
     pub aliases: Vec<i32>,
-    pub links: Vec<String>,
+    pub links: Vec<Link>,
+    pub external_ids: Vec<ExternalId>,
     pub localized_names: Vec<LocalizedName>,
 
     pub start_location: Location,
@@ -65,6 +68,22 @@ pub struct Tenure {
     pub leave_year: Option<i16>,
 }
 
+impl Tenure {
+    /// Whether the member was in the group at some point of `year`. Missing
+    /// bounds are treated as open.
+    pub fn contains_year(self, year: i16) -> bool {
+        self.join_year.is_none_or(|join| join <= year)
+            && self.leave_year.is_none_or(|leave| year <= leave)
+    }
+
+    pub const fn is_inverted(self) -> bool {
+        matches!(
+            (self.join_year, self.leave_year),
+            (Some(join), Some(leave)) if join > leave
+        )
+    }
+}
+
 #[derive(Clone, Debug, Serialize, ToSchema, AutoMapper)]
 #[mapper(from(entity::artist::Model))]
 pub struct SimpleArtist {
@@ -2,7 +2,8 @@ use axum::http::StatusCode;
 use derive_more::Display;
 use entity::enums::EntityType;
 pub use entity::sea_orm_active_enums::ArtistType;
-use macros::{ApiError, IntoErrorSchema, cmp_chain};
+use itertools::Itertools;
+use macros::{ApiError, IntoErrorSchema};
 use serde::Deserialize;
 use url::Url;
 use utoipa::ToSchema;
@@ -10,7 +11,7 @@ use utoipa::ToSchema;
 use super::Tenure;
 use crate::domain::correction::CorrectionEntity;
 use crate::domain::shared::{
-    DateWithPrecision, EntityIdent, Location, NewLocalizedName,
+    DateWithPrecision, EntityIdent, Location, NewExternalId, NewLocalizedName,
 };
 
 #[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
@@ -32,8 +33,10 @@ impl From<ValidationErrorKind> for ValidationError {
 pub enum ValidationErrorKind {
     #[display("Unknown type artist cannot have members")]
     UnknownTypeArtistHasMembers,
-    #[display("Invalid tenure")]
-    InvalidTenure,
+    #[display("Tenure leave year is before its join year")]
+    InvertedTenure,
+    #[display("Tenures of the same membership overlap")]
+    OverlappingTenure,
 }
 use ValidationErrorKind::*;
 
@@ -53,6 +56,7 @@ pub struct NewArtist {
     pub end_date: Option<DateWithPrecision>,
 
     pub links: Option<Vec<Url>>,
+    pub external_ids: Option<Vec<NewExternalId>>,
     pub localized_names: Option<Vec<NewLocalizedName>>,
 
     pub start_location: Option<Location>,
@@ -103,49 +107,25 @@ fn validate_artist_type_and_membership(
     }
 }
 
-fn validate_tenures(tenures: &[Tenure]) -> Result<(), ValidationError> {
-    match tenures {
-        [] => Ok(()),
-        [tenure] => {
-            let Tenure {
-                join_year,
-                leave_year,
-            } = tenure;
-
-            match (join_year, leave_year) {
-                (Some(join), Some(leave)) => join > leave,
-                _ => true,
-            }
-            .ok_or_else(|| InvalidTenure.into())
-        }
-        rest => validate_tenure_body(rest),
-    }
-}
+pub(super) fn validate_tenures(
+    tenures: &[Tenure],
+) -> Result<(), ValidationError> {
+    tenures
+        .iter()
+        .all(|tenure| !tenure.is_inverted())
+        .ok_or_else(|| ValidationError::from(InvertedTenure))?;
 
-fn validate_tenure_body(tenures: &[Tenure]) -> Result<(), ValidationError> {
+    // Only the earliest tenure may have an unknown join year and only the
+    // latest may be ongoing, so neighbours must both have their bound set
     tenures
-        .windows(2)
-        .all(|x| {
-            let [first, second] = x else { unreachable!() };
-
-            if let Tenure {
-                join_year: first_join,
-                leave_year: Some(first_leave),
-            } = first
-                && let Tenure {
-                    join_year: Some(second_join),
-                    leave_year: second_leave,
-                } = second
-            {
-                let first_join = &first_join.unwrap_or_default();
-                let second_leave = &second_leave.unwrap_or(i16::MAX);
-
-                cmp_chain! {
-                    first_join < first_leave < second_join < second_leave
-                }
-            } else {
-                false
-            }
+        .iter()
+        .sorted_by_key(|tenure| tenure.join_year.unwrap_or(i16::MIN))
+        .tuple_windows()
+        .all(|(first, second)| {
+            matches!(
+                (first.leave_year, second.join_year),
+                (Some(leave), Some(join)) if leave < join
+            )
         })
-        .ok_or_else(|| InvalidTenure.into())
+        .ok_or_else(|| OverlappingTenure.into())
 }
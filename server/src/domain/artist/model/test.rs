@@ -1 +1,58 @@
+use super::new_artist::validate_tenures;
+use super::*;
 
+const fn tenure(join_year: Option<i16>, leave_year: Option<i16>) -> Tenure {
+    Tenure {
+        join_year,
+        leave_year,
+    }
+}
+
+#[test]
+fn tenure_contains_year() {
+    assert!(tenure(Some(2005), Some(2010)).contains_year(2005));
+    assert!(tenure(Some(2005), Some(2010)).contains_year(2010));
+    assert!(!tenure(Some(2005), Some(2010)).contains_year(2011));
+    assert!(tenure(None, Some(2010)).contains_year(1990));
+    assert!(tenure(Some(2005), None).contains_year(2024));
+    assert!(!tenure(Some(2005), None).contains_year(2004));
+}
+
+#[test]
+fn single_year_tenure_is_valid() {
+    assert!(validate_tenures(&[tenure(Some(2010), Some(2010))]).is_ok());
+}
+
+#[test]
+fn reject_inverted_tenure() {
+    let err = validate_tenures(&[tenure(Some(2010), Some(2005))]).unwrap_err();
+    assert!(matches!(err.kind, ValidationErrorKind::InvertedTenure));
+}
+
+#[test]
+fn accept_disjoint_tenures_in_any_order() {
+    assert!(
+        validate_tenures(&[
+            tenure(Some(2015), None),
+            tenure(None, Some(2008)),
+            tenure(Some(2010), Some(2012)),
+        ])
+        .is_ok()
+    );
+}
+
+#[test]
+fn reject_overlapping_tenures() {
+    for tenures in [
+        [
+            tenure(Some(2005), Some(2010)),
+            tenure(Some(2008), Some(2012)),
+        ],
+        [tenure(Some(2005), None), tenure(Some(2008), Some(2012))],
+        [tenure(None, Some(2010)), tenure(None, Some(2012))],
+        [tenure(Some(2005), Some(2010)), tenure(Some(2010), None)],
+    ] {
+        let err = validate_tenures(&tenures).unwrap_err();
+        assert!(matches!(err.kind, ValidationErrorKind::OverlappingTenure));
+    }
+}
@@ -15,6 +15,10 @@ pub struct Credit {
     pub release_date: Option<DateWithPrecision>,
     pub release_type: ReleaseType,
     pub roles: Vec<CreditRoleRef>,
+    /// The names of the alias cluster credited on this release, only
+    /// present when aliases are included
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub credited_as: Vec<ArtistReleaseArtist>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -24,28 +28,55 @@ pub struct Discography {
     pub artist: Vec<ArtistReleaseArtist>,
     pub release_date: Option<DateWithPrecision>,
     pub release_type: ReleaseType,
+    /// The names of the alias cluster credited on this release, only
+    /// present when aliases are included
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub credited_as: Vec<ArtistReleaseArtist>,
+    /// The release group this release stands for, only present when
+    /// release groups are collapsed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_group: Option<CollapsedReleaseGroup>,
 }
 
-#[derive(Serialize, ToSchema)]
+#[derive(Clone, Serialize, ToSchema)]
 pub struct ArtistReleaseArtist {
     pub id: i32,
     pub name: String,
 }
 
+#[derive(Clone, Serialize, ToSchema)]
+pub struct CollapsedReleaseGroup {
+    pub id: i32,
+    pub title: String,
+    pub edition_count: i32,
+}
+
 // Queries
 
 pub struct AppearanceQuery {
     pub artist_id: i32,
+    /// Also match releases of the artist's aliases
+    pub include_aliases: bool,
     pub pagination: Cursor,
 }
 
 pub struct CreditQuery {
     pub artist_id: i32,
+    /// Also match releases of the artist's aliases
+    pub include_aliases: bool,
+    /// Only match credits with this role
+    pub role_id: Option<i32>,
+    /// Also match credits with a sub role of `role_id`
+    pub include_sub_roles: bool,
     pub pagination: Cursor,
 }
 
 pub struct DiscographyQuery {
     pub artist_id: i32,
+    /// Also match releases of the artist's aliases
+    pub include_aliases: bool,
     pub release_type: ReleaseType,
+    /// Return one release per release group
+    pub collapse_groups: bool,
     pub pagination: Cursor,
 }
@@ -16,7 +16,9 @@ use snafu::Snafu;
 use utoipa::ToSchema;
 
 use crate::adapter::inbound::rest::api_response::{Error, IntoApiResponse};
-use crate::constant::{USER_NAME_REGEX_STR, USER_PASSWORD_REGEX_STR};
+use crate::constant::{
+    IMPORT_BOT_USERNAME, USER_NAME_REGEX_STR, USER_PASSWORD_REGEX_STR,
+};
 use crate::infra::singleton::ARGON2_HASHER;
 
 #[derive(Debug, Snafu, ApiError)]
@@ -88,6 +90,8 @@ impl From<ValidateCredsErrorKind> for ValidateCredsError {
 pub enum ValidateCredsErrorKind {
     #[display("Invalid username")]
     InvalidUserName,
+    #[display("Username is reserved")]
+    ReservedUserName,
     #[display("Invalid Password")]
     InvalidPassword,
     #[display("Password is too weak")]
@@ -207,6 +211,10 @@ fn validate_username(username: &str) -> Result<(), ValidateCredsError> {
     static USER_NAME_REGEX: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(USER_NAME_REGEX_STR).unwrap());
 
+    if username.eq_ignore_ascii_case(IMPORT_BOT_USERNAME) {
+        return Err(ReservedUserName.into());
+    }
+
     if USER_NAME_REGEX.is_match(username)
         && !username
             .chars()
@@ -322,6 +330,8 @@ mod test {
             ("straße", true),
             ("مرحبا", true),
             ("مستخدم", true),
+            ("ImportBot", false),
+            ("importbot", false),
         ];
 
         for (username, expected) in test_cases {
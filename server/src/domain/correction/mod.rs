@@ -69,6 +69,9 @@ pub trait ApproveCorrectionContext: Send + Sync {
     type TagRepo: super::tag::TxRepo;
     type SongLyricsRepo: super::song_lyrics::TxRepo;
     type CreditRoleRepo: super::credit_role::TxRepo;
+    type SourceWorkRepo: super::source_work::TxRepo;
+    type ReleaseGroupRepo: super::release_group::TxRepo;
+    type EventSeriesRepo: super::event_series::TxRepo;
 
     fn artist_repo(self) -> Self::ArtistRepo;
     fn release_repo(self) -> Self::ReleaseRepo;
@@ -78,6 +81,9 @@ pub trait ApproveCorrectionContext: Send + Sync {
     fn tag_repo(self) -> Self::TagRepo;
     fn song_lyrics_repo(self) -> Self::SongLyricsRepo;
     fn credit_role_repo(self) -> Self::CreditRoleRepo;
+    fn source_work_repo(self) -> Self::SourceWorkRepo;
+    fn release_group_repo(self) -> Self::ReleaseGroupRepo;
+    fn event_series_repo(self) -> Self::EventSeriesRepo;
 }
 
 pub trait TxRepo: Repo {
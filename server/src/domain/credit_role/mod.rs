@@ -1,4 +1,7 @@
 mod model;
-pub use model::{CreditRole, CreditRoleRef, CreditRoleSummary, NewCreditRole};
+pub use model::{
+    CreditRole, CreditRoleRef, CreditRoleSummary, NewCreditRole,
+    ValidationError, validate_super_roles,
+};
 mod repo;
 pub use repo::*;
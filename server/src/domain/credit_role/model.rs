@@ -11,12 +11,17 @@ pub struct CreditRoleRef {
     pub name: String,
 }
 
-#[derive(AutoMapper, Clone, Debug, Serialize, ToSchema)]
-#[mapper(from(DbCreditRole))]
+#[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct CreditRoleSummary {
     pub id: i32,
     pub name: String,
     pub short_description: String,
+    /// Releases with a release credit in this role
+    pub release_count: i32,
+    /// Songs with a song credit in this role
+    pub song_count: i32,
+    /// Artists credited in this role on a release or a song
+    pub artist_count: i32,
 }
 
 #[derive(AutoMapper, Clone, Debug, Serialize, ToSchema)]
@@ -26,12 +31,42 @@ pub struct CreditRole {
     pub name: String,
     pub short_description: String,
     pub description: String,
+    #[mapper(on(DbCreditRole, with = Vec::new()))]
+    pub external_ids: Vec<ExternalId>,
 }
 
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use derive_more::Display;
 use entity::enums::EntityType;
+use macros::{ApiError, IntoErrorSchema};
 
 use crate::domain::correction::CorrectionEntity;
-use crate::domain::shared::EntityIdent;
+use crate::domain::shared::{EntityIdent, ExternalId, NewExternalId};
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[snafu(display("Validation error: {kind}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ValidationError {
+    pub kind: ValidationErrorKind,
+}
+
+impl From<ValidationErrorKind> for ValidationError {
+    fn from(kind: ValidationErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum ValidationErrorKind {
+    #[display("A credit role cannot inherit from itself")]
+    SelfInheritance,
+    #[display("Credit role inheritance would form a cycle through role {_0}")]
+    InheritanceCycle(i32),
+}
 
 #[derive(Deserialize, ToSchema)]
 pub struct NewCreditRole {
@@ -39,6 +74,41 @@ pub struct NewCreditRole {
     pub short_description: Option<String>,
     pub description: Option<String>,
     pub super_roles: Option<Vec<i32>>,
+    pub external_ids: Option<Vec<NewExternalId>>,
+}
+
+impl NewCreditRole {
+    /// `super_roles` point from a role to its parents, so inheriting from
+    /// any role below `id` would close a loop
+    pub fn validate_hierarchy(
+        &self,
+        id: i32,
+        descendant_ids: &HashSet<i32>,
+    ) -> Result<(), ValidationError> {
+        validate_super_roles(
+            id,
+            self.super_roles.iter().flatten().copied(),
+            descendant_ids,
+        )
+    }
+}
+
+/// Checks the parents of role `id` against the roles below it
+pub fn validate_super_roles(
+    id: i32,
+    super_ids: impl IntoIterator<Item = i32>,
+    descendant_ids: &HashSet<i32>,
+) -> Result<(), ValidationError> {
+    for super_id in super_ids {
+        if super_id == id {
+            return Err(ValidationErrorKind::SelfInheritance.into());
+        }
+        if descendant_ids.contains(&super_id) {
+            return Err(ValidationErrorKind::InheritanceCycle(super_id).into());
+        }
+    }
+
+    Ok(())
 }
 
 impl CorrectionEntity for NewCreditRole {
@@ -46,3 +116,27 @@ impl CorrectionEntity for NewCreditRole {
         EntityType::CreditRole
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reject_inheritance_cycles() {
+        let descendants = HashSet::from([3, 4]);
+
+        assert!(matches!(
+            validate_super_roles(1, [2, 1], &descendants),
+            Err(ValidationError {
+                kind: ValidationErrorKind::SelfInheritance
+            })
+        ));
+        assert!(matches!(
+            validate_super_roles(1, [2, 4], &descendants),
+            Err(ValidationError {
+                kind: ValidationErrorKind::InheritanceCycle(4)
+            })
+        ));
+        assert!(validate_super_roles(1, [2, 5], &descendants).is_ok());
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use entity::credit_role::Model as DbCreditRole;
 
 use super::model::*;
@@ -10,9 +12,6 @@ pub trait QueryKind {
 impl QueryKind for query_kind::Ref {
     type Output = CreditRoleRef;
 }
-impl QueryKind for query_kind::Summary {
-    type Output = CreditRoleSummary;
-}
 impl QueryKind for query_kind::Full {
     type Output = CreditRole;
 }
@@ -31,6 +30,14 @@ where
         data: &NewCreditRole,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Ids of all roles that reach `role_id` by following inheritance
+    async fn find_descendant_ids(
+        &self,
+        role_id: i32,
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Fails with [`super::ValidationError`] if the inheritance of the
+    /// correction would form a cycle
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
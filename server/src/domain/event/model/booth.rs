@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Where something is found at an event, e.g. day 2, "東3ホール", "ケ-12a"
+#[serde_with::apply(
+    Option => #[serde(default, skip_serializing_if = "Option::is_none")],
+)]
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema,
+)]
+pub struct Booth {
+    /// Day of the event, starting from 1
+    pub day: Option<i16>,
+    pub hall: Option<String>,
+    /// Space number, e.g. "ケ-12a"
+    pub space: Option<String>,
+}
+
+impl Booth {
+    /// Builds a booth from its stored columns, if any of them is set
+    pub fn from_columns(
+        day: Option<i16>,
+        hall: Option<String>,
+        space: Option<String>,
+    ) -> Option<Self> {
+        Some(Self { day, hall, space }.normalized()).filter(|x| !x.is_empty())
+    }
+
+    /// Splits a normalized booth into its stored columns
+    pub fn into_columns(self) -> (Option<i16>, Option<String>, Option<String>) {
+        let Self { day, hall, space } = self.normalized();
+        (day, hall, space)
+    }
+
+    /// Trims the hall and space, and drops them when blank
+    #[must_use]
+    pub fn normalized(self) -> Self {
+        fn clean(s: Option<String>) -> Option<String> {
+            s.map(|s| s.trim().to_owned()).filter(|s| !s.is_empty())
+        }
+
+        Self {
+            day: self.day,
+            hall: clean(self.hall),
+            space: clean(self.space),
+        }
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.day.is_none() && self.hall.is_none() && self.space.is_none()
+    }
+
+    pub fn validate(&self) -> garde::Result {
+        match self.day {
+            Some(day) if day < 1 => {
+                Err(garde::Error::new("booth day must start from 1"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,10 +1,14 @@
 use entity::enums::EntityType;
 use garde::Validate;
 use serde::Deserialize;
+use url::Url;
 use utoipa::ToSchema;
 
+use super::Booth;
 use crate::domain::correction::CorrectionEntity;
-use crate::domain::shared::{DateWithPrecision, EntityIdent, Location};
+use crate::domain::shared::{
+    DateWithPrecision, EntityIdent, Location, NewExternalId,
+};
 
 #[derive(Validate, Deserialize, ToSchema)]
 pub struct NewEvent {
@@ -22,6 +26,41 @@ pub struct NewEvent {
     pub end_date: Option<DateWithPrecision>,
     #[garde(skip)]
     pub alternative_names: Option<Vec<String>>,
+    #[garde(skip)]
+    pub links: Option<Vec<Url>>,
+    #[garde(skip)]
+    pub external_ids: Option<Vec<NewExternalId>>,
+    /// The series the event is an edition of
+    #[garde(custom(validate_series()))]
+    pub series: Option<NewEventSeriesLink>,
+    /// Circles exhibiting at the event
+    #[garde(custom(validate_circles()))]
+    pub circles: Option<Vec<NewEventCircle>>,
+}
+
+#[derive(Clone, Deserialize, ToSchema)]
+pub struct NewEventSeriesLink {
+    pub series_id: i32,
+    /// Edition number within the series, e.g. 105 for Comiket 105
+    pub ordinal: Option<i32>,
+}
+
+#[derive(Clone, Deserialize, ToSchema)]
+pub struct NewEventCircle {
+    pub artist_id: i32,
+    #[serde(default)]
+    pub booth: Booth,
+}
+
+/// Problems with the series link that can only be found in the database
+#[derive(Debug, snafu::Snafu)]
+pub enum SeriesError {
+    #[snafu(display("Event series {id} does not exist"))]
+    UnknownSeries { id: i32 },
+    #[snafu(display(
+        "Edition {ordinal} of the series is already event {event_id}"
+    ))]
+    OrdinalTaken { ordinal: i32, event_id: i32 },
 }
 
 impl CorrectionEntity for NewEvent {
@@ -47,3 +86,35 @@ fn validate_end_date(
         )),
     }
 }
+
+fn validate_series()
+-> impl FnOnce(&Option<NewEventSeriesLink>, &()) -> garde::Result {
+    move |series, ()| match series.as_ref().and_then(|x| x.ordinal) {
+        Some(ordinal) if ordinal < 1 => {
+            Err(garde::Error::new("series ordinal must start from 1"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_circles()
+-> impl FnOnce(&Option<Vec<NewEventCircle>>, &()) -> garde::Result {
+    move |circles, ()| {
+        let circles = circles.as_deref().unwrap_or_default();
+
+        for (idx, circle) in circles.iter().enumerate() {
+            if circles[..idx]
+                .iter()
+                .any(|x| x.artist_id == circle.artist_id)
+            {
+                return Err(garde::Error::new(format!(
+                    "Circle {} is listed more than once",
+                    circle.artist_id
+                )));
+            }
+            circle.booth.validate()?;
+        }
+
+        Ok(())
+    }
+}
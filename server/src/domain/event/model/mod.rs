@@ -1,5 +1,7 @@
+mod booth;
 pub mod r#in;
 pub mod out;
 
+pub use booth::*;
 pub use r#in::*;
 pub use out::*;
@@ -1,7 +1,10 @@
 use serde::Serialize;
 use utoipa::ToSchema;
 
-use crate::domain::shared::{DateWithPrecision, Location};
+use super::Booth;
+use crate::domain::artist::SimpleArtist;
+use crate::domain::event_series::EventSeriesRef;
+use crate::domain::shared::{DateWithPrecision, ExternalId, Link, Location};
 
 #[serde_with::apply(
     Vec      => #[serde(skip_serializing_if = "Vec::is_empty")],
@@ -18,7 +21,35 @@ pub struct Event {
     pub location: Location,
     pub start_date: Option<DateWithPrecision>,
     pub end_date: Option<DateWithPrecision>,
+    pub series: Option<EventSeriesRef>,
     pub alternative_names: Vec<AlternativeName>,
+    pub links: Vec<Link>,
+    pub external_ids: Vec<ExternalId>,
+    /// Circles exhibiting at the event in booth order, only included in
+    /// single event responses. Capped, the catalogue lists all of them
+    pub circles: Vec<EventCircle>,
+    /// Releases sold at the event in booth order, only included in single
+    /// event responses. Capped, the catalogue lists all of them
+    pub releases: Vec<EventRelease>,
+    /// Number of circles, including those left out of `circles`
+    pub circle_count: Option<u64>,
+    /// Number of releases, including those left out of `releases`
+    pub release_count: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventCircle {
+    pub artist: SimpleArtist,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub booth: Option<Booth>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventRelease {
+    pub id: i32,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub booth: Option<Booth>,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema)]
@@ -15,6 +15,18 @@ where
         data: &NewEvent,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
 
+    async fn series_exists(
+        &self,
+        series_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Id of the event that is edition `ordinal` of the series
+    async fn find_series_edition(
+        &self,
+        series_id: i32,
+        ordinal: i32,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
@@ -0,0 +1,52 @@
+use entity::enums::EntityType;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::correction::CorrectionEntity;
+use crate::domain::event::SimpleEvent;
+use crate::domain::shared::{
+    DateWithPrecision, EntityIdent, ExternalId, NewExternalId,
+};
+
+/// A recurring event, such as Comiket or 博麗神社例大祭
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventSeries {
+    pub id: i32,
+    pub name: String,
+    pub description: String,
+    pub external_ids: Vec<ExternalId>,
+    /// Editions ordered by ordinal, then by start date
+    pub editions: Vec<EventSeriesEdition>,
+    /// Releases sold at any edition of the series
+    pub release_count: i64,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventSeriesEdition {
+    pub ordinal: Option<i32>,
+    pub event: SimpleEvent,
+    pub start_date: Option<DateWithPrecision>,
+    pub release_count: i64,
+}
+
+/// The series an event belongs to, and its number within it
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventSeriesRef {
+    pub id: i32,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ordinal: Option<i32>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewEventSeries {
+    pub name: EntityIdent,
+    pub description: Option<String>,
+    pub external_ids: Option<Vec<NewExternalId>>,
+}
+
+impl CorrectionEntity for NewEventSeries {
+    fn entity_type() -> EntityType {
+        EntityType::EventSeries
+    }
+}
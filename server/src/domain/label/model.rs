@@ -1,12 +1,43 @@
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use derive_more::Display;
 use entity::enums::EntityType;
+use entity::sea_orm_active_enums::LabelRelationType;
+use macros::{ApiError, IntoErrorSchema};
 use serde::{Deserialize, Serialize};
+use url::Url;
 use utoipa::ToSchema;
 
 use crate::domain::correction::CorrectionEntity;
 use crate::domain::shared::{
-    DateWithPrecision, EntityIdent, LocalizedName, NewLocalizedName,
+    DateWithPrecision, EntityIdent, ExternalId, Link, LocalizedName,
+    NewExternalId, NewLocalizedName,
 };
 
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[snafu(display("Validation error: {kind}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ValidationError {
+    pub kind: ValidationErrorKind,
+}
+
+impl From<ValidationErrorKind> for ValidationError {
+    fn from(kind: ValidationErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum ValidationErrorKind {
+    #[display("A label cannot be related to itself")]
+    SelfRelation,
+    #[display("Label relations would form a cycle through label {_0}")]
+    RelationCycle(i32),
+}
+
 #[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct Label {
     pub id: i32,
@@ -15,6 +46,10 @@ pub struct Label {
     pub dissolved_date: Option<DateWithPrecision>,
     pub founders: Vec<i32>,
     pub localized_names: Vec<LocalizedName>,
+    pub links: Vec<Link>,
+    pub external_ids: Vec<ExternalId>,
+    /// Labels above this one
+    pub relations: Vec<LabelRelation>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -24,6 +59,12 @@ pub struct SimpleLabel {
     pub name: String,
 }
 
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LabelRelation {
+    pub label: SimpleLabel,
+    pub r#type: LabelRelationType,
+}
+
 #[derive(Deserialize, ToSchema)]
 pub struct NewLabel {
     pub name: EntityIdent,
@@ -31,6 +72,71 @@ pub struct NewLabel {
     pub dissolved_date: Option<DateWithPrecision>,
     pub founders: Option<Vec<i32>>,
     pub localized_names: Option<Vec<NewLocalizedName>>,
+    pub links: Option<Vec<Url>>,
+    pub external_ids: Option<Vec<NewExternalId>>,
+    pub relations: Option<Vec<NewLabelRelation>>,
+}
+
+/// Points from the label to its parent, the label it is an imprint of, or
+/// its distributor
+#[derive(Deserialize, ToSchema)]
+pub struct NewLabelRelation {
+    pub related_label_id: i32,
+    pub r#type: LabelRelationType,
+}
+
+/// Parent and imprint relations put a label below the related one, a
+/// distributor only sells its releases
+pub const fn is_child_relation(r#type: LabelRelationType) -> bool {
+    matches!(
+        r#type,
+        LabelRelationType::Parent | LabelRelationType::Imprint
+    )
+}
+
+impl NewLabel {
+    pub fn related_labels(
+        &self,
+    ) -> impl Iterator<Item = (i32, LabelRelationType)> + '_ {
+        self.relations
+            .iter()
+            .flatten()
+            .map(|relation| (relation.related_label_id, relation.r#type))
+    }
+
+    /// Relations point from a label to the ones above it, so linking label
+    /// `id` to any of its descendants would close a loop
+    pub fn validate_hierarchy(
+        &self,
+        id: i32,
+        descendant_ids: &HashSet<i32>,
+    ) -> Result<(), ValidationError> {
+        validate_related_labels(id, self.related_labels(), descendant_ids)
+    }
+}
+
+/// Checks the labels above label `id` against the ones below it. Only
+/// parent and imprint relations can close a loop, a label may distribute
+/// its own parent.
+pub fn validate_related_labels(
+    id: i32,
+    related_labels: impl IntoIterator<Item = (i32, LabelRelationType)>,
+    descendant_ids: &HashSet<i32>,
+) -> Result<(), ValidationError> {
+    for (related_label_id, r#type) in related_labels {
+        if related_label_id == id {
+            return Err(ValidationErrorKind::SelfRelation.into());
+        }
+        if is_child_relation(r#type)
+            && descendant_ids.contains(&related_label_id)
+        {
+            return Err(
+                ValidationErrorKind::RelationCycle(related_label_id).into()
+            );
+        }
+    }
+
+    Ok(())
 }
 
 impl CorrectionEntity for NewLabel {
@@ -38,3 +144,65 @@ impl CorrectionEntity for NewLabel {
         EntityType::Label
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_label(related: &[i32]) -> NewLabel {
+        new_label_of_type(related, "Imprint")
+    }
+
+    fn new_label_of_type(related: &[i32], r#type: &str) -> NewLabel {
+        let relations = related
+            .iter()
+            .map(|id| {
+                serde_json::json!({ "related_label_id": id, "type": r#type })
+            })
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(serde_json::json!({
+            "name": "Team Shanghai Alice",
+            "relations": relations,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reject_self_relation() {
+        let err = new_label(&[2, 1]).validate_hierarchy(1, &HashSet::new());
+        assert!(matches!(
+            err,
+            Err(ValidationError {
+                kind: ValidationErrorKind::SelfRelation
+            })
+        ));
+    }
+
+    #[test]
+    fn reject_relation_to_descendant() {
+        let descendants = HashSet::from([3, 4]);
+        let err = new_label(&[2, 4]).validate_hierarchy(1, &descendants);
+        assert!(matches!(
+            err,
+            Err(ValidationError {
+                kind: ValidationErrorKind::RelationCycle(4)
+            })
+        ));
+    }
+
+    #[test]
+    fn allow_distributing_a_descendant() {
+        let descendants = HashSet::from([3, 4]);
+        let label = new_label_of_type(&[4], "Distributor");
+
+        assert!(label.validate_hierarchy(1, &descendants).is_ok());
+        assert!(matches!(
+            new_label_of_type(&[1], "Distributor")
+                .validate_hierarchy(1, &descendants),
+            Err(ValidationError {
+                kind: ValidationErrorKind::SelfRelation
+            })
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use super::model::NewLabel;
 use crate::domain::Transaction;
 
@@ -15,6 +17,12 @@ where
         data: &NewLabel,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Ids of all labels that reach `label_id` by following relations
+    async fn find_descendant_ids(
+        &self,
+        label_id: i32,
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
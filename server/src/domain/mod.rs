@@ -3,6 +3,7 @@ pub mod artist_image_queue;
 pub mod auth;
 pub mod correction;
 pub mod event;
+pub mod event_series;
 pub mod image;
 pub mod image_queue;
 pub mod label;
@@ -12,11 +13,13 @@ pub mod release;
 pub mod shared;
 pub mod song;
 pub mod song_lyrics;
+pub mod source_work;
 pub mod tag;
 pub mod user;
 pub use shared::*;
 pub mod artist_release;
 pub mod credit_role;
+pub mod release_group;
 pub mod release_image;
 pub mod release_image_queue;
 pub mod error {
@@ -4,7 +4,10 @@ use serde::Deserialize;
 use utoipa::ToSchema;
 
 use crate::domain::correction::CorrectionEntity;
-use crate::domain::shared::{DateWithPrecision, NewLocalizedTitle};
+use crate::domain::event::Booth;
+use crate::domain::shared::{
+    DateWithPrecision, NewExternalId, NewLocalizedTitle,
+};
 
 #[derive(Clone, Validate, Deserialize, ToSchema)]
 pub struct NewRelease {
@@ -26,10 +29,13 @@ pub struct NewRelease {
     pub credits: Vec<NewCredit>,
     #[garde(length(min = 1))]
     pub discs: Vec<NewDisc>,
-    #[garde(skip)]
-    pub events: Vec<i32>,
+    #[garde(custom(validate_events()))]
+    pub events: Vec<NewReleaseEvent>,
     #[garde(skip)]
     pub localized_titles: Vec<NewLocalizedTitle>,
+    #[garde(skip)]
+    #[serde(default)]
+    pub external_ids: Vec<NewExternalId>,
     #[garde(custom(is_valid_track_list(&self.discs)))]
     pub tracks: Vec<NewTrack>,
 }
@@ -52,12 +58,62 @@ fn is_valid_track_list(
     }
 }
 
+fn validate_events() -> impl FnOnce(&[NewReleaseEvent], &()) -> garde::Result {
+    move |events, ()| {
+        for (idx, event) in events.iter().enumerate() {
+            if events[..idx].iter().any(|x| x.event_id == event.event_id) {
+                return Err(garde::Error::new(format!(
+                    "Event {} is listed more than once",
+                    event.event_id
+                )));
+            }
+            event.booth.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
 impl CorrectionEntity for NewRelease {
     fn entity_type() -> entity::enums::EntityType {
         entity::enums::EntityType::Release
     }
 }
 
+/// An event the release was sold at. A bare event id is also accepted.
+#[derive(Clone, ToSchema, Deserialize)]
+#[serde(from = "NewReleaseEventRepr")]
+pub struct NewReleaseEvent {
+    pub event_id: i32,
+    #[serde(default)]
+    pub booth: Booth,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NewReleaseEventRepr {
+    Id(i32),
+    Full {
+        event_id: i32,
+        #[serde(default)]
+        booth: Booth,
+    },
+}
+
+impl From<NewReleaseEventRepr> for NewReleaseEvent {
+    fn from(value: NewReleaseEventRepr) -> Self {
+        match value {
+            NewReleaseEventRepr::Id(event_id) => Self {
+                event_id,
+                booth: Booth::default(),
+            },
+            NewReleaseEventRepr::Full { event_id, booth } => {
+                Self { event_id, booth }
+            }
+        }
+    }
+}
+
 #[derive(Clone, ToSchema, Deserialize)]
 pub struct NewCatalogNumber {
     pub catalog_number: String,
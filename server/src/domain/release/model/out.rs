@@ -3,9 +3,9 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::domain::credit_role::CreditRoleRef;
-use crate::domain::event::SimpleEvent;
+use crate::domain::event::Booth;
 use crate::domain::label::SimpleLabel;
-use crate::domain::shared::{DateWithPrecision, LocalizedTitle};
+use crate::domain::shared::{DateWithPrecision, ExternalId, LocalizedTitle};
 use crate::domain::song::SongRef;
 
 #[serde_with::apply(
@@ -30,9 +30,19 @@ pub struct Release {
     pub credits: Vec<ReleaseCredit>,
     pub catalog_nums: Vec<CatalogNumber>,
     pub localized_titles: Vec<LocalizedTitle>,
+    pub external_ids: Vec<ExternalId>,
     pub discs: Vec<ReleaseDisc>,
     pub tracks: Vec<ReleaseTrack>,
-    pub events: Vec<SimpleEvent>,
+    pub events: Vec<ReleaseEvent>,
+}
+
+#[derive(Clone, Debug, ToSchema, Serialize)]
+#[cfg_attr(test, derive(PartialEq, Eq))]
+pub struct ReleaseEvent {
+    pub id: i32,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub booth: Option<Booth>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize, Deserialize)]
@@ -0,0 +1,153 @@
+use std::backtrace::Backtrace;
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use derive_more::Display;
+use entity::enums::EntityType;
+pub use entity::sea_orm_active_enums::ReleaseEditionType;
+use macros::ApiError;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::correction::CorrectionEntity;
+use crate::domain::shared::{
+    DateWithPrecision, EntityIdent, ExternalId, NewExternalId,
+};
+
+/// The editions of one album, such as its event first press, shop
+/// edition and digital re-release
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReleaseGroup {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+    pub external_ids: Vec<ExternalId>,
+    pub editions: Vec<ReleaseEdition>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReleaseEdition {
+    pub release_id: i32,
+    pub title: String,
+    pub release_date: Option<DateWithPrecision>,
+    pub catalog_numbers: Vec<String>,
+    pub edition_type: ReleaseEditionType,
+    pub notes: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewReleaseGroup {
+    pub title: EntityIdent,
+    pub description: Option<String>,
+    pub external_ids: Option<Vec<NewExternalId>>,
+    pub editions: Option<Vec<NewReleaseEdition>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewReleaseEdition {
+    pub release_id: i32,
+    pub edition_type: ReleaseEditionType,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError)]
+#[snafu(display("Validation error: {kind}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ValidationError {
+    pub kind: ValidationErrorKind,
+    pub backtrace: Backtrace,
+}
+
+impl From<ValidationErrorKind> for ValidationError {
+    fn from(kind: ValidationErrorKind) -> Self {
+        Self {
+            kind,
+            backtrace: Backtrace::capture(),
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum ValidationErrorKind {
+    #[display("Release {_0} is listed more than once")]
+    DuplicateRelease(i32),
+    #[display("Release {_0} already belongs to another release group")]
+    ReleaseInOtherGroup(i32),
+}
+
+use ValidationErrorKind::*;
+
+impl NewReleaseGroup {
+    pub fn release_ids(&self) -> Vec<i32> {
+        self.editions
+            .iter()
+            .flatten()
+            .map(|edition| edition.release_id)
+            .collect()
+    }
+
+    /// Validate the editions, given the releases among them that are
+    /// already editions of another group
+    pub fn validate(
+        &self,
+        grouped_elsewhere: &[i32],
+    ) -> Result<(), ValidationError> {
+        let mut seen = HashSet::new();
+
+        for id in self.release_ids() {
+            if !seen.insert(id) {
+                return Err(DuplicateRelease(id).into());
+            }
+            if grouped_elsewhere.contains(&id) {
+                return Err(ReleaseInOtherGroup(id).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CorrectionEntity for NewReleaseGroup {
+    fn entity_type() -> EntityType {
+        EntityType::ReleaseGroup
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn new_group(editions: &serde_json::Value) -> NewReleaseGroup {
+        serde_json::from_value(json!({
+            "title": "東方紅魔郷",
+            "editions": editions,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn validate_editions() {
+        let group = new_group(&json!([
+            { "release_id": 1, "edition_type": "Original" },
+            { "release_id": 2, "edition_type": "Reissue", "notes": "2nd press" },
+        ]));
+        assert!(group.validate(&[]).is_ok());
+        assert!(matches!(
+            group.validate(&[2]).unwrap_err().kind,
+            ReleaseInOtherGroup(2)
+        ));
+
+        let group = new_group(&json!([
+            { "release_id": 1, "edition_type": "Original" },
+            { "release_id": 1, "edition_type": "Remaster" },
+        ]));
+        assert!(matches!(
+            group.validate(&[]).unwrap_err().kind,
+            DuplicateRelease(1)
+        ));
+    }
+}
@@ -0,0 +1,30 @@
+use super::model::NewReleaseGroup;
+use crate::domain::Transaction;
+
+pub trait TxRepo: Transaction
+where
+    Self::apply_update(..): Send,
+{
+    async fn create(
+        &self,
+        data: &NewReleaseGroup,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn create_history(
+        &self,
+        data: &NewReleaseGroup,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn apply_update(
+        &self,
+        correction: entity::correction::Model,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Releases among `release_ids` that are editions of a group other
+    /// than `group_id`
+    async fn find_grouped_elsewhere(
+        &self,
+        group_id: Option<i32>,
+        release_ids: &[i32],
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>>;
+}
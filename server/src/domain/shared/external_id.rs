@@ -0,0 +1,293 @@
+//! Identifiers of entities in other databases.
+//!
+//! Values are validated and normalized per source when they are submitted,
+//! so that the reverse lookup can compare them as plain strings. Page URLs
+//! are derived from the source, the value and the entity type when reading.
+
+use axum::http::StatusCode;
+use entity::enums::EntityType;
+pub use entity::enums::ExternalIdSource;
+use macros::{ApiError, IntoErrorSchema};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use utoipa::ToSchema;
+
+/// Longest page title accepted by `MediaWiki`, in bytes
+const WIKI_TITLE_MAX_LEN: usize = 255;
+
+/// Characters that can't appear in a `MediaWiki` page title
+const WIKI_TITLE_FORBIDDEN: &[char] = &['#', '<', '>', '[', ']', '|', '{', '}'];
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct ExternalId {
+    pub source: ExternalIdSource,
+    pub value: String,
+    /// Page of the entity on the source, if the source has pages for this
+    /// kind of entity
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, ToSchema)]
+#[serde(try_from = "UncheckedExternalId")]
+pub struct NewExternalId {
+    pub source: ExternalIdSource,
+    /// - `musicbrainz`: MBID, a UUID
+    /// - `vgmdb`, `discogs`: numeric id
+    /// - `wikidata`: item id, such as `Q42`
+    /// - `touhouwiki`: page title on the English Touhou Wiki
+    pub value: String,
+}
+
+#[derive(Deserialize)]
+struct UncheckedExternalId {
+    source: ExternalIdSource,
+    value: String,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[snafu(display("Invalid {source:?} id \"{value}\": {reason}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct InvalidExternalId {
+    #[snafu(source(false))]
+    pub source: ExternalIdSource,
+    pub value: String,
+    pub reason: &'static str,
+}
+
+impl TryFrom<UncheckedExternalId> for NewExternalId {
+    type Error = InvalidExternalId;
+
+    fn try_from(
+        UncheckedExternalId { source, value }: UncheckedExternalId,
+    ) -> Result<Self, Self::Error> {
+        Self::try_new(source, &value)
+    }
+}
+
+impl NewExternalId {
+    pub fn try_new(
+        source: ExternalIdSource,
+        value: &str,
+    ) -> Result<Self, InvalidExternalId> {
+        let value = value.trim();
+        let normalized = match source {
+            ExternalIdSource::MusicBrainz => normalize_uuid(value),
+            ExternalIdSource::Vgmdb | ExternalIdSource::Discogs => {
+                normalize_number(value)
+            }
+            ExternalIdSource::Wikidata => normalize_wikidata(value),
+            ExternalIdSource::TouhouWiki => normalize_wiki_title(value),
+        };
+
+        normalized
+            .map(|value| Self { source, value })
+            .map_err(|reason| InvalidExternalId {
+                source,
+                value: value.to_owned(),
+                reason,
+            })
+    }
+}
+
+impl ExternalId {
+    /// Build from a stored value, which is expected to be normalized already
+    pub fn from_stored(
+        entity_type: EntityType,
+        source: ExternalIdSource,
+        value: String,
+    ) -> Self {
+        let url = page_url(entity_type, source, &value);
+
+        Self { source, value, url }
+    }
+}
+
+fn page_url(
+    entity_type: EntityType,
+    source: ExternalIdSource,
+    value: &str,
+) -> Option<String> {
+    let path = match source {
+        ExternalIdSource::MusicBrainz => match entity_type {
+            EntityType::Artist => "https://musicbrainz.org/artist",
+            EntityType::Label => "https://musicbrainz.org/label",
+            EntityType::Release => "https://musicbrainz.org/release",
+            EntityType::Song => "https://musicbrainz.org/recording",
+            EntityType::Event => "https://musicbrainz.org/event",
+            EntityType::ReleaseGroup => "https://musicbrainz.org/release-group",
+            EntityType::EventSeries => "https://musicbrainz.org/series",
+            _ => return None,
+        },
+        ExternalIdSource::Vgmdb => match entity_type {
+            EntityType::Artist => "https://vgmdb.net/artist",
+            EntityType::Label => "https://vgmdb.net/org",
+            EntityType::Release => "https://vgmdb.net/album",
+            EntityType::Event => "https://vgmdb.net/event",
+            EntityType::SourceWork => "https://vgmdb.net/product",
+            _ => return None,
+        },
+        ExternalIdSource::Discogs => match entity_type {
+            EntityType::Artist => "https://www.discogs.com/artist",
+            EntityType::Label => "https://www.discogs.com/label",
+            EntityType::Release => "https://www.discogs.com/release",
+            EntityType::ReleaseGroup => "https://www.discogs.com/master",
+            _ => return None,
+        },
+        ExternalIdSource::Wikidata => "https://www.wikidata.org/wiki",
+        ExternalIdSource::TouhouWiki => "https://en.touhouwiki.net/wiki",
+    };
+
+    let mut url = Url::parse(path).ok()?;
+    url.path_segments_mut().ok()?.push(&value.replace(' ', "_"));
+
+    Some(url.into())
+}
+
+fn normalize_uuid(value: &str) -> Result<String, &'static str> {
+    let value = value.to_ascii_lowercase();
+    let is_uuid = value.len() == 36
+        && value.char_indices().all(|(idx, char)| match idx {
+            8 | 13 | 18 | 23 => char == '-',
+            _ => char.is_ascii_hexdigit(),
+        });
+
+    if is_uuid {
+        Ok(value)
+    } else {
+        Err("expected a UUID")
+    }
+}
+
+fn normalize_number(value: &str) -> Result<String, &'static str> {
+    match value.parse::<u32>() {
+        Ok(number)
+            if number > 0 && value.bytes().all(|x| x.is_ascii_digit()) =>
+        {
+            Ok(number.to_string())
+        }
+        _ => Err("expected a positive number"),
+    }
+}
+
+fn normalize_wikidata(value: &str) -> Result<String, &'static str> {
+    value
+        .strip_prefix(['Q', 'q'])
+        .and_then(|number| normalize_number(number).ok())
+        .map(|number| format!("Q{number}"))
+        .ok_or("expected an item id such as Q42")
+}
+
+/// Underscores and spaces are the same in page titles, and the first
+/// letter is case insensitive
+fn normalize_wiki_title(value: &str) -> Result<String, &'static str> {
+    let title = value
+        .replace('_', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if title.is_empty() {
+        return Err("expected a page title");
+    }
+    if title.len() > WIKI_TITLE_MAX_LEN {
+        return Err("page title is too long");
+    }
+    if title.contains(WIKI_TITLE_FORBIDDEN) {
+        return Err("page title contains forbidden characters");
+    }
+
+    let mut chars = title.chars();
+    Ok(chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize(source: ExternalIdSource, value: &str) -> Option<String> {
+        NewExternalId::try_new(source, value).ok().map(|x| x.value)
+    }
+
+    #[test]
+    fn validate_per_source() {
+        use ExternalIdSource::*;
+
+        for (source, value, expected) in [
+            (
+                MusicBrainz,
+                " 8A8A6F5E-4F9B-4F0E-8D43-8B0E1C7C1E02 ",
+                Some("8a8a6f5e-4f9b-4f0e-8d43-8b0e1c7c1e02"),
+            ),
+            (MusicBrainz, "8a8a6f5e4f9b4f0e8d438b0e1c7c1e02", None),
+            (MusicBrainz, "8a8a6f5e-4f9b-4f0e-8d43-8b0e1c7c1e0g", None),
+            (Vgmdb, "12345", Some("12345")),
+            (Vgmdb, "0", None),
+            (Vgmdb, "+1", None),
+            (Vgmdb, "12a", None),
+            (Discogs, "007", Some("7")),
+            (Wikidata, "q42", Some("Q42")),
+            (Wikidata, "P31", None),
+            (Wikidata, "Q", None),
+            (TouhouWiki, "  reimu_Hakurei ", Some("Reimu Hakurei")),
+            (
+                TouhouWiki,
+                "Lyrics: 魔理沙は大変なものを盗んでいきました",
+                Some("Lyrics: 魔理沙は大変なものを盗んでいきました"),
+            ),
+            (TouhouWiki, "A[b]", None),
+            (TouhouWiki, " _ ", None),
+        ] {
+            assert_eq!(
+                normalize(source, value).as_deref(),
+                expected,
+                "{source:?} {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_with_validation() {
+        let id: NewExternalId = serde_json::from_value(serde_json::json!({
+            "source": "vgmdb",
+            "value": "0042",
+        }))
+        .unwrap();
+        assert_eq!(id.value, "42");
+
+        let result = serde_json::from_value::<NewExternalId>(
+            serde_json::json!({ "source": "musicbrainz", "value": "42" }),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn build_page_urls() {
+        let url = |entity_type, source, value: &str| {
+            ExternalId::from_stored(entity_type, source, value.to_owned()).url
+        };
+
+        assert_eq!(
+            url(EntityType::Label, ExternalIdSource::Vgmdb, "1").as_deref(),
+            Some("https://vgmdb.net/org/1")
+        );
+        assert_eq!(
+            url(EntityType::Song, ExternalIdSource::MusicBrainz, "x")
+                .as_deref(),
+            Some("https://musicbrainz.org/recording/x")
+        );
+        assert_eq!(
+            url(
+                EntityType::Artist,
+                ExternalIdSource::TouhouWiki,
+                "ZUN (Team)"
+            )
+            .as_deref(),
+            Some("https://en.touhouwiki.net/wiki/ZUN_(Team)")
+        );
+        assert_eq!(url(EntityType::Tag, ExternalIdSource::Vgmdb, "1"), None);
+    }
+}
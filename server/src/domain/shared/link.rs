@@ -0,0 +1,275 @@
+//! External links of artists, labels and events.
+//!
+//! Links are canonicalized before being stored, so that the same profile
+//! submitted in different forms ends up as a single row and can be looked up
+//! by URL. The kind is derived from the URL when reading.
+
+use itertools::Itertools;
+use serde::Serialize;
+use url::Url;
+use utoipa::ToSchema;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkKind {
+    Twitter,
+    Bandcamp,
+    Booth,
+    Soundcloud,
+    Youtube,
+    Niconico,
+    Pixiv,
+    Spotify,
+    AppleMusic,
+    /// Any site not listed above
+    Homepage,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct Link {
+    pub kind: LinkKind,
+    pub url: String,
+}
+
+/// Query parameters that never identify a page, removed from every link
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid", "gclid", "dclid", "msclkid", "igshid", "mc_cid", "mc_eid", "_gl",
+    "si", "ref_src", "ref_url",
+];
+
+impl LinkKind {
+    fn of_host(host: &str) -> Self {
+        let is = |domain: &str| {
+            host == domain || host.ends_with(&format!(".{domain}"))
+        };
+
+        if is("twitter.com") || is("x.com") {
+            Self::Twitter
+        } else if is("bandcamp.com") {
+            Self::Bandcamp
+        } else if is("booth.pm") {
+            Self::Booth
+        } else if is("soundcloud.com") {
+            Self::Soundcloud
+        } else if is("youtube.com") || host == "youtu.be" {
+            Self::Youtube
+        } else if is("nicovideo.jp") || host == "nico.ms" {
+            Self::Niconico
+        } else if is("pixiv.net") {
+            Self::Pixiv
+        } else if host == "open.spotify.com" {
+            Self::Spotify
+        } else if host == "music.apple.com" || host == "itunes.apple.com" {
+            Self::AppleMusic
+        } else {
+            Self::Homepage
+        }
+    }
+
+    /// Platform specific parameters used for tracking or share attribution
+    const fn tracking_params(self) -> &'static [&'static str] {
+        match self {
+            Self::Twitter => &["s", "t"],
+            Self::Youtube => &["feature", "pp"],
+            Self::AppleMusic => &["ls"],
+            _ => &[],
+        }
+    }
+}
+
+impl Link {
+    /// Classify a stored link, which is expected to be canonical already
+    pub fn from_stored(url: String) -> Self {
+        let kind = Url::parse(&url)
+            .ok()
+            .and_then(|url| url.host_str().map(LinkKind::of_host))
+            .unwrap_or(LinkKind::Homepage);
+
+        Self { kind, url }
+    }
+}
+
+impl From<&Url> for Link {
+    fn from(url: &Url) -> Self {
+        let url = canonicalize(url);
+        let kind = url.host_str().map_or(LinkKind::Homepage, LinkKind::of_host);
+
+        Self {
+            kind,
+            url: url.into(),
+        }
+    }
+}
+
+/// Canonicalize and deduplicate links, keeping the first occurrence
+pub fn normalize_links(urls: &[Url]) -> Vec<Link> {
+    urls.iter()
+        .map(Link::from)
+        .unique_by(|link| link.url.clone())
+        .collect()
+}
+
+/// Drop tracking parameters. Links to known platforms are also brought to
+/// one form per page: https, no port or fragment, a single host per
+/// platform and no trailing slash. Any other site is otherwise kept as is,
+/// since its scheme, host and path can all matter.
+pub fn canonicalize(url: &Url) -> Url {
+    let mut url = url.clone();
+    let kind = url.host_str().map_or(LinkKind::Homepage, LinkKind::of_host);
+
+    if kind != LinkKind::Homepage {
+        canonicalize_platform(&mut url, kind);
+    }
+
+    // Filter the raw pairs so that the rest of the query is not re-encoded
+    if let Some(query) = url.query().map(ToOwned::to_owned) {
+        let query = query
+            .split('&')
+            .filter(|pair| {
+                let key = pair.split_once('=').map_or(*pair, |(key, _)| key);
+                !key.starts_with("utm_")
+                    && !TRACKING_PARAMS.contains(&key)
+                    && !kind.tracking_params().contains(&key)
+            })
+            .join("&");
+
+        url.set_query((!query.is_empty()).then_some(&query));
+    }
+
+    url
+}
+
+fn canonicalize_platform(url: &mut Url, kind: LinkKind) {
+    if url.scheme() == "http" {
+        let _ = url.set_scheme("https");
+    }
+    let _ = url.set_port(None);
+    url.set_fragment(None);
+
+    if let Some(host) = url.host_str().map(ToOwned::to_owned) {
+        let host = match kind {
+            LinkKind::Twitter => Some("x.com"),
+            LinkKind::Bandcamp | LinkKind::Booth => None,
+            // Subdomains such as `music.youtube.com` are separate sites
+            _ => host
+                .strip_prefix("www.")
+                .or_else(|| host.strip_prefix("m."))
+                .or_else(|| host.strip_prefix("sp.")),
+        };
+
+        if let Some(host) = host {
+            let _ = url.set_host(Some(host));
+        }
+    }
+
+    if url.host_str() == Some("youtu.be") {
+        let id = url.path().trim_matches('/').to_owned();
+        if !id.is_empty() {
+            let _ = url.set_host(Some("youtube.com"));
+            url.set_path("/watch");
+            let query = url
+                .query_pairs()
+                .into_owned()
+                .filter(|(key, _)| key != "v")
+                .collect_vec();
+            url.query_pairs_mut()
+                .clear()
+                .append_pair("v", &id)
+                .extend_pairs(query);
+        }
+    }
+
+    if url.path().len() > 1 && url.path().ends_with('/') {
+        let path = url.path().trim_end_matches('/').to_owned();
+        url.set_path(&path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(url: &str) -> Link {
+        Link::from(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn classify_platforms() {
+        for (url, kind) in [
+            ("https://twitter.com/zun_info", LinkKind::Twitter),
+            ("https://example.bandcamp.com/album/a", LinkKind::Bandcamp),
+            ("https://example.booth.pm/items/1", LinkKind::Booth),
+            ("https://soundcloud.com/a", LinkKind::Soundcloud),
+            ("https://youtu.be/abc", LinkKind::Youtube),
+            ("https://www.nicovideo.jp/user/1", LinkKind::Niconico),
+            ("https://www.pixiv.net/users/1", LinkKind::Pixiv),
+            ("https://open.spotify.com/artist/1", LinkKind::Spotify),
+            ("https://music.apple.com/jp/artist/1", LinkKind::AppleMusic),
+            ("https://www16.big.or.jp/~zun/", LinkKind::Homepage),
+        ] {
+            assert_eq!(link(url).kind, kind, "{url}");
+        }
+    }
+
+    #[test]
+    fn canonicalize_urls() {
+        for (url, canonical) in [
+            (
+                "http://mobile.twitter.com/zun_info?s=20&t=abc",
+                "https://x.com/zun_info",
+            ),
+            (
+                "https://www.youtube.com/@channel/?feature=shared#top",
+                "https://youtube.com/@channel",
+            ),
+            (
+                "https://youtu.be/abc?si=xyz&t=42",
+                "https://youtube.com/watch?v=abc&t=42",
+            ),
+            (
+                "https://music.youtube.com/channel/abc?feature=share",
+                "https://music.youtube.com/channel/abc",
+            ),
+            (
+                "http://example.com/?utm_source=x&page=2",
+                "http://example.com/?page=2",
+            ),
+            (
+                "https://www.pixiv.net/users/1/",
+                "https://pixiv.net/users/1",
+            ),
+        ] {
+            assert_eq!(link(url).url, canonical, "{url}");
+        }
+    }
+
+    #[test]
+    fn keep_homepages_as_submitted() {
+        for url in [
+            "http://www16.big.or.jp/~zun/",
+            "http://example.com:8080/music/",
+            "https://www.example.com/works/#2024",
+            "https://example.com/search?q=a+b%20c",
+        ] {
+            assert_eq!(link(url).url, url);
+        }
+    }
+
+    #[test]
+    fn deduplicate_after_canonicalization() {
+        let urls = [
+            "https://twitter.com/zun_info",
+            "http://x.com/zun_info/",
+            "https://example.com",
+        ]
+        .map(|url| Url::parse(url).unwrap());
+
+        assert_eq!(
+            normalize_links(&urls)
+                .into_iter()
+                .map(|link| link.url)
+                .collect_vec(),
+            ["https://x.com/zun_info", "https://example.com/"]
+        );
+    }
+}
@@ -1,7 +1,13 @@
+mod external_id;
+mod link;
 mod model;
 mod repository;
+mod search;
+pub use external_id::*;
+pub use link::*;
 pub use model::*;
 pub use repository::*;
+pub use search::*;
 
 pub mod query_kind {
     pub struct Ref;
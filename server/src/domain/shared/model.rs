@@ -23,6 +23,17 @@ impl DateWithPrecision {
         (self.value, self.precision)
     }
 
+    /// ISO 8601 truncated to the known precision, e.g. "2019-08"
+    pub fn to_iso_string(self) -> String {
+        let format = match self.precision {
+            DatePrecision::Day => "%Y-%m-%d",
+            DatePrecision::Month => "%Y-%m",
+            DatePrecision::Year => "%Y",
+        };
+
+        self.value.format(format).to_string()
+    }
+
     pub const fn from_option(
         value: Option<NaiveDate>,
         precision: DatePrecision,
@@ -32,6 +43,29 @@ impl DateWithPrecision {
             None => None,
         }
     }
+
+    /// Parses a date that may lack its day or month, e.g. "2019",
+    /// "2019-08" or "2019/08/12"
+    pub fn parse_partial(date: &str) -> Option<Self> {
+        let mut fields = date.split(['-', '/', '.']).map(str::trim);
+        let year = fields.next()?.parse().ok()?;
+        let month = fields.next().and_then(|x| x.parse().ok());
+        let day = fields.next().and_then(|x| x.parse().ok());
+
+        let (value, precision) = match (month, day) {
+            (Some(month), Some(day)) => (
+                NaiveDate::from_ymd_opt(year, month, day)?,
+                DatePrecision::Day,
+            ),
+            (Some(month), None) => (
+                NaiveDate::from_ymd_opt(year, month, 1)?,
+                DatePrecision::Month,
+            ),
+            _ => (NaiveDate::from_ymd_opt(year, 1, 1)?, DatePrecision::Year),
+        };
+
+        Some(Self { value, precision })
+    }
 }
 
 impl From<(NaiveDate, DatePrecision)> for DateWithPrecision {
@@ -122,3 +156,25 @@ impl Location {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_partial_date() {
+        let date = DateWithPrecision::parse_partial("2019").unwrap();
+        assert_eq!(date.value, NaiveDate::from_ymd_opt(2019, 1, 1).unwrap());
+        assert!(matches!(date.precision, DatePrecision::Year));
+
+        let date = DateWithPrecision::parse_partial("2019-08").unwrap();
+        assert_eq!(date.value, NaiveDate::from_ymd_opt(2019, 8, 1).unwrap());
+        assert!(matches!(date.precision, DatePrecision::Month));
+
+        let date = DateWithPrecision::parse_partial("2019/08/12").unwrap();
+        assert_eq!(date.value, NaiveDate::from_ymd_opt(2019, 8, 12).unwrap());
+        assert!(matches!(date.precision, DatePrecision::Day));
+
+        assert!(DateWithPrecision::parse_partial("unknown").is_none());
+    }
+}
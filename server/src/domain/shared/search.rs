@@ -0,0 +1,22 @@
+#![expect(clippy::option_if_let_else, reason = "macro")]
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// A search result with the matched parts of its text highlighted
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SearchHit<T> {
+    #[serde(flatten)]
+    pub item: T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight: Option<Highlight>,
+}
+
+/// HTML-escaped snippets with matches wrapped in `<mark>`
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, ToSchema)]
+pub struct Highlight {
+    pub short_description: Option<String>,
+    pub description: Option<String>,
+}
@@ -1,4 +1,9 @@
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use derive_more::Display;
 use entity::enums::EntityType;
+use macros::{ApiError, IntoErrorSchema};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -6,8 +11,11 @@ use crate::domain::artist::SimpleArtist;
 use crate::domain::correction::CorrectionEntity;
 use crate::domain::credit_role::CreditRoleRef;
 use crate::domain::release::SimpleRelease;
-use crate::domain::shared::{EntityIdent, Language, NewLocalizedName};
+use crate::domain::shared::{
+    EntityIdent, ExternalId, Language, NewExternalId, NewLocalizedName,
+};
 use crate::domain::song_lyrics::SongLyrics;
+use crate::domain::source_work::SourceTrackRef;
 
 #[serde_with::apply(
     Vec => #[serde(skip_serializing_if = "Vec::is_empty")],
@@ -21,7 +29,10 @@ pub struct Song {
     pub credits: Vec<SongCredit>,
     pub languages: Vec<Language>,
     pub localized_titles: Vec<LocalizedTitle>,
+    pub external_ids: Vec<ExternalId>,
     pub lyrics: Vec<SongLyrics>,
+    /// Original tracks this song arranges
+    pub source_tracks: Vec<SourceTrackRef>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -51,6 +62,8 @@ pub struct NewSong {
     pub credits: Option<Vec<NewSongCredit>>,
     pub languages: Option<Vec<i32>>,
     pub localized_titles: Option<Vec<NewLocalizedName>>,
+    pub external_ids: Option<Vec<NewExternalId>>,
+    pub source_tracks: Option<Vec<i32>>,
 }
 
 #[derive(Deserialize, ToSchema)]
@@ -60,6 +73,43 @@ pub struct NewSongCredit {
     pub role_id: Option<i32>,
 }
 
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[snafu(display("Validation error: {kind}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ValidationError {
+    pub kind: ValidationErrorKind,
+}
+
+impl From<ValidationErrorKind> for ValidationError {
+    fn from(kind: ValidationErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum ValidationErrorKind {
+    #[display("Source track {_0} does not exist")]
+    UnknownSourceTrack(i32),
+}
+
+impl NewSong {
+    /// `existing` holds the ids of source tracks that exist
+    pub fn validate_source_tracks(
+        &self,
+        existing: &HashSet<i32>,
+    ) -> Result<(), ValidationError> {
+        for id in self.source_tracks.iter().flatten() {
+            if !existing.contains(id) {
+                return Err(ValidationErrorKind::UnknownSourceTrack(*id).into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl CorrectionEntity for NewSong {
     fn entity_type() -> EntityType {
         EntityType::Song
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use super::model::NewSong;
 use crate::domain::Transaction;
 
@@ -15,6 +17,12 @@ where
         correction: &NewSong,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Ids among `ids` of source work tracks that exist
+    async fn find_existing_source_tracks(
+        &self,
+        ids: &[i32],
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
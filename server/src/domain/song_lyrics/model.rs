@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::domain::correction::CorrectionEntity;
-use crate::domain::shared::Language;
+use crate::domain::shared::{ExternalId, Language, NewExternalId};
 
 #[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct SongLyrics {
@@ -17,6 +17,7 @@ pub struct SongLyrics {
     pub content: String,
     pub is_main: bool,
     pub language: Language,
+    pub external_ids: Vec<ExternalId>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema)]
@@ -25,6 +26,7 @@ pub struct NewSongLyrics {
     pub language_id: i32,
     pub content: String,
     pub is_main: bool,
+    pub external_ids: Option<Vec<NewExternalId>>,
 }
 
 #[derive(Debug, snafu::Snafu, ApiError)]
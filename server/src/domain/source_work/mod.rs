@@ -0,0 +1,5 @@
+mod model;
+mod repo;
+
+pub use model::*;
+pub use repo::*;
@@ -0,0 +1,189 @@
+use std::backtrace::Backtrace;
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use derive_more::Display;
+use entity::enums::EntityType;
+pub use entity::sea_orm_active_enums::SourceWorkKind;
+use macros::ApiError;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::correction::CorrectionEntity;
+use crate::domain::shared::{
+    DateWithPrecision, EntityIdent, ExternalId, NewExternalId,
+};
+
+/// An original work, such as a Touhou game or one of ZUN's music CDs,
+/// whose tracks are the sources of arrangements
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SourceWork {
+    pub id: i32,
+    pub title: String,
+    pub kind: SourceWorkKind,
+    pub release_date: Option<DateWithPrecision>,
+    pub description: String,
+    pub external_ids: Vec<ExternalId>,
+    pub tracks: Vec<SourceWorkTrack>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SimpleSourceWork {
+    pub id: i32,
+    pub title: String,
+    pub kind: SourceWorkKind,
+}
+
+impl From<entity::source_work::Model> for SimpleSourceWork {
+    fn from(model: entity::source_work::Model) -> Self {
+        Self {
+            id: model.id,
+            title: model.title,
+            kind: model.kind,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SourceWorkTrack {
+    pub id: i32,
+    pub track_number: Option<String>,
+    pub title: String,
+}
+
+/// An original track as referenced from the songs arranging it
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SourceTrackRef {
+    pub id: i32,
+    pub title: String,
+    pub source_work: SimpleSourceWork,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewSourceWork {
+    pub title: EntityIdent,
+    pub kind: SourceWorkKind,
+    pub release_date: Option<DateWithPrecision>,
+    pub description: Option<String>,
+    pub external_ids: Option<Vec<NewExternalId>>,
+    pub tracks: Option<Vec<NewSourceWorkTrack>>,
+}
+
+#[derive(Deserialize, ToSchema)]
+pub struct NewSourceWorkTrack {
+    /// Id of the existing track to edit, omitted for new tracks.
+    /// Keeping the id keeps the songs linked to the track.
+    #[serde(default)]
+    pub id: Option<i32>,
+    pub track_number: Option<String>,
+    pub title: String,
+}
+
+#[derive(Debug, snafu::Snafu, ApiError)]
+#[snafu(display("Validation error: {kind}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ValidationError {
+    pub kind: ValidationErrorKind,
+    pub backtrace: Backtrace,
+}
+
+impl From<ValidationErrorKind> for ValidationError {
+    fn from(kind: ValidationErrorKind) -> Self {
+        Self {
+            kind,
+            backtrace: Backtrace::capture(),
+        }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum ValidationErrorKind {
+    #[display("Track title is empty")]
+    EmptyTrackTitle,
+    #[display("Track {_0} is listed more than once")]
+    DuplicateTrack(i32),
+    #[display("Track {_0} does not belong to this source work")]
+    UnknownTrack(i32),
+}
+
+use ValidationErrorKind::*;
+
+impl NewSourceWork {
+    /// Validate the tracks against the ids of the tracks the work
+    /// currently has, which is empty when creating a new work
+    pub fn validate(
+        &self,
+        existing_track_ids: &[i32],
+    ) -> Result<(), ValidationError> {
+        let mut seen = HashSet::new();
+
+        for track in self.tracks.iter().flatten() {
+            if track.title.trim().is_empty() {
+                return Err(EmptyTrackTitle.into());
+            }
+
+            if let Some(id) = track.id {
+                if !existing_track_ids.contains(&id) {
+                    return Err(UnknownTrack(id).into());
+                }
+                if !seen.insert(id) {
+                    return Err(DuplicateTrack(id).into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CorrectionEntity for NewSourceWork {
+    fn entity_type() -> EntityType {
+        EntityType::SourceWork
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn new_work(tracks: &serde_json::Value) -> NewSourceWork {
+        serde_json::from_value(json!({
+            "title": "東方紅魔郷",
+            "kind": "Game",
+            "tracks": tracks,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn validate_tracks() {
+        let work = new_work(&json!([
+            { "id": 1, "title": "赤より紅い夢" },
+            { "title": "ほおずきみたいに紅い魂" },
+        ]));
+        assert!(work.validate(&[1, 2]).is_ok());
+        assert!(matches!(
+            work.validate(&[]).unwrap_err().kind,
+            UnknownTrack(1)
+        ));
+
+        let work = new_work(&json!([
+            { "id": 1, "title": "a" },
+            { "id": 1, "title": "b" },
+        ]));
+        assert!(matches!(
+            work.validate(&[1]).unwrap_err().kind,
+            DuplicateTrack(1)
+        ));
+
+        let work = new_work(&json!([{ "title": " " }]));
+        assert!(matches!(
+            work.validate(&[]).unwrap_err().kind,
+            EmptyTrackTitle
+        ));
+    }
+}
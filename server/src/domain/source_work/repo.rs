@@ -0,0 +1,28 @@
+use super::model::NewSourceWork;
+use crate::domain::Transaction;
+
+pub trait TxRepo: Transaction
+where
+    Self::apply_update(..): Send,
+{
+    async fn create(
+        &self,
+        data: &NewSourceWork,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn create_history(
+        &self,
+        data: &NewSourceWork,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
+
+    async fn apply_update(
+        &self,
+        correction: entity::correction::Model,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Ids of the tracks the source work currently has
+    async fn find_track_ids(
+        &self,
+        id: i32,
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>>;
+}
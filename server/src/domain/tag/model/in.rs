@@ -1,10 +1,38 @@
+use std::collections::HashSet;
+
+use axum::http::StatusCode;
+use derive_more::Display;
 use entity::enums::EntityType;
 use entity::sea_orm_active_enums::{TagRelationType, TagType};
+use macros::{ApiError, IntoErrorSchema};
 use serde::Deserialize;
 use utoipa::ToSchema;
 
 use crate::domain::correction::CorrectionEntity;
-use crate::domain::shared::EntityIdent;
+use crate::domain::shared::{EntityIdent, NewExternalId};
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[snafu(display("Validation error: {kind}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ValidationError {
+    pub kind: ValidationErrorKind,
+}
+
+impl From<ValidationErrorKind> for ValidationError {
+    fn from(kind: ValidationErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+#[derive(Debug, Display)]
+pub enum ValidationErrorKind {
+    #[display("A tag cannot be related to itself")]
+    SelfRelation,
+    #[display("Tag relations would form a cycle through tag {_0}")]
+    RelationCycle(i32),
+}
 
 #[derive(Deserialize, ToSchema)]
 pub struct NewTag {
@@ -13,6 +41,7 @@ pub struct NewTag {
     pub short_description: Option<String>,
     pub description: Option<String>,
     pub alt_names: Option<Vec<String>>,
+    pub external_ids: Option<Vec<NewExternalId>>,
     pub relations: Option<Vec<NewTagRelation>>,
 }
 
@@ -22,8 +51,86 @@ pub struct NewTagRelation {
     pub r#type: TagRelationType,
 }
 
+impl NewTag {
+    pub fn related_tag_ids(&self) -> impl Iterator<Item = i32> + '_ {
+        self.relations
+            .iter()
+            .flatten()
+            .map(|relation| relation.related_tag_id)
+    }
+
+    /// Relations point from a tag to its parents, so linking tag `id` to any
+    /// of its descendants would close a loop
+    pub fn validate_hierarchy(
+        &self,
+        id: i32,
+        descendant_ids: &HashSet<i32>,
+    ) -> Result<(), ValidationError> {
+        for related_tag_id in self.related_tag_ids() {
+            if related_tag_id == id {
+                return Err(ValidationErrorKind::SelfRelation.into());
+            }
+            if descendant_ids.contains(&related_tag_id) {
+                return Err(
+                    ValidationErrorKind::RelationCycle(related_tag_id).into()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl CorrectionEntity for NewTag {
     fn entity_type() -> EntityType {
         EntityType::Tag
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_tag(related: &[i32]) -> NewTag {
+        let relations = related
+            .iter()
+            .map(|id| serde_json::json!({ "related_tag_id": id, "type": "Inherit" }))
+            .collect::<Vec<_>>();
+
+        serde_json::from_value(serde_json::json!({
+            "name": "Trance",
+            "type": "Genre",
+            "relations": relations,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reject_self_relation() {
+        let err = new_tag(&[2, 1]).validate_hierarchy(1, &HashSet::new());
+        assert!(matches!(
+            err,
+            Err(ValidationError {
+                kind: ValidationErrorKind::SelfRelation
+            })
+        ));
+    }
+
+    #[test]
+    fn reject_relation_to_descendant() {
+        let descendants = HashSet::from([3, 4]);
+        let err = new_tag(&[2, 4]).validate_hierarchy(1, &descendants);
+        assert!(matches!(
+            err,
+            Err(ValidationError {
+                kind: ValidationErrorKind::RelationCycle(4)
+            })
+        ));
+    }
+
+    #[test]
+    fn accept_relation_to_unrelated_tag() {
+        let descendants = HashSet::from([3, 4]);
+        assert!(new_tag(&[2, 5]).validate_hierarchy(1, &descendants).is_ok());
+    }
+}
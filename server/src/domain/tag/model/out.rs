@@ -2,6 +2,8 @@ use entity::sea_orm_active_enums::{TagRelationType, TagType};
 use serde::Serialize;
 use utoipa::ToSchema;
 
+use crate::domain::shared::ExternalId;
+
 #[serde_with::apply(
     Vec => #[serde(skip_serializing_if = "Vec::is_empty")]
 )]
@@ -13,6 +15,7 @@ pub struct Tag {
     pub short_description: String,
     pub description: String,
     pub alt_names: Vec<AlternativeName>,
+    pub external_ids: Vec<ExternalId>,
     pub relations: Vec<TagRelation>,
 }
 
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use super::model::NewTag;
 use crate::domain::Transaction;
 
@@ -15,6 +17,12 @@ where
         correction: &NewTag,
     ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>>;
 
+    /// Ids of all tags that reach `tag_id` by following relations
+    async fn find_descendant_ids(
+        &self,
+        tag_id: i32,
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>>;
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
@@ -2,11 +2,17 @@ use utoipa_axum::router::OpenApiRouter;
 
 use crate::adapter::inbound::rest::state::ArcAppState;
 
+mod collaboration;
 pub mod find;
+mod lineup;
 mod release;
+mod stats;
 
 pub fn router() -> OpenApiRouter<ArcAppState> {
     OpenApiRouter::new()
+        .merge(collaboration::router())
         .merge(find::router())
+        .merge(lineup::router())
         .merge(release::router())
+        .merge(stats::router())
 }
@@ -0,0 +1,131 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::{CollaborationGraph, Collaborator, GraphFormat};
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Artist";
+
+const DEFAULT_COLLABORATORS: u32 = 20;
+const MAX_COLLABORATORS: u32 = 100;
+
+const DEFAULT_GRAPH_DEPTH: u8 = 1;
+const MAX_GRAPH_DEPTH: u8 = 3;
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| {
+            r.routes(routes!(find_artist_collaborators))
+                .routes(routes!(find_artist_collaboration_graph))
+        })
+        .finish()
+}
+
+data!(
+    DataVecCollaborator, Vec<Collaborator>
+    DataOptionCollaborationGraph, Option<CollaborationGraph>
+);
+
+#[derive(Deserialize, IntoParams)]
+struct CollaboratorQuery {
+    #[param(minimum = 1, maximum = 100)]
+    limit: Option<u32>,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/artist/{id}/collaborators",
+    params(
+        ("id" = i32, Path),
+        CollaboratorQuery,
+    ),
+    responses(
+        (status = 200, body = DataVecCollaborator),
+        (status = 404, description = "Artist not found"),
+        Error,
+    ),
+)]
+async fn find_artist_collaborators(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(query): Query<CollaboratorQuery>,
+) -> Result<Response, Error> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_COLLABORATORS)
+        .clamp(1, MAX_COLLABORATORS);
+
+    let collaborators =
+        super::repo::find_collaborators(&repo, id, limit).await?;
+
+    Ok(collaborators.map_or_else(
+        || StatusCode::NOT_FOUND.into_response(),
+        |collaborators| Data::new(collaborators).into_response(),
+    ))
+}
+
+#[derive(Deserialize, IntoParams)]
+struct GraphQuery {
+    /// Number of hops from the artist, defaults to 1
+    #[param(minimum = 1, maximum = 3)]
+    depth: Option<u8>,
+    #[serde(default)]
+    #[param(inline)]
+    format: GraphFormat,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/artist/{id}/collaboration-graph",
+    params(
+        ("id" = i32, Path),
+        GraphQuery,
+    ),
+    responses(
+        (
+            status = 200,
+            content(
+                (DataOptionCollaborationGraph = "application/json"),
+                (String = "application/graphml+xml"),
+            ),
+        ),
+        (status = 404, description = "Artist not found, GraphML only"),
+        Error,
+    ),
+)]
+async fn find_artist_collaboration_graph(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(query): Query<GraphQuery>,
+) -> Result<Response, Error> {
+    let depth = query
+        .depth
+        .unwrap_or(DEFAULT_GRAPH_DEPTH)
+        .clamp(1, MAX_GRAPH_DEPTH);
+
+    let graph = super::repo::find_graph(&repo, id, depth).await?;
+
+    Ok(match query.format {
+        GraphFormat::Json => Data::new(graph).into_response(),
+        GraphFormat::GraphMl => graph.map_or_else(
+            || StatusCode::NOT_FOUND.into_response(),
+            |graph| {
+                (
+                    [(header::CONTENT_TYPE, "application/graphml+xml")],
+                    graph.to_graphml(),
+                )
+                    .into_response()
+            },
+        ),
+    })
+}
@@ -0,0 +1,139 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::artist::SimpleArtist;
+use crate::domain::credit_role::CreditRoleRef;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Collaborator {
+    pub artist: SimpleArtist,
+    /// Number of releases both artists took part in
+    pub shared_release_count: i32,
+    /// Credit roles of the collaborator on the shared releases
+    pub roles: Vec<CreditRoleRef>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CollaborationGraph {
+    pub nodes: Vec<CollaborationNode>,
+    pub edges: Vec<CollaborationEdge>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CollaborationNode {
+    pub id: i32,
+    pub name: String,
+    /// Number of hops from the requested artist, which is at 0
+    pub depth: u8,
+}
+
+/// Edges are undirected, `source` is always the smaller id
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CollaborationEdge {
+    pub source: i32,
+    pub target: i32,
+    /// Number of shared releases
+    pub weight: i32,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    #[default]
+    Json,
+    GraphMl,
+}
+
+impl CollaborationGraph {
+    pub fn to_graphml(&self) -> String {
+        let mut out = String::from(concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            "\n",
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#,
+            "\n",
+            r#"  <key id="name" for="node" attr.name="name" attr.type="string"/>"#,
+            "\n",
+            r#"  <key id="depth" for="node" attr.name="depth" attr.type="int"/>"#,
+            "\n",
+            r#"  <key id="weight" for="edge" attr.name="weight" attr.type="int"/>"#,
+            "\n",
+            r#"  <graph id="collaboration" edgedefault="undirected">"#,
+            "\n",
+        ));
+
+        for node in &self.nodes {
+            let _ = writeln!(
+                out,
+                r#"    <node id="a{}"><data key="name">{}</data><data key="depth">{}</data></node>"#,
+                node.id,
+                escape_xml(&node.name),
+                node.depth
+            );
+        }
+
+        for edge in &self.edges {
+            let _ = writeln!(
+                out,
+                r#"    <edge source="a{}" target="a{}"><data key="weight">{}</data></edge>"#,
+                edge.source, edge.target, edge.weight
+            );
+        }
+
+        out.push_str("  </graph>\n</graphml>\n");
+        out
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graphml_escapes_names() {
+        let graph = CollaborationGraph {
+            nodes: vec![
+                CollaborationNode {
+                    id: 1,
+                    name: "A & <B>".to_string(),
+                    depth: 0,
+                },
+                CollaborationNode {
+                    id: 2,
+                    name: "C".to_string(),
+                    depth: 1,
+                },
+            ],
+            edges: vec![CollaborationEdge {
+                source: 1,
+                target: 2,
+                weight: 3,
+            }],
+        };
+
+        let graphml = graph.to_graphml();
+
+        assert!(graphml.contains(
+            r#"<node id="a1"><data key="name">A &amp; &lt;B&gt;</data><data key="depth">0</data></node>"#
+        ));
+        assert!(graphml.contains(
+            r#"<edge source="a1" target="a2"><data key="weight">3</data></edge>"#
+        ));
+    }
+}
@@ -0,0 +1,250 @@
+use std::collections::{BTreeMap, HashMap};
+
+use entity::{artist, credit_role};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult,
+    QueryFilter, Statement,
+};
+
+use super::model::{
+    CollaborationEdge, CollaborationGraph, CollaborationNode, Collaborator,
+};
+use crate::domain::Connection;
+use crate::domain::artist::SimpleArtist;
+use crate::domain::credit_role::CreditRoleRef;
+
+/// Stops the graph from growing to the whole database on hub artists
+const MAX_GRAPH_NODES: usize = 500;
+
+/// Every `(artist, release, role)` an artist took part in, either as a
+/// release or track artist, or through a credit on the release or on one of
+/// its songs. `role_id` is null for artist entries.
+///
+/// Not materialized so that filters on `artist_id` and `release_id` are
+/// pushed down into each branch.
+const PARTICIPATION_CTE: &str = r"
+    participation AS NOT MATERIALIZED (
+        SELECT artist_id, release_id, NULL::int AS role_id
+        FROM release_artist
+        UNION
+        SELECT rta.artist_id, rt.release_id, NULL
+        FROM release_track_artist rta
+        JOIN release_track rt ON rt.id = rta.track_id
+        UNION
+        SELECT artist_id, release_id, role_id
+        FROM release_credit
+        UNION
+        SELECT sa.artist_id, rt.release_id, NULL
+        FROM song_artist sa
+        JOIN release_track rt ON rt.song_id = sa.song_id
+        UNION
+        SELECT sc.artist_id, rt.release_id, sc.role_id
+        FROM song_credit sc
+        JOIN release_track rt ON rt.song_id = sc.song_id
+    )";
+
+#[derive(FromQueryResult)]
+struct CollaboratorRow {
+    id: i32,
+    name: String,
+    shared_release_count: i32,
+    role_ids: Vec<i32>,
+}
+
+#[derive(FromQueryResult)]
+struct EdgeRow {
+    source: i32,
+    target: i32,
+    weight: i32,
+}
+
+pub(super) async fn find_collaborators<R>(
+    repo: &R,
+    artist_id: i32,
+    limit: u32,
+) -> Result<Option<Vec<Collaborator>>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    if artist::Entity::find_by_id(artist_id)
+        .one(db)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    let sql = format!(
+        r"
+        WITH {PARTICIPATION_CTE},
+        own AS (
+            SELECT DISTINCT release_id FROM participation WHERE artist_id = $1
+        )
+        SELECT
+            a.id,
+            a.name,
+            COUNT(DISTINCT p.release_id)::int AS shared_release_count,
+            array_remove(array_agg(DISTINCT p.role_id), NULL) AS role_ids
+        FROM participation p
+        JOIN own ON own.release_id = p.release_id
+        JOIN artist a ON a.id = p.artist_id
+        WHERE p.artist_id <> $1
+        GROUP BY a.id, a.name
+        ORDER BY shared_release_count DESC, a.id
+        LIMIT $2"
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        sql,
+        [artist_id.into(), limit.into()],
+    );
+
+    let rows = CollaboratorRow::find_by_statement(stmt).all(db).await?;
+
+    let role_ids = rows
+        .iter()
+        .flat_map(|row| row.role_ids.iter().copied())
+        .unique()
+        .collect_vec();
+
+    let roles: HashMap<i32, CreditRoleRef> = credit_role::Entity::find()
+        .filter(credit_role::Column::Id.is_in(role_ids))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|role| {
+            (
+                role.id,
+                CreditRoleRef {
+                    id: role.id,
+                    name: role.name,
+                },
+            )
+        })
+        .collect();
+
+    Ok(Some(
+        rows.into_iter()
+            .map(|row| Collaborator {
+                artist: SimpleArtist {
+                    id: row.id,
+                    name: row.name,
+                },
+                shared_release_count: row.shared_release_count,
+                roles: row
+                    .role_ids
+                    .iter()
+                    .filter_map(|id| roles.get(id))
+                    .cloned()
+                    .collect(),
+            })
+            .collect(),
+    ))
+}
+
+/// Breadth-first walk from `artist_id`, up to `depth` hops
+///
+/// Edges between the outermost nodes are kept, so the graph contains every
+/// collaboration between the returned artists.
+pub(super) async fn find_graph<R>(
+    repo: &R,
+    artist_id: i32,
+    depth: u8,
+) -> Result<Option<CollaborationGraph>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let Some(root) = artist::Entity::find_by_id(artist_id).one(db).await?
+    else {
+        return Ok(None);
+    };
+
+    let mut depths = HashMap::from([(root.id, 0)]);
+    let mut edges = BTreeMap::new();
+    let mut frontier = vec![root.id];
+
+    for level in 1..=depth + 1 {
+        if frontier.is_empty() {
+            break;
+        }
+
+        let expand = level <= depth;
+        let mut next = vec![];
+
+        for row in find_edges(db, &frontier).await? {
+            if !depths.contains_key(&row.target) {
+                if !expand || depths.len() >= MAX_GRAPH_NODES {
+                    continue;
+                }
+                depths.insert(row.target, level);
+                next.push(row.target);
+            }
+
+            let key = (row.source.min(row.target), row.source.max(row.target));
+            edges.insert(key, row.weight);
+        }
+
+        frontier = next;
+    }
+
+    let nodes = artist::Entity::find()
+        .filter(artist::Column::Id.is_in(depths.keys().copied()))
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|artist| CollaborationNode {
+            depth: depths[&artist.id],
+            id: artist.id,
+            name: artist.name,
+        })
+        .sorted_by_key(|node| (node.depth, node.id))
+        .collect();
+
+    let edges = edges
+        .into_iter()
+        .map(|((source, target), weight)| CollaborationEdge {
+            source,
+            target,
+            weight,
+        })
+        .collect();
+
+    Ok(Some(CollaborationGraph { nodes, edges }))
+}
+
+/// Collaborations of the given artists, weighted by shared releases
+async fn find_edges(
+    db: &impl ConnectionTrait,
+    artist_ids: &[i32],
+) -> Result<Vec<EdgeRow>, DbErr> {
+    let sql = format!(
+        r"
+        WITH {PARTICIPATION_CTE}
+        SELECT
+            a.artist_id AS source,
+            b.artist_id AS target,
+            COUNT(DISTINCT a.release_id)::int AS weight
+        FROM participation a
+        JOIN participation b
+            ON b.release_id = a.release_id AND b.artist_id <> a.artist_id
+        WHERE a.artist_id = ANY($1)
+        GROUP BY a.artist_id, b.artist_id
+        ORDER BY weight DESC, source, target"
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        sql,
+        [artist_ids.to_vec().into()],
+    );
+
+    EdgeRow::find_by_statement(stmt).all(db).await
+}
@@ -1,11 +1,15 @@
-use entity::artist;
+use entity::{artist, artist_tag_vote};
 use enumset::EnumSet;
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, Select};
+use sea_orm::{
+    ColumnTrait, EntityName, EntityTrait, IdenStatic, QueryFilter, Select,
+};
 use sea_query::{Cond, SimpleExpr};
 use serde::Deserialize;
+use serde_with::{DisplayFromStr, OneOrMany, serde_as};
 use utoipa::{IntoParams, ToSchema};
 
 use crate::domain::artist::ArtistType;
+use crate::infra::database::sea_orm::tag_hierarchy;
 pub use crate::shared::http::{
     CorrectionSortField, PaginationQuery, SortDirection,
 };
@@ -49,6 +53,7 @@ impl From<CommonFilter> for SimpleExpr {
     }
 }
 
+#[serde_as]
 #[derive(Clone, Debug, Default, Deserialize, ToSchema, IntoParams)]
 #[schema(as = ArtistFilter)]
 pub struct ArtistFilter {
@@ -57,6 +62,14 @@ pub struct ArtistFilter {
     #[serde(default, rename = "artist_type")]
     pub artist_types: Option<EnumSet<ArtistType>>,
 
+    #[serde_as(as = "Option<OneOrMany<DisplayFromStr>>")]
+    #[serde(default, rename = "tag_id", alias = "tag_id[]")]
+    pub tag_ids: Option<Vec<i32>>,
+
+    /// Also match entities voted with a subtag of `tag_id`
+    #[serde(default)]
+    pub include_subtags: bool,
+
     #[serde(default)]
     pub sort_field: Option<CorrectionSortField>,
 
@@ -81,6 +94,16 @@ impl ArtistFilter {
                 select.filter(artist::Column::ArtistType.is_in(*artist_types));
         }
 
+        if let Some(tag_ids) = &self.tag_ids {
+            select = select.filter(tag_hierarchy::tagged_with(
+                artist::Entity.table_name(),
+                artist_tag_vote::Entity.table_name(),
+                artist_tag_vote::Column::ArtistId.as_str(),
+                tag_ids,
+                self.include_subtags,
+            ));
+        }
+
         select
     }
 }
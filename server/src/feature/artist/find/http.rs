@@ -1,6 +1,7 @@
 use axum::extract::{Path, Query, State};
 use libfp::BifunctorExt;
 use serde::Deserialize;
+use url::Url;
 use utoipa::IntoParams;
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
@@ -22,6 +23,7 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
         .with_public(|r| {
             r.routes(routes!(find_artist_by_id))
                 .routes(routes!(find_many_artist))
+                .routes(routes!(find_artist_by_link))
                 .routes(routes!(explore_artist))
         })
         .finish()
@@ -91,6 +93,38 @@ async fn find_many_artist(
         .bimap_into()
 }
 
+#[derive(Deserialize, IntoParams)]
+struct FindByLinkQuery {
+    /// Profile url, matched after canonicalization
+    #[param(value_type = String, format = Uri)]
+    url: Url,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/artist/by-link",
+    params(
+        FindByLinkQuery,
+        CommonFilter
+    ),
+    responses(
+        (status = 200, body = DataVecArtist),
+        Error,
+    ),
+)]
+async fn find_artist_by_link(
+    State(repo): State<state::SeaOrmRepository>,
+    Query(query): Query<FindByLinkQuery>,
+    axum_extra::extract::Query(common): axum_extra::extract::Query<
+        CommonFilter,
+    >,
+) -> Result<Data<Vec<Artist>>, Error> {
+    repo::find_by_link(&repo, &query.url, common)
+        .await
+        .bimap_into()
+}
+
 #[utoipa::path(
     get,
     tag = TAG,
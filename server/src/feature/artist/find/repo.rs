@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-use entity::sea_orm_active_enums::ArtistImageType;
+use entity::sea_orm_active_enums::{ArtistImageType, EntityType};
 use entity::{
     artist, artist_alias, artist_image, artist_link, artist_localized_name,
     artist_membership, artist_membership_role, artist_membership_tenure,
@@ -10,19 +10,21 @@ use entity::{
 use itertools::{Itertools, izip};
 use sea_orm::{
     ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
-    FromQueryResult, LoaderTrait, QueryFilter, QueryOrder, Select,
+    FromQueryResult, LoaderTrait, QueryFilter, QueryOrder, QuerySelect,
+    QueryTrait, Select,
 };
 use sea_query::extension::postgres::PgBinOper;
 use sea_query::{ExprTrait, Func, SimpleExpr};
+use url::Url;
 
 use super::{CommonFilter, FindManyFilter};
 use crate::domain::Connection;
 use crate::domain::artist::{Artist, Membership, Tenure};
 use crate::domain::credit_role::CreditRoleRef;
-use crate::domain::shared::{LocalizedName, Location};
-use crate::infra::database::sea_orm::utils;
+use crate::domain::shared::{Link, LocalizedName, Location, canonicalize};
+use crate::infra::database::sea_orm::{external_id, utils};
 
-pub(super) async fn find_one<R>(
+pub(crate) async fn find_one<R>(
     repo: &R,
     id: i32,
     common: CommonFilter,
@@ -67,6 +69,34 @@ where
     find_many_impl(select, repo.conn()).await
 }
 
+/// Artists with a link matching `url` once canonicalized
+pub(super) async fn find_by_link<R>(
+    repo: &R,
+    url: &Url,
+    common: CommonFilter,
+) -> Result<Vec<Artist>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let url = canonicalize(url);
+
+    let select = artist::Entity::find()
+        .filter(
+            artist::Column::Id.in_subquery(
+                artist_link::Entity::find()
+                    .select_only()
+                    .column(artist_link::Column::ArtistId)
+                    .filter(artist_link::Column::Url.eq(url.as_str()))
+                    .into_query(),
+            ),
+        )
+        .filter(SimpleExpr::from(common))
+        .order_by_asc(artist::Column::Id);
+
+    find_many_impl(select, repo.conn()).await
+}
+
 #[derive(FromQueryResult)]
 struct ArtistImage {
     artist_id: i32,
@@ -76,7 +106,7 @@ struct ArtistImage {
 }
 
 #[expect(clippy::too_many_lines, reason = "TODO")]
-async fn find_many_impl(
+pub(crate) async fn find_many_impl(
     select: Select<artist::Entity>,
     db: &impl ConnectionTrait,
 ) -> Result<Vec<Artist>, DbErr> {
@@ -124,6 +154,9 @@ async fn find_many_impl(
         .unzip();
 
     let links = artists.load_many(artist_link::Entity, db).await?;
+    let mut external_ids =
+        external_id::find_by_entity_ids(EntityType::Artist, ids.clone(), db)
+            .await?;
     let localized_names =
         artists.load_many(artist_localized_name::Entity, db).await?;
 
@@ -260,7 +293,13 @@ async fn find_many_impl(
                 end_date,
 
                 aliases,
-                links: links.into_iter().map(|x| x.url).collect_vec(),
+                links: links
+                    .into_iter()
+                    .map(|x| Link::from_stored(x.url))
+                    .collect_vec(),
+                external_ids: external_ids
+                    .remove(&artist.id)
+                    .unwrap_or_default(),
                 localized_names,
                 start_location: Location {
                     country: artist.start_location_country,
@@ -345,13 +384,10 @@ where
         return Ok(crate::domain::shared::Paginated::nothing());
     }
 
-    let mut select = artist::Entity::find()
+    let select = filter
+        .into_select()
         .filter(artist::Column::Id.is_in(entity_ids.clone()));
 
-    if let Some(artist_types) = filter.artist_types {
-        select = select.filter(artist::Column::ArtistType.is_in(artist_types));
-    }
-
     let mut artists = find_many_impl(select, repo.conn()).await?;
 
     artists = crate::infra::database::sea_orm::utils::sort_by_id_list(
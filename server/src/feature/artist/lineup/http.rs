@@ -0,0 +1,76 @@
+use axum::extract::{Path, Query, State};
+use chrono::NaiveDate;
+use libfp::BifunctorExt;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::{Lineup, LineupTimeline};
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Artist";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| {
+            r.routes(routes!(find_artist_lineup))
+                .routes(routes!(find_artist_lineup_timeline))
+        })
+        .finish()
+}
+
+data!(
+    DataOptionLineup, Option<Lineup>
+    DataOptionLineupTimeline, Option<LineupTimeline>
+);
+
+#[derive(Deserialize, IntoParams)]
+struct LineupQuery {
+    at: NaiveDate,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/artist/{id}/lineup",
+    params(
+        ("id" = i32, Path),
+        LineupQuery,
+    ),
+    responses(
+        (status = 200, body = DataOptionLineup),
+        Error,
+    ),
+)]
+async fn find_artist_lineup(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(query): Query<LineupQuery>,
+) -> Result<Data<Option<Lineup>>, Error> {
+    super::repo::find_lineup(&repo, id, query.at)
+        .await
+        .bimap_into()
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/artist/{id}/lineup/timeline",
+    params(
+        ("id" = i32, Path),
+    ),
+    responses(
+        (status = 200, body = DataOptionLineupTimeline),
+        Error,
+    ),
+)]
+async fn find_artist_lineup_timeline(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<LineupTimeline>>, Error> {
+    super::repo::find_timeline(&repo, id).await.bimap_into()
+}
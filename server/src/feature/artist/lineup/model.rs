@@ -0,0 +1,60 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::artist::{SimpleArtist, Tenure};
+use crate::domain::credit_role::CreditRoleRef;
+use crate::domain::shared::DateWithPrecision;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Lineup {
+    pub at: NaiveDate,
+    pub members: Vec<LineupMember>,
+    /// Members without any recorded tenure, who may or may not have been in
+    /// the group at that date
+    pub undated_members: Vec<LineupMember>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LineupTimeline {
+    /// Dated releases of the group, oldest first
+    pub releases: Vec<ReleaseLineup>,
+    /// Members without any recorded tenure, they are left out of every
+    /// release lineup
+    pub undated_members: Vec<LineupMember>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ReleaseLineup {
+    pub release_id: i32,
+    pub title: String,
+    pub release_date: DateWithPrecision,
+    pub members: Vec<LineupMember>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LineupMember {
+    pub artist: SimpleArtist,
+    pub roles: Vec<CreditRoleRef>,
+    pub tenure: Vec<Tenure>,
+}
+
+impl LineupMember {
+    pub const fn is_undated(&self) -> bool {
+        self.tenure.is_empty()
+    }
+
+    /// Tenures only have a year, so a member is counted for the whole year
+    /// they joined or left in
+    pub fn active_in(&self, year: i16) -> bool {
+        self.tenure.iter().any(|tenure| tenure.contains_year(year))
+    }
+}
+
+/// Years outside of `i16` can't match any tenure anyway
+pub fn year_of(date: NaiveDate) -> i16 {
+    use chrono::Datelike;
+
+    let year = date.year().clamp(i16::MIN.into(), i16::MAX.into());
+    i16::try_from(year).unwrap_or_default()
+}
@@ -0,0 +1,164 @@
+use chrono::NaiveDate;
+use entity::{
+    artist, artist_membership, artist_membership_role,
+    artist_membership_tenure, credit_role, release, release_artist,
+};
+use itertools::{Itertools, izip};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, LoaderTrait, QueryFilter,
+    QueryOrder,
+};
+
+use super::model::{
+    Lineup, LineupMember, LineupTimeline, ReleaseLineup, year_of,
+};
+use crate::domain::Connection;
+use crate::domain::artist::{SimpleArtist, Tenure};
+use crate::domain::credit_role::CreditRoleRef;
+
+pub(super) async fn find_lineup<R>(
+    repo: &R,
+    group_id: i32,
+    at: NaiveDate,
+) -> Result<Option<Lineup>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let Some(members) = find_members(repo.conn(), group_id).await? else {
+        return Ok(None);
+    };
+
+    let year = year_of(at);
+    let (undated_members, members): (Vec<_>, Vec<_>) =
+        members.into_iter().partition(LineupMember::is_undated);
+
+    Ok(Some(Lineup {
+        at,
+        members: members
+            .into_iter()
+            .filter(|member| member.active_in(year))
+            .collect(),
+        undated_members,
+    }))
+}
+
+pub(super) async fn find_timeline<R>(
+    repo: &R,
+    group_id: i32,
+) -> Result<Option<LineupTimeline>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let Some(members) = find_members(db, group_id).await? else {
+        return Ok(None);
+    };
+
+    let (undated_members, members): (Vec<_>, Vec<_>) =
+        members.into_iter().partition(LineupMember::is_undated);
+
+    let releases = release::Entity::find()
+        .inner_join(release_artist::Entity)
+        .filter(release_artist::Column::ArtistId.eq(group_id))
+        .filter(release::Column::ReleaseDate.is_not_null())
+        .order_by_asc(release::Column::ReleaseDate)
+        .order_by_asc(release::Column::Id)
+        .all(db)
+        .await?;
+
+    let releases = releases
+        .into_iter()
+        .filter_map(|release| {
+            let date = release.release_date?;
+            let year = year_of(date);
+
+            Some(ReleaseLineup {
+                release_id: release.id,
+                title: release.title,
+                release_date: (date, release.release_date_precision).into(),
+                members: members
+                    .iter()
+                    .filter(|member| member.active_in(year))
+                    .cloned()
+                    .collect(),
+            })
+        })
+        .collect();
+
+    Ok(Some(LineupTimeline {
+        releases,
+        undated_members,
+    }))
+}
+
+/// All members of the group with their roles and tenures, `None` if the
+/// artist doesn't exist
+async fn find_members(
+    db: &impl ConnectionTrait,
+    group_id: i32,
+) -> Result<Option<Vec<LineupMember>>, DbErr> {
+    if artist::Entity::find_by_id(group_id)
+        .one(db)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    let memberships = artist_membership::Entity::find()
+        .filter(artist_membership::Column::GroupId.eq(group_id))
+        .order_by_asc(artist_membership::Column::Id)
+        .all(db)
+        .await?;
+
+    let roles = memberships
+        .load_many_to_many(
+            credit_role::Entity,
+            artist_membership_role::Entity,
+            db,
+        )
+        .await?;
+
+    let tenures = memberships
+        .load_many(artist_membership_tenure::Entity, db)
+        .await?;
+
+    let artists = artist::Entity::find()
+        .filter(
+            artist::Column::Id
+                .is_in(memberships.iter().map(|x| x.member_id).unique()),
+        )
+        .all(db)
+        .await?;
+
+    let members = izip!(memberships, roles, tenures)
+        .filter_map(|(membership, roles, tenures)| {
+            let artist =
+                artists.iter().find(|x| x.id == membership.member_id)?;
+
+            Some(LineupMember {
+                artist: SimpleArtist {
+                    id: artist.id,
+                    name: artist.name.clone(),
+                },
+                roles: roles
+                    .into_iter()
+                    .map(|role| CreditRoleRef {
+                        id: role.id,
+                        name: role.name,
+                    })
+                    .collect(),
+                tenure: tenures
+                    .iter()
+                    .sorted_by_key(|x| x.id)
+                    .map_into::<Tenure>()
+                    .collect(),
+            })
+        })
+        .collect();
+
+    Ok(Some(members))
+}
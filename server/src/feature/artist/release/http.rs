@@ -39,6 +39,9 @@ data!(
 struct AppearanceQueryDto {
     cursor: i32,
     limit: u8,
+    /// Also include releases of the artist's aliases
+    #[serde(default)]
+    include_aliases: bool,
 }
 
 impl AppearanceQueryDto {
@@ -49,6 +52,7 @@ impl AppearanceQueryDto {
                 at: self.cursor,
                 limit: self.limit,
             },
+            include_aliases: self.include_aliases,
         }
     }
 }
@@ -78,6 +82,15 @@ async fn find_artist_appearances(
 struct CreditQueryDto {
     cursor: i32,
     limit: u8,
+    /// Also include releases of the artist's aliases
+    #[serde(default)]
+    include_aliases: bool,
+    /// Only releases the artist is credited on with this role
+    role_id: Option<i32>,
+    /// Also match the sub roles of `role_id`, so that "Performer" matches
+    /// "Vocals" and "Guitar"
+    #[serde(default)]
+    include_sub_roles: bool,
 }
 
 impl CreditQueryDto {
@@ -88,6 +101,9 @@ impl CreditQueryDto {
                 at: self.cursor,
                 limit: self.limit,
             },
+            include_aliases: self.include_aliases,
+            role_id: self.role_id,
+            include_sub_roles: self.include_sub_roles,
         }
     }
 }
@@ -118,6 +134,12 @@ struct DiscographyQueryDto {
     release_type: ReleaseType,
     cursor: i32,
     limit: u8,
+    /// Also include releases of the artist's aliases
+    #[serde(default)]
+    include_aliases: bool,
+    /// Return only the earliest entered edition of each release group
+    #[serde(default)]
+    collapse_groups: bool,
 }
 
 impl DiscographyQueryDto {
@@ -129,6 +151,8 @@ impl DiscographyQueryDto {
                 at: self.cursor,
                 limit: self.limit,
             },
+            include_aliases: self.include_aliases,
+            collapse_groups: self.collapse_groups,
         }
     }
 }
@@ -157,6 +181,12 @@ async fn find_artist_discographies_by_type(
 #[derive(Deserialize, IntoParams)]
 struct InitDiscographyQueryDto {
     limit: u8,
+    /// Also include releases of the artist's aliases
+    #[serde(default)]
+    include_aliases: bool,
+    /// Return only the earliest entered edition of each release group
+    #[serde(default)]
+    collapse_groups: bool,
 }
 
 impl InitDiscographyQueryDto {
@@ -172,6 +202,8 @@ impl InitDiscographyQueryDto {
                 at: 0,
                 limit: self.limit,
             },
+            include_aliases: self.include_aliases,
+            collapse_groups: self.collapse_groups,
         }
     }
 }
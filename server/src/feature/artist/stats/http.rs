@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::ArtistStats;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Artist";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(find_artist_stats)))
+        .finish()
+}
+
+data!(DataOptionArtistStats, Option<ArtistStats>);
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/artist/{id}/stats",
+    params(
+        ("id" = i32, Path),
+    ),
+    responses(
+        (status = 200, body = DataOptionArtistStats),
+        Error,
+    ),
+)]
+async fn find_artist_stats(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<ArtistStats>>, Error> {
+    super::repo::find_stats(&repo, id).await.bimap_into()
+}
@@ -0,0 +1,62 @@
+use entity::enums::ReleaseType;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::credit_role::CreditRoleRef;
+use crate::domain::event::SimpleEvent;
+use crate::domain::shared::DateWithPrecision;
+use crate::domain::tag::TagRef;
+
+/// Aggregates over the releases the artist is a release artist of, unless
+/// stated otherwise
+#[serde_with::apply(
+    Option => #[serde(skip_serializing_if = "Option::is_none")],
+)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ArtistStats {
+    pub release_count: i32,
+    pub releases_by_type: Vec<ReleaseTypeCount>,
+    /// Releases with a known date, by year of release
+    pub releases_by_year: Vec<YearCount>,
+    pub first_release_date: Option<DateWithPrecision>,
+    pub latest_release_date: Option<DateWithPrecision>,
+    pub track_count: i32,
+    /// Sum of the known track durations, in milliseconds
+    pub total_duration: i64,
+    /// Release and song credits of the artist, by role
+    pub credits: Vec<CreditCount>,
+    /// Tags with the highest total score on the artist
+    pub top_tags: Vec<TagScore>,
+    /// Events the releases were published at, earliest first
+    pub events: Vec<EventCount>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, sea_orm::FromQueryResult)]
+pub struct ReleaseTypeCount {
+    pub release_type: ReleaseType,
+    pub count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, sea_orm::FromQueryResult)]
+pub struct YearCount {
+    pub year: i32,
+    pub count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CreditCount {
+    pub role: CreditRoleRef,
+    pub count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TagScore {
+    pub tag: TagRef,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct EventCount {
+    pub event: SimpleEvent,
+    pub release_count: i32,
+}
@@ -0,0 +1,313 @@
+use entity::enums::TagType;
+use entity::{artist, release, release_artist};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult,
+    QueryFilter, QueryOrder, Select, Statement,
+};
+
+use super::model::{
+    ArtistStats, CreditCount, EventCount, ReleaseTypeCount, TagScore, YearCount,
+};
+use crate::domain::Connection;
+use crate::domain::credit_role::CreditRoleRef;
+use crate::domain::event::SimpleEvent;
+use crate::domain::shared::DateWithPrecision;
+use crate::domain::tag::TagRef;
+
+#[derive(FromQueryResult)]
+struct TrackTotalRow {
+    track_count: i32,
+    total_duration: i64,
+}
+
+#[derive(FromQueryResult)]
+struct CreditRow {
+    id: i32,
+    name: String,
+    count: i32,
+}
+
+#[derive(FromQueryResult)]
+struct TagRow {
+    id: i32,
+    name: String,
+    r#type: TagType,
+    score: i32,
+}
+
+#[derive(FromQueryResult)]
+struct EventRow {
+    id: i32,
+    name: String,
+    release_count: i32,
+}
+
+pub(super) async fn find_stats<R>(
+    repo: &R,
+    artist_id: i32,
+) -> Result<Option<ArtistStats>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    if artist::Entity::find_by_id(artist_id)
+        .one(db)
+        .await?
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    let (
+        releases_by_type,
+        releases_by_year,
+        first_release_date,
+        latest_release_date,
+        track_total,
+        credits,
+        top_tags,
+        events,
+    ) = tokio::try_join!(
+        query_all::<ReleaseTypeCount>(db, RELEASES_BY_TYPE_SQL, artist_id),
+        query_all::<YearCount>(db, RELEASES_BY_YEAR_SQL, artist_id),
+        find_release_date(db, artist_id, Order::First),
+        find_release_date(db, artist_id, Order::Latest),
+        query_all::<TrackTotalRow>(db, TRACK_TOTAL_SQL, artist_id),
+        query_all::<CreditRow>(db, CREDITS_SQL, artist_id),
+        query_all::<TagRow>(db, TOP_TAGS_SQL, artist_id),
+        query_all::<EventRow>(db, EVENTS_SQL, artist_id),
+    )?;
+
+    let (track_count, total_duration) = track_total
+        .first()
+        .map_or((0, 0), |row| (row.track_count, row.total_duration));
+
+    Ok(Some(ArtistStats {
+        release_count: releases_by_type.iter().map(|x| x.count).sum(),
+        releases_by_type,
+        releases_by_year,
+        first_release_date,
+        latest_release_date,
+        track_count,
+        total_duration,
+        credits: credits
+            .into_iter()
+            .map(|row| CreditCount {
+                role: CreditRoleRef {
+                    id: row.id,
+                    name: row.name,
+                },
+                count: row.count,
+            })
+            .collect(),
+        top_tags: top_tags
+            .into_iter()
+            .map(|row| TagScore {
+                tag: TagRef {
+                    id: row.id,
+                    name: row.name,
+                    r#type: row.r#type,
+                },
+                score: row.score,
+            })
+            .collect(),
+        events: events
+            .into_iter()
+            .map(|row| EventCount {
+                event: SimpleEvent {
+                    id: row.id,
+                    name: row.name,
+                },
+                release_count: row.release_count,
+            })
+            .collect(),
+    }))
+}
+
+#[derive(Clone, Copy)]
+enum Order {
+    First,
+    Latest,
+}
+
+async fn find_release_date(
+    db: &impl ConnectionTrait,
+    artist_id: i32,
+    order: Order,
+) -> Result<Option<DateWithPrecision>, DbErr> {
+    let select = release_date_select(artist_id, order);
+
+    Ok(select.one(db).await?.and_then(|release| {
+        release
+            .release_date
+            .map(|date| (date, release.release_date_precision).into())
+    }))
+}
+
+fn release_date_select(
+    artist_id: i32,
+    order: Order,
+) -> Select<release::Entity> {
+    let select = release::Entity::find()
+        .inner_join(release_artist::Entity)
+        .filter(release_artist::Column::ArtistId.eq(artist_id))
+        .filter(release::Column::ReleaseDate.is_not_null());
+
+    match order {
+        Order::First => select.order_by_asc(release::Column::ReleaseDate),
+        Order::Latest => select.order_by_desc(release::Column::ReleaseDate),
+    }
+}
+
+async fn query_all<T: FromQueryResult>(
+    db: &impl ConnectionTrait,
+    sql: &str,
+    artist_id: i32,
+) -> Result<Vec<T>, DbErr> {
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        sql,
+        [artist_id.into()],
+    );
+
+    T::find_by_statement(stmt).all(db).await
+}
+
+const RELEASES_BY_TYPE_SQL: &str = r"
+    SELECT r.release_type::text AS release_type, COUNT(*)::int AS count
+    FROM release r
+    JOIN release_artist ra ON ra.release_id = r.id
+    WHERE ra.artist_id = $1
+    GROUP BY r.release_type
+    ORDER BY count DESC, r.release_type";
+
+const RELEASES_BY_YEAR_SQL: &str = r"
+    SELECT EXTRACT(YEAR FROM r.release_date)::int AS year, COUNT(*)::int AS count
+    FROM release r
+    JOIN release_artist ra ON ra.release_id = r.id
+    WHERE ra.artist_id = $1 AND r.release_date IS NOT NULL
+    GROUP BY year
+    ORDER BY year";
+
+const TRACK_TOTAL_SQL: &str = r"
+    SELECT
+        COUNT(*)::int AS track_count,
+        COALESCE(SUM(rt.duration), 0)::bigint AS total_duration
+    FROM release_track rt
+    JOIN release_artist ra ON ra.release_id = rt.release_id
+    WHERE ra.artist_id = $1";
+
+/// A role is counted once per release and once per song it is credited on
+const CREDITS_SQL: &str = r"
+    SELECT cr.id, cr.name, COUNT(*)::int AS count
+    FROM (
+        SELECT 'release' AS kind, release_id AS id, role_id
+        FROM release_credit
+        WHERE artist_id = $1
+        UNION
+        SELECT 'song', song_id, role_id
+        FROM song_credit
+        WHERE artist_id = $1 AND role_id IS NOT NULL
+    ) c
+    JOIN credit_role cr ON cr.id = c.role_id
+    GROUP BY cr.id, cr.name
+    ORDER BY count DESC, cr.id";
+
+/// Ten best scored tags
+const TOP_TAGS_SQL: &str = r"
+    SELECT t.id, t.name, t.type::text AS type, SUM(v.score)::int AS score
+    FROM artist_tag_vote v
+    JOIN tag t ON t.id = v.tag_id
+    WHERE v.artist_id = $1
+    GROUP BY t.id, t.name, t.type
+    HAVING SUM(v.score) > 0
+    ORDER BY score DESC, t.id
+    LIMIT 10";
+
+const EVENTS_SQL: &str = r"
+    SELECT e.id, e.name, COUNT(DISTINCT re.release_id)::int AS release_count
+    FROM release_event re
+    JOIN release_artist ra ON ra.release_id = re.release_id
+    JOIN event e ON e.id = re.event_id
+    WHERE ra.artist_id = $1
+    GROUP BY e.id, e.name, e.start_date
+    ORDER BY e.start_date NULLS LAST, e.id";
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::{QuerySelect, QueryTrait};
+
+    use super::*;
+    use crate::utils::squash_sql;
+
+    #[test]
+    fn first_and_latest_release_dates() {
+        let sql = |order| {
+            release_date_select(1, order)
+                .select_only()
+                .column(release::Column::Id)
+                .build(sea_orm::DatabaseBackend::Postgres)
+                .to_string()
+        };
+
+        assert_eq!(
+            sql(Order::First),
+            r#"SELECT "release"."id" FROM "release" INNER JOIN "release_artist" ON "release"."id" = "release_artist"."release_id" WHERE "release_artist"."artist_id" = 1 AND "release"."release_date" IS NOT NULL ORDER BY "release"."release_date" ASC"#
+        );
+        assert!(
+            sql(Order::Latest)
+                .ends_with(r#"ORDER BY "release"."release_date" DESC"#)
+        );
+    }
+
+    #[test]
+    fn releases_counted_per_type_and_dated_year() {
+        let by_type = squash_sql(RELEASES_BY_TYPE_SQL);
+        assert!(
+            by_type.contains("WHERE ra.artist_id = $1 GROUP BY r.release_type")
+        );
+        assert!(by_type.ends_with("ORDER BY count DESC, r.release_type"));
+
+        let by_year = squash_sql(RELEASES_BY_YEAR_SQL);
+        assert!(
+            by_year.contains("AND r.release_date IS NOT NULL GROUP BY year")
+        );
+        assert!(by_year.ends_with("ORDER BY year"));
+    }
+
+    #[test]
+    fn credits_counted_once_per_release_and_song() {
+        let sql = squash_sql(CREDITS_SQL);
+
+        // `UNION` drops a role credited twice on the same release or song
+        assert!(sql.contains(
+            "WHERE artist_id = $1 UNION SELECT 'song', song_id, role_id"
+        ));
+        assert!(sql.contains("WHERE artist_id = $1 AND role_id IS NOT NULL"));
+        assert!(
+            sql.ends_with("GROUP BY cr.id, cr.name ORDER BY count DESC, cr.id")
+        );
+    }
+
+    #[test]
+    fn top_tags_are_positively_scored() {
+        let sql = squash_sql(TOP_TAGS_SQL);
+
+        assert!(sql.contains("SUM(v.score)::int AS score"));
+        assert!(sql.ends_with(
+            "HAVING SUM(v.score) > 0 ORDER BY score DESC, t.id LIMIT 10"
+        ));
+    }
+
+    #[test]
+    fn events_count_distinct_releases() {
+        let sql = squash_sql(EVENTS_SQL);
+
+        assert!(
+            sql.contains("COUNT(DISTINCT re.release_id)::int AS release_count")
+        );
+        assert!(sql.ends_with("ORDER BY e.start_date NULLS LAST, e.id"));
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use moka::future::Cache;
+use rapidhash::fast::SeedableState as RapidState;
+
+use super::model::{AutocompleteItem, EntityType};
+
+// Pickers fire a request per keystroke and many users type the same prefixes,
+// so a short-lived cache absorbs most of the load. New entities may take up
+// to the TTL to show up.
+const AUTOCOMPLETE_TTL: Duration = Duration::from_secs(60);
+const AUTOCOMPLETE_CAPACITY: u64 = 10_000;
+
+type Key = (EntityType, String, u32);
+
+pub struct AutocompleteCache(
+    OnceLock<Cache<Key, Vec<AutocompleteItem>, RapidState<'static>>>,
+);
+
+impl AutocompleteCache {
+    const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    fn cache(&self) -> &Cache<Key, Vec<AutocompleteItem>, RapidState<'static>> {
+        self.0.get_or_init(|| {
+            Cache::builder()
+                .time_to_live(AUTOCOMPLETE_TTL)
+                .max_capacity(AUTOCOMPLETE_CAPACITY)
+                .build_with_hasher(RapidState::fixed())
+        })
+    }
+
+    pub async fn get_or_try_insert<F, E>(
+        &self,
+        key: Key,
+        init: F,
+    ) -> Result<Vec<AutocompleteItem>, E>
+    where
+        F: Future<Output = Result<Vec<AutocompleteItem>, E>>,
+    {
+        if let Some(items) = self.cache().get(&key).await {
+            return Ok(items);
+        }
+
+        let items = init.await?;
+        self.cache().insert(key, items.clone()).await;
+
+        Ok(items)
+    }
+}
+
+pub static AUTOCOMPLETE_CACHE: AutocompleteCache = AutocompleteCache::new();
@@ -0,0 +1,71 @@
+use axum::extract::{Path, Query, State};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::cache::AUTOCOMPLETE_CACHE;
+use super::model::{AutocompleteItem, EntityType};
+use super::repo;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Autocomplete";
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 20;
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(autocomplete)))
+        .finish()
+}
+
+data!(DataVecAutocompleteItem, Vec<AutocompleteItem>);
+
+#[derive(Deserialize, IntoParams)]
+struct AutocompletePath {
+    #[param(inline)]
+    entity_type: EntityType,
+}
+
+#[derive(Deserialize, IntoParams)]
+struct AutocompleteQuery {
+    prefix: String,
+    #[param(minimum = 1, maximum = 20)]
+    limit: Option<u32>,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/autocomplete/{entity_type}",
+    params(AutocompletePath, AutocompleteQuery),
+    responses(
+        (status = 200, body = DataVecAutocompleteItem),
+        Error,
+    ),
+)]
+async fn autocomplete(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(AutocompletePath { entity_type }): Path<AutocompletePath>,
+    Query(query): Query<AutocompleteQuery>,
+) -> Result<Data<Vec<AutocompleteItem>>, Error> {
+    let prefix = query.prefix.trim().to_lowercase();
+    if prefix.is_empty() {
+        return Ok(Data::new(vec![]));
+    }
+
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let items = AUTOCOMPLETE_CACHE
+        .get_or_try_insert(
+            (entity_type, prefix.clone(), limit),
+            repo::find_by_prefix(&repo, entity_type, &prefix, limit),
+        )
+        .await?;
+
+    Ok(Data::new(items))
+}
@@ -0,0 +1,6 @@
+mod cache;
+mod http;
+mod model;
+mod repo;
+
+pub use http::router;
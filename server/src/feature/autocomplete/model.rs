@@ -0,0 +1,105 @@
+use sea_orm::{EntityName, IdenStatic};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum EntityType {
+    Artist,
+    Event,
+    Label,
+    Release,
+    Song,
+    Tag,
+}
+
+/// A table holding extra names of an entity, e.g. localized names
+pub struct AltNameTable {
+    pub table: &'static str,
+    pub entity_id_column: &'static str,
+    pub name_column: &'static str,
+}
+
+impl EntityType {
+    pub fn table_name(self) -> &'static str {
+        match self {
+            Self::Artist => entity::artist::Entity.table_name(),
+            Self::Event => entity::event::Entity.table_name(),
+            Self::Label => entity::label::Entity.table_name(),
+            Self::Release => entity::release::Entity.table_name(),
+            Self::Song => entity::song::Entity.table_name(),
+            Self::Tag => entity::tag::Entity.table_name(),
+        }
+    }
+
+    pub fn name_column(self) -> &'static str {
+        match self {
+            Self::Artist => entity::artist::Column::Name.as_str(),
+            Self::Event => entity::event::Column::Name.as_str(),
+            Self::Label => entity::label::Column::Name.as_str(),
+            Self::Release => entity::release::Column::Title.as_str(),
+            Self::Song => entity::song::Column::Title.as_str(),
+            Self::Tag => entity::tag::Column::Name.as_str(),
+        }
+    }
+
+    pub fn alt_name_table(self) -> AltNameTable {
+        use entity::{
+            artist_localized_name, event_alternative_name,
+            label_localized_name, release_localized_title,
+            song_localized_title, tag_alternative_name,
+        };
+
+        match self {
+            Self::Artist => AltNameTable {
+                table: artist_localized_name::Entity.table_name(),
+                entity_id_column: artist_localized_name::Column::ArtistId
+                    .as_str(),
+                name_column: artist_localized_name::Column::Name.as_str(),
+            },
+            Self::Event => AltNameTable {
+                table: event_alternative_name::Entity.table_name(),
+                entity_id_column: event_alternative_name::Column::EventId
+                    .as_str(),
+                name_column: event_alternative_name::Column::Name.as_str(),
+            },
+            Self::Label => AltNameTable {
+                table: label_localized_name::Entity.table_name(),
+                entity_id_column: label_localized_name::Column::LabelId
+                    .as_str(),
+                name_column: label_localized_name::Column::Name.as_str(),
+            },
+            Self::Release => AltNameTable {
+                table: release_localized_title::Entity.table_name(),
+                entity_id_column: release_localized_title::Column::ReleaseId
+                    .as_str(),
+                name_column: release_localized_title::Column::Title.as_str(),
+            },
+            Self::Song => AltNameTable {
+                table: song_localized_title::Entity.table_name(),
+                entity_id_column: song_localized_title::Column::SongId.as_str(),
+                name_column: song_localized_title::Column::Title.as_str(),
+            },
+            Self::Tag => AltNameTable {
+                table: tag_alternative_name::Entity.table_name(),
+                entity_id_column: tag_alternative_name::Column::TagId.as_str(),
+                name_column: tag_alternative_name::Column::Name.as_str(),
+            },
+        }
+    }
+
+    /// Only artists have free-form text aliases
+    pub const fn has_text_alias(self) -> bool {
+        matches!(self, Self::Artist)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, sea_orm::FromQueryResult)]
+pub struct AutocompleteItem {
+    pub id: i32,
+    pub name: String,
+    /// The localized name or alias that matched the prefix,
+    /// absent when the primary name matched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disambiguation: Option<String>,
+}
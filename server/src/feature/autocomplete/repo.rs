@@ -0,0 +1,163 @@
+use std::fmt::Write;
+
+use sea_orm::{ConnectionTrait, DbErr, FromQueryResult, Statement};
+
+use super::model::{AutocompleteItem, EntityType};
+use crate::domain::Connection;
+
+/// Trigram similarity is meaningless for very short inputs,
+/// so the fuzzy fallback only kicks in after this many characters
+const FUZZY_MIN_CHARS: usize = 3;
+
+pub async fn find_by_prefix<R>(
+    repo: &R,
+    entity_type: EntityType,
+    prefix: &str,
+    limit: u32,
+) -> Result<Vec<AutocompleteItem>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let fuzzy = prefix.chars().count() >= FUZZY_MIN_CHARS;
+    let sql = build_sql(entity_type, fuzzy);
+
+    let mut values = vec![
+        format!("{}%", escape_like(prefix)).into(),
+        i64::from(limit).into(),
+    ];
+    if fuzzy {
+        values.push(prefix.into());
+    }
+
+    let stmt = Statement::from_sql_and_values(
+        repo.conn().get_database_backend(),
+        sql,
+        values,
+    );
+
+    AutocompleteItem::find_by_statement(stmt)
+        .all(repo.conn())
+        .await
+}
+
+/// Escape `LIKE` wildcards so user input is matched literally
+fn escape_like(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for ch in input.chars() {
+        if matches!(ch, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Parameters:
+/// - `$1`: escaped, lowercased prefix followed by `%`
+/// - `$2`: limit
+/// - `$3`: lowercased prefix, only bound when `fuzzy` is set
+///
+/// Prefix matches on the primary name rank first, then prefix matches on
+/// alternative names, then trigram matches. Each entity appears once with its
+/// best match.
+fn build_sql(entity_type: EntityType, fuzzy: bool) -> String {
+    let table = entity_type.table_name();
+    let name = entity_type.name_column();
+    let alt = entity_type.alt_name_table();
+    let (alt_table, alt_fk, alt_name) =
+        (alt.table, alt.entity_id_column, alt.name_column);
+
+    let mut candidates = format!(
+        r"
+        SELECT e.id, e.{name} AS name, NULL::text AS matched, 0 AS rank, 1::real AS score
+        FROM {table} e
+        WHERE lower(e.{name}) LIKE $1
+        UNION ALL
+        SELECT e.id, e.{name}, alt.{alt_name}, 1, 1::real
+        FROM {alt_table} alt
+        JOIN {table} e ON e.id = alt.{alt_fk}
+        WHERE lower(alt.{alt_name}) LIKE $1"
+    );
+
+    if entity_type.has_text_alias() {
+        // `artist_text_alias_search` is backed by a trigram index, use it to
+        // narrow down rows before unnesting
+        candidates.push_str(
+            r"
+        UNION ALL
+        SELECT e.id, e.name, alias, 1, 1::real
+        FROM artist e
+        CROSS JOIN LATERAL unnest(e.text_alias) AS alias
+        WHERE artist_text_alias_search(e.text_alias) LIKE '%' || $1
+          AND lower(alias) LIKE $1",
+        );
+    }
+
+    if fuzzy {
+        write!(
+            candidates,
+            r"
+        UNION ALL
+        SELECT e.id, e.{name}, NULL, 2, similarity(lower(e.{name}), $3)
+        FROM {table} e
+        WHERE lower(e.{name}) % $3
+        UNION ALL
+        SELECT e.id, e.{name}, alt.{alt_name}, 3, similarity(lower(alt.{alt_name}), $3)
+        FROM {alt_table} alt
+        JOIN {table} e ON e.id = alt.{alt_fk}
+        WHERE lower(alt.{alt_name}) % $3"
+        )
+        .expect("Writing to a String never fails");
+    }
+
+    format!(
+        r"
+        WITH candidate AS ({candidates}
+        )
+        SELECT id, name, disambiguation
+        FROM (
+            SELECT DISTINCT ON (id)
+                id, name, NULLIF(matched, name) AS disambiguation, rank, score
+            FROM candidate
+            ORDER BY id, rank, score DESC
+        ) AS best
+        ORDER BY rank, score DESC, length(name), name
+        LIMIT $2
+        "
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_like_wildcards() {
+        assert_eq!(escape_like("50%_off"), r"50\%\_off");
+        assert_eq!(escape_like(r"a\b"), r"a\\b");
+        assert_eq!(escape_like("東方"), "東方");
+    }
+
+    #[test]
+    fn fuzzy_params_only_when_enabled() {
+        let sql = build_sql(EntityType::Song, false);
+        assert!(!sql.contains("$3"));
+        assert!(sql.contains("song_localized_title"));
+
+        let sql = build_sql(EntityType::Song, true);
+        assert!(sql.contains("similarity(lower(e.title), $3)"));
+    }
+
+    #[test]
+    fn text_alias_only_for_artist() {
+        assert!(
+            build_sql(EntityType::Artist, false)
+                .contains("artist_text_alias_search")
+        );
+        assert!(
+            !build_sql(EntityType::Label, false)
+                .contains("artist_text_alias_search")
+        );
+    }
+}
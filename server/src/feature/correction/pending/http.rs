@@ -27,6 +27,9 @@ enum EntityTypePath {
     Event,
     SongLyrics,
     CreditRole,
+    SourceWork,
+    ReleaseGroup,
+    EventSeries,
 }
 
 impl From<EntityTypePath> for entity::enums::EntityType {
@@ -40,6 +43,9 @@ impl From<EntityTypePath> for entity::enums::EntityType {
             EntityTypePath::Event => Self::Event,
             EntityTypePath::SongLyrics => Self::SongLyrics,
             EntityTypePath::CreditRole => Self::CreditRole,
+            EntityTypePath::SourceWork => Self::SourceWork,
+            EntityTypePath::ReleaseGroup => Self::ReleaseGroup,
+            EntityTypePath::EventSeries => Self::EventSeries,
         }
     }
 }
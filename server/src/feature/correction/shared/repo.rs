@@ -6,14 +6,18 @@ use entity::{
     artist_localized_name_history, artist_membership_history,
     artist_membership_role_history, artist_membership_tenure_history,
     credit_role_history, credit_role_inheritance_history,
-    event_alternative_name_history, event_history, label_founder_history,
-    label_history, label_localized_name_history, release_artist_history,
+    event_alternative_name_history, event_circle_history, event_history,
+    event_link_history, event_series_history, label_founder_history,
+    label_history, label_link_history, label_localized_name_history,
+    label_relation_history, release_artist_history,
     release_catalog_number_history, release_credit_history,
-    release_disc_history, release_event_history, release_history,
+    release_disc_history, release_event_history, release_group_history,
+    release_group_release_history, release_history,
     release_localized_title_history, release_track_artist_history,
     release_track_history, song_artist_history, song_credit_history,
     song_history, song_language_history, song_localized_title_history,
-    song_lyrics_history, tag_alternative_name_history, tag_history,
+    song_lyrics_history, song_source_track_history, source_work_history,
+    source_work_track_history, tag_alternative_name_history, tag_history,
     tag_relation_history,
 };
 use sea_orm::{
@@ -23,13 +27,15 @@ use sea_orm::{
 use serde_json::{Value, json};
 
 use crate::domain::correction::CorrectionDiffEntry;
+use crate::domain::event::Booth;
+use crate::infra::database::sea_orm::external_id;
 
 pub async fn snapshot_for_history(
     db: &impl ConnectionTrait,
     entity_type: EntityType,
     history_id: i32,
 ) -> Result<Value, DbErr> {
-    match entity_type {
+    let mut snapshot = match entity_type {
         EntityType::Artist => snapshot_artist(db, history_id).await,
         EntityType::Label => snapshot_label(db, history_id).await,
         EntityType::Release => snapshot_release(db, history_id).await,
@@ -38,7 +44,23 @@ pub async fn snapshot_for_history(
         EntityType::Event => snapshot_event(db, history_id).await,
         EntityType::SongLyrics => snapshot_song_lyrics(db, history_id).await,
         EntityType::CreditRole => snapshot_credit_role(db, history_id).await,
+        EntityType::SourceWork => snapshot_source_work(db, history_id).await,
+        EntityType::ReleaseGroup => {
+            snapshot_release_group(db, history_id).await
+        }
+        EntityType::EventSeries => snapshot_event_series(db, history_id).await,
+    }?;
+
+    let external_ids = external_id::find_histories(entity_type, history_id, db)
+        .await?
+        .into_iter()
+        .map(|model| json!({ "source": model.source, "value": model.value }))
+        .collect::<Vec<_>>();
+    if let Value::Object(map) = &mut snapshot {
+        map.insert("external_ids".to_owned(), Value::Array(external_ids));
     }
+
+    Ok(snapshot)
 }
 
 pub fn diff_snapshots(before: &Value, after: &Value) -> Vec<CorrectionDiffEntry> {
@@ -167,6 +189,15 @@ fn location_value(
     }
 }
 
+fn booth_value(
+    day: Option<i16>,
+    hall: Option<String>,
+    space: Option<String>,
+) -> Value {
+    Booth::from_columns(day, hall, space)
+        .map_or(Value::Null, |booth| json!(booth))
+}
+
 async fn snapshot_artist(
     db: &impl ConnectionTrait,
     history_id: i32,
@@ -309,6 +340,29 @@ async fn snapshot_label(
         })
         .collect::<Vec<_>>();
 
+    let links = label_link_history::Entity::find()
+        .filter(label_link_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(label_link_history::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.url)
+        .collect::<Vec<_>>();
+
+    let relations = label_relation_history::Entity::find()
+        .filter(label_relation_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(label_relation_history::Column::RelatedLabelId)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            json!({
+                "related_label_id": model.related_label_id,
+                "type": model.r#type,
+            })
+        })
+        .collect::<Vec<_>>();
+
     Ok(json!({
         "name": history.name,
         "founded_date": date_with_precision(
@@ -321,6 +375,8 @@ async fn snapshot_label(
         ),
         "founders": founders,
         "localized_names": localized_names,
+        "links": links,
+        "relations": relations,
     }))
 }
 
@@ -450,7 +506,16 @@ async fn snapshot_release(
         .all(db)
         .await?
         .into_iter()
-        .map(|model| model.event_id)
+        .map(|model| {
+            json!({
+                "event_id": model.event_id,
+                "booth": booth_value(
+                    model.booth_day,
+                    model.booth_hall,
+                    model.booth_space,
+                ),
+            })
+        })
         .collect::<Vec<_>>();
 
     Ok(json!({
@@ -532,12 +597,27 @@ async fn snapshot_song(
         .map(|model| model.language_id)
         .collect::<Vec<_>>();
 
+    let source_tracks = song_source_track_history::Entity::find()
+        .filter(song_source_track_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(song_source_track_history::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            json!({
+                "id": model.source_track_id,
+                "title": model.title,
+            })
+        })
+        .collect::<Vec<_>>();
+
     Ok(json!({
         "title": history.title,
         "artists": artists,
         "credits": credits,
         "localized_titles": localized_titles,
         "languages": language_ids,
+        "source_tracks": source_tracks,
     }))
 }
 
@@ -593,7 +673,9 @@ async fn snapshot_event(
         .ok_or_else(|| DbErr::Custom("Event history not found".to_string()))?;
 
     let alternative_names = event_alternative_name_history::Entity::find()
-        .filter(event_alternative_name_history::Column::HistoryId.eq(history_id))
+        .filter(
+            event_alternative_name_history::Column::HistoryId.eq(history_id),
+        )
         .order_by_asc(event_alternative_name_history::Column::Id)
         .all(db)
         .await?
@@ -601,6 +683,33 @@ async fn snapshot_event(
         .map(|model| model.name)
         .collect::<Vec<_>>();
 
+    let links = event_link_history::Entity::find()
+        .filter(event_link_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(event_link_history::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| model.url)
+        .collect::<Vec<_>>();
+
+    let circles = event_circle_history::Entity::find()
+        .filter(event_circle_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(event_circle_history::Column::ArtistId)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            json!({
+                "artist_id": model.artist_id,
+                "booth": booth_value(
+                    model.booth_day,
+                    model.booth_hall,
+                    model.booth_space,
+                ),
+            })
+        })
+        .collect::<Vec<_>>();
+
     Ok(json!({
         "name": history.name,
         "description": history.description,
@@ -618,7 +727,15 @@ async fn snapshot_event(
             history.location_province.as_deref(),
             history.location_city.as_deref(),
         ),
+        "series": history.series_id.map(|series_id| {
+            json!({
+                "series_id": series_id,
+                "ordinal": history.series_ordinal,
+            })
+        }),
         "alternative_names": alternative_names,
+        "links": links,
+        "circles": circles,
     }))
 }
 
@@ -645,7 +762,9 @@ async fn snapshot_credit_role(
     let history = credit_role_history::Entity::find_by_id(history_id)
         .one(db)
         .await?
-        .ok_or_else(|| DbErr::Custom("Credit role history not found".to_string()))?;
+        .ok_or_else(|| {
+            DbErr::Custom("Credit role history not found".to_string())
+        })?;
 
     let inherits = credit_role_inheritance_history::Entity::find()
         .filter(
@@ -664,3 +783,91 @@ async fn snapshot_credit_role(
         "inherits": inherits,
     }))
 }
+
+async fn snapshot_source_work(
+    db: &impl ConnectionTrait,
+    history_id: i32,
+) -> Result<Value, DbErr> {
+    let history = source_work_history::Entity::find_by_id(history_id)
+        .one(db)
+        .await?
+        .ok_or_else(|| {
+            DbErr::Custom("Source work history not found".to_string())
+        })?;
+
+    let tracks = source_work_track_history::Entity::find()
+        .filter(source_work_track_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(source_work_track_history::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            json!({
+                "id": model.track_id,
+                "track_number": model.track_number,
+                "title": model.title,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(json!({
+        "title": history.title,
+        "kind": history.kind,
+        "release_date": date_with_precision(
+            history.release_date,
+            Some(history.release_date_precision),
+        ),
+        "description": history.description,
+        "tracks": tracks,
+    }))
+}
+
+async fn snapshot_release_group(
+    db: &impl ConnectionTrait,
+    history_id: i32,
+) -> Result<Value, DbErr> {
+    let history = release_group_history::Entity::find_by_id(history_id)
+        .one(db)
+        .await?
+        .ok_or_else(|| {
+            DbErr::Custom("Release group history not found".to_string())
+        })?;
+
+    let editions = release_group_release_history::Entity::find()
+        .filter(release_group_release_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(release_group_release_history::Column::ReleaseId)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            json!({
+                "release_id": model.release_id,
+                "edition_type": model.edition_type,
+                "notes": model.notes,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(json!({
+        "title": history.title,
+        "description": history.description,
+        "editions": editions,
+    }))
+}
+
+async fn snapshot_event_series(
+    db: &impl ConnectionTrait,
+    history_id: i32,
+) -> Result<Value, DbErr> {
+    let history = event_series_history::Entity::find_by_id(history_id)
+        .one(db)
+        .await?
+        .ok_or_else(|| {
+            DbErr::Custom("Event series history not found".to_string())
+        })?;
+
+    Ok(json!({
+        "name": history.name,
+        "description": history.description,
+    }))
+}
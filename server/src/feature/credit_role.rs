@@ -2,8 +2,13 @@ use utoipa_axum::router::OpenApiRouter;
 
 use crate::adapter::inbound::rest::state::ArcAppState;
 
+mod artists;
 pub mod find;
+mod tree;
 
 pub fn router() -> OpenApiRouter<ArcAppState> {
-    OpenApiRouter::new().merge(find::router())
+    OpenApiRouter::new()
+        .merge(artists::router())
+        .merge(find::router())
+        .merge(tree::router())
 }
@@ -0,0 +1,54 @@
+use axum::extract::{Path, Query, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::CreditRoleArtist;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::domain::shared::Paginated;
+use crate::infra::error::Error;
+use crate::shared::http::PaginationQuery;
+
+const TAG: &str = "Credit Role";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(find_credit_role_artists)))
+        .finish()
+}
+
+data! {
+    DataOptionPaginatedCreditRoleArtist, Option<Paginated<CreditRoleArtist>>
+}
+
+/// Artists ranked by how many releases and songs they are credited on in
+/// the role. The cursor is an offset.
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/credit-role/{id}/artists",
+    params(
+        ("id" = i32, Path),
+        PaginationQuery,
+    ),
+    responses(
+        (status = 200, body = DataOptionPaginatedCreditRoleArtist),
+        Error,
+    ),
+)]
+async fn find_credit_role_artists(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Data<Option<Paginated<CreditRoleArtist>>>, Error> {
+    super::repo::find_artists(
+        &repo,
+        id,
+        pagination.cursor.unwrap_or_default(),
+        pagination.limit(),
+    )
+    .await
+    .bimap_into()
+}
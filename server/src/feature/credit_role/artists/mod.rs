@@ -0,0 +1,5 @@
+mod http;
+mod model;
+mod repo;
+
+pub use http::router;
@@ -0,0 +1,38 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::artist::SimpleArtist;
+
+/// An artist credited in a role. A release or song counts once however
+/// many times the artist is credited on it in that role.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CreditRoleArtist {
+    pub artist: SimpleArtist,
+    pub release_count: i32,
+    pub song_count: i32,
+    /// Credits with a known year, songs dated by their earliest release
+    pub by_year: Vec<CreditYearCount>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CreditYearCount {
+    pub year: i32,
+    pub release_count: i32,
+    pub song_count: i32,
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub(super) struct ArtistCountRow {
+    pub id: i32,
+    pub name: String,
+    pub release_count: i32,
+    pub song_count: i32,
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub(super) struct YearCountRow {
+    pub artist_id: i32,
+    pub year: i32,
+    pub release_count: i32,
+    pub song_count: i32,
+}
@@ -0,0 +1,178 @@
+use entity::credit_role;
+use itertools::Itertools;
+use sea_orm::{
+    ConnectionTrait, DbErr, EntityTrait, FromQueryResult, PaginatorTrait,
+    Statement,
+};
+
+use super::model::{
+    ArtistCountRow, CreditRoleArtist, CreditYearCount, YearCountRow,
+};
+use crate::domain::Connection;
+use crate::domain::artist::SimpleArtist;
+use crate::domain::shared::Paginated;
+use crate::infra::database::sea_orm::utils::paginate_by_offset;
+
+/// Artists credited in a role, most credited first
+///
+/// The cursor is an offset, since the order is not by id
+pub(super) async fn find_artists<R>(
+    repo: &R,
+    role_id: i32,
+    offset: i32,
+    limit: u32,
+) -> Result<Option<Paginated<CreditRoleArtist>>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let exists = credit_role::Entity::find_by_id(role_id).count(db).await? > 0;
+    if !exists {
+        return Ok(None);
+    }
+
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        format!("{CREDITS_CTE} {ARTISTS_SQL}"),
+        [
+            role_id.into(),
+            (i64::from(limit) + 1).into(),
+            i64::from(offset.max(0)).into(),
+        ],
+    );
+    let page = paginate_by_offset(
+        ArtistCountRow::find_by_statement(stmt).all(db).await?,
+        offset,
+        limit,
+    );
+
+    let artist_ids = page.items.iter().map(|row| row.id).collect_vec();
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        format!("{CREDITS_CTE} {YEARS_SQL}"),
+        [role_id.into(), artist_ids.into()],
+    );
+    let years = YearCountRow::find_by_statement(stmt)
+        .all(db)
+        .await?
+        .into_iter()
+        .into_group_map_by(|row| row.artist_id);
+
+    Ok(Some(page.map_items(|row| {
+        CreditRoleArtist {
+            by_year: years
+                .get(&row.id)
+                .into_iter()
+                .flatten()
+                .map(|year| CreditYearCount {
+                    year: year.year,
+                    release_count: year.release_count,
+                    song_count: year.song_count,
+                })
+                .collect(),
+            artist: SimpleArtist {
+                id: row.id,
+                name: row.name,
+            },
+            release_count: row.release_count,
+            song_count: row.song_count,
+        }
+    })))
+}
+
+/// Distinct (artist, release) and (artist, song) pairs credited with the
+/// role `$1`, with the year of the release or of the earliest release of
+/// the song
+const CREDITS_CTE: &str = r"
+    WITH credit AS (
+        SELECT rc.artist_id, 'release' AS kind, rc.release_id AS id,
+            EXTRACT(YEAR FROM r.release_date)::int AS year
+        FROM release_credit rc
+        JOIN release r ON r.id = rc.release_id
+        WHERE rc.role_id = $1
+        UNION
+        SELECT sc.artist_id, 'song', sc.song_id,
+            (
+                SELECT MIN(EXTRACT(YEAR FROM r.release_date))::int
+                FROM release_track rt
+                JOIN release r ON r.id = rt.release_id
+                WHERE rt.song_id = sc.song_id
+            )
+        FROM song_credit sc
+        WHERE sc.role_id = $1
+    )";
+
+/// - `$2`: limit
+/// - `$3`: offset
+const ARTISTS_SQL: &str = r"
+    SELECT
+        a.id,
+        a.name,
+        COUNT(*) FILTER (WHERE c.kind = 'release')::int AS release_count,
+        COUNT(*) FILTER (WHERE c.kind = 'song')::int AS song_count
+    FROM credit c
+    JOIN artist a ON a.id = c.artist_id
+    GROUP BY a.id, a.name
+    ORDER BY COUNT(*) DESC, a.id
+    LIMIT $2 OFFSET $3";
+
+/// - `$2`: artist ids
+const YEARS_SQL: &str = r"
+    SELECT
+        c.artist_id,
+        c.year,
+        COUNT(*) FILTER (WHERE c.kind = 'release')::int AS release_count,
+        COUNT(*) FILTER (WHERE c.kind = 'song')::int AS song_count
+    FROM credit c
+    WHERE c.artist_id = ANY($2) AND c.year IS NOT NULL
+    GROUP BY c.artist_id, c.year
+    ORDER BY c.artist_id, c.year";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::squash_sql;
+
+    #[test]
+    fn credits_counted_once_per_release_and_song() {
+        let sql = squash_sql(CREDITS_CTE);
+
+        // `UNION` drops an artist credited twice on the same release or song
+        assert!(sql.contains(
+            "WHERE rc.role_id = $1 UNION SELECT sc.artist_id, 'song', sc.song_id"
+        ));
+        assert!(sql.ends_with("FROM song_credit sc WHERE sc.role_id = $1 )"));
+    }
+
+    #[test]
+    fn songs_dated_by_earliest_release() {
+        let sql = squash_sql(CREDITS_CTE);
+
+        assert!(sql.contains(
+            "SELECT MIN(EXTRACT(YEAR FROM r.release_date))::int FROM release_track rt JOIN release r ON r.id = rt.release_id WHERE rt.song_id = sc.song_id"
+        ));
+    }
+
+    #[test]
+    fn artists_ranked_by_credit_count() {
+        let sql = squash_sql(ARTISTS_SQL);
+
+        assert!(sql.contains(
+            "COUNT(*) FILTER (WHERE c.kind = 'release')::int AS release_count"
+        ));
+        assert!(sql.ends_with(
+            "GROUP BY a.id, a.name ORDER BY COUNT(*) DESC, a.id LIMIT $2 OFFSET $3"
+        ));
+    }
+
+    #[test]
+    fn years_of_dated_credits_per_artist() {
+        let sql = squash_sql(YEARS_SQL);
+
+        assert!(sql.ends_with(
+            "WHERE c.artist_id = ANY($2) AND c.year IS NOT NULL GROUP BY c.artist_id, c.year ORDER BY c.artist_id, c.year"
+        ));
+    }
+}
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+
 use entity::credit_role;
+use entity::enums::EntityType;
+use itertools::Itertools;
 use sea_orm::{
-    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult,
+    QueryFilter, QueryOrder, Select, Statement,
 };
 use sea_query::extension::postgres::PgBinOper;
 use sea_query::{ExprTrait, Func};
@@ -8,7 +13,8 @@ use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::domain::Connection;
-use crate::domain::credit_role::QueryKind;
+use crate::domain::credit_role::{CreditRole, CreditRoleSummary};
+use crate::infra::database::sea_orm::external_id;
 
 #[derive(Clone, Debug, Default, Deserialize, ToSchema, IntoParams)]
 pub struct CommonFilter {}
@@ -17,33 +23,52 @@ pub enum FindManyFilter {
     Name(String),
 }
 
-pub(super) async fn find_one<R, K>(
+pub(super) async fn find_one_full<R>(
     repo: &R,
     id: i32,
     common: CommonFilter,
-) -> Result<Option<K::Output>, DbErr>
+) -> Result<Option<CreditRole>, DbErr>
 where
     R: Connection,
     R::Conn: ConnectionTrait,
-    K: QueryKind,
 {
     let _ = common;
 
-    credit_role::Entity::find_by_id(id)
-        .one(repo.conn())
+    find_many_impl(credit_role::Entity::find_by_id(id), repo.conn())
         .await
-        .map(|role| role.map(Into::into))
+        .map(|mut roles| roles.pop())
+}
+
+pub(crate) async fn find_many_impl(
+    select: Select<credit_role::Entity>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<CreditRole>, DbErr> {
+    let roles = select.all(db).await?;
+
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::CreditRole,
+        roles.iter().map(|role| role.id),
+        db,
+    )
+    .await?;
+
+    Ok(roles
+        .into_iter()
+        .map(|role| CreditRole {
+            external_ids: external_ids.remove(&role.id).unwrap_or_default(),
+            ..role.into()
+        })
+        .collect())
 }
 
-pub(super) async fn find_many<R, K>(
+pub(super) async fn find_many_summary<R>(
     repo: &R,
     filter: FindManyFilter,
     common: CommonFilter,
-) -> Result<Vec<K::Output>, DbErr>
+) -> Result<Vec<CreditRoleSummary>, DbErr>
 where
     R: Connection,
     R::Conn: ConnectionTrait,
-    K: QueryKind,
 {
     let _ = common;
 
@@ -65,5 +90,58 @@ where
         }
     };
 
-    Ok(roles.into_iter().map(Into::into).collect())
+    let stmt = Statement::from_sql_and_values(
+        repo.conn().get_database_backend(),
+        USAGE_SQL,
+        [roles.iter().map(|role| role.id).collect_vec().into()],
+    );
+    let usages: HashMap<i32, UsageRow> = UsageRow::find_by_statement(stmt)
+        .all(repo.conn())
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row))
+        .collect();
+
+    Ok(roles
+        .into_iter()
+        .map(|role| {
+            let usage = usages.get(&role.id);
+            CreditRoleSummary {
+                release_count: usage.map_or(0, |x| x.release_count),
+                song_count: usage.map_or(0, |x| x.song_count),
+                artist_count: usage.map_or(0, |x| x.artist_count),
+                id: role.id,
+                name: role.name,
+                short_description: role.short_description,
+            }
+        })
+        .collect())
 }
+
+#[derive(FromQueryResult)]
+struct UsageRow {
+    id: i32,
+    release_count: i32,
+    song_count: i32,
+    artist_count: i32,
+}
+
+/// - `$1`: role ids
+const USAGE_SQL: &str = r"
+    SELECT
+        c.role_id AS id,
+        COUNT(DISTINCT c.entity_id) FILTER (WHERE c.kind = 'release')::int
+            AS release_count,
+        COUNT(DISTINCT c.entity_id) FILTER (WHERE c.kind = 'song')::int
+            AS song_count,
+        COUNT(DISTINCT c.artist_id)::int AS artist_count
+    FROM (
+        SELECT 'release' AS kind, role_id, release_id AS entity_id, artist_id
+        FROM release_credit
+        WHERE role_id = ANY($1)
+        UNION ALL
+        SELECT 'song', role_id, song_id, artist_id
+        FROM song_credit
+        WHERE role_id = ANY($1)
+    ) c
+    GROUP BY c.role_id";
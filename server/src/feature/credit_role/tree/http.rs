@@ -0,0 +1,36 @@
+use axum::extract::{Path, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::CreditRoleTree;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Credit Role";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(find_credit_role_tree)))
+        .finish()
+}
+
+data!(DataOptionCreditRoleTree, Option<CreditRoleTree>);
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/credit-role/{id}/tree",
+    responses(
+        (status = 200, body = DataOptionCreditRoleTree),
+        Error,
+    ),
+)]
+async fn find_credit_role_tree(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<CreditRoleTree>>, Error> {
+    super::repo::find_tree(&repo, id).await.bimap_into()
+}
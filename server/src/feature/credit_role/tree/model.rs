@@ -0,0 +1,46 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::credit_role::CreditRoleRef;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CreditRoleTree {
+    /// Super roles of the role, their super roles and so on
+    pub ancestors: Vec<CreditRoleTreeNode>,
+    /// Sub roles of the role, their sub roles and so on
+    pub descendants: Vec<CreditRoleTreeNode>,
+}
+
+/// A role reached while walking the hierarchy. A role reachable from
+/// several roles appears once per role it is reached from.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CreditRoleTreeNode {
+    pub role: CreditRoleRef,
+    /// The role this one was reached from, one step closer to the root of
+    /// the traversal
+    pub reached_from: i32,
+    /// Number of inheritance steps between this role and the root,
+    /// starting at 1
+    pub depth: i32,
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub(super) struct CreditRoleTreeRow {
+    pub id: i32,
+    pub name: String,
+    pub reached_from: i32,
+    pub depth: i32,
+}
+
+impl From<CreditRoleTreeRow> for CreditRoleTreeNode {
+    fn from(row: CreditRoleTreeRow) -> Self {
+        Self {
+            role: CreditRoleRef {
+                id: row.id,
+                name: row.name,
+            },
+            reached_from: row.reached_from,
+            depth: row.depth,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use entity::credit_role;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter,
+};
+
+use super::model::{CreditRoleTree, CreditRoleTreeNode, CreditRoleTreeRow};
+use crate::domain::Connection;
+use crate::infra::database::sea_orm::hierarchy::{self, Direction};
+
+pub(super) async fn find_tree<R>(
+    repo: &R,
+    id: i32,
+) -> Result<Option<CreditRoleTree>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let exists = credit_role::Entity::find()
+        .filter(credit_role::Column::Id.eq(id))
+        .count(repo.conn())
+        .await?
+        > 0;
+
+    if !exists {
+        return Ok(None);
+    }
+
+    let ancestors = walk(repo.conn(), id, Direction::Ancestors).await?;
+    let descendants = walk(repo.conn(), id, Direction::Descendants).await?;
+
+    Ok(Some(CreditRoleTree {
+        ancestors,
+        descendants,
+    }))
+}
+
+async fn walk(
+    db: &impl ConnectionTrait,
+    id: i32,
+    direction: Direction,
+) -> Result<Vec<CreditRoleTreeNode>, DbErr> {
+    Ok(hierarchy::CREDIT_ROLE
+        .walk::<CreditRoleTreeRow>(db, id, direction, "credit_role.name")
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
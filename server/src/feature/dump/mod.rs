@@ -0,0 +1,223 @@
+//! Full dumps of the database for offline use.
+//!
+//! Each entity type is written to its own gzip compressed JSON Lines file,
+//! serialized with the same models as the API. All files are read in one
+//! repeatable read transaction, so they are consistent with each other.
+//! A dump is written to a staging directory outside the served one and
+//! only renamed into place after its manifest, so readers never see a
+//! partial dump.
+
+mod model;
+mod writer;
+
+use std::path::Path;
+
+use chrono::Utc;
+use entity::{
+    artist, credit_role, event, label, release, song, song_lyrics, tag,
+};
+pub use model::{Manifest, ManifestFile};
+use sea_orm::{
+    AccessMode, ColumnTrait, DatabaseConnection, DatabaseTransaction, DbErr,
+    EntityTrait, IsolationLevel, QueryFilter, QueryOrder, QuerySelect, Select,
+    TransactionTrait,
+};
+use serde::Serialize;
+use tokio::fs;
+use tokio::task::block_in_place;
+use writer::JsonlWriter;
+
+use crate::infra::database::sea_orm::release::impls as release_repo;
+
+#[derive(Debug, snafu::Snafu)]
+pub enum Error {
+    #[snafu(transparent)]
+    Database { source: DbErr },
+    #[snafu(transparent)]
+    Io { source: std::io::Error },
+}
+
+/// Entities are read by id in batches of this size
+const BATCH_SIZE: u64 = 500;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const LATEST_FILE: &str = "latest.json";
+
+/// Write a new dump under `root` and remove the ones beyond the `keep`
+/// most recent. `staging_root` must be on the same file system as `root`.
+pub async fn export(
+    db: &DatabaseConnection,
+    root: &Path,
+    staging_root: &Path,
+    keep: usize,
+) -> Result<Manifest, Error> {
+    let created_at = Utc::now();
+    let name = created_at.format("%Y%m%dT%H%M%SZ").to_string();
+    let staging = staging_root.join(&name);
+
+    fs::create_dir_all(root).await?;
+    fs::create_dir_all(&staging).await?;
+
+    let files = match write_files(db, &staging).await {
+        Ok(files) => files,
+        Err(err) => {
+            let _ = fs::remove_dir_all(&staging).await;
+            return Err(err);
+        }
+    };
+
+    let manifest = Manifest {
+        name,
+        created_at,
+        files,
+    };
+    let json =
+        serde_json::to_vec_pretty(&manifest).map_err(std::io::Error::from)?;
+
+    fs::write(staging.join(MANIFEST_FILE), &json).await?;
+    fs::rename(&staging, root.join(&manifest.name)).await?;
+
+    let latest = staging_root.join(LATEST_FILE);
+    fs::write(&latest, &json).await?;
+    fs::rename(&latest, root.join(LATEST_FILE)).await?;
+
+    remove_old_dumps(root, keep.max(1)).await?;
+
+    Ok(manifest)
+}
+
+async fn write_files(
+    db: &DatabaseConnection,
+    dir: &Path,
+) -> Result<Vec<ManifestFile>, Error> {
+    use crate::feature::artist::find::repo as artist_repo;
+    use crate::feature::credit_role::find::repo as credit_role_repo;
+    use crate::feature::event::find::repo as event_repo;
+    use crate::feature::label::find::repo as label_repo;
+    use crate::feature::song::find::repo as song_repo;
+    use crate::feature::song_lyrics::find::repo as song_lyrics_repo;
+    use crate::feature::tag::find::repo as tag_repo;
+
+    let tx = db
+        .begin_with_config(
+            Some(IsolationLevel::RepeatableRead),
+            Some(AccessMode::ReadOnly),
+        )
+        .await?;
+
+    // A closure per entity type would be simpler, but the future of an
+    // async closure borrowing the transaction can't be proven `Send`
+    macro_rules! write_entities {
+        ($entity:ident, $repo:ident) => {{
+            let mut file = EntityFile::create(dir, stringify!($entity))?;
+            while let Some(select) =
+                file.next_batch(&tx, $entity::Column::Id).await?
+            {
+                file.write(&$repo::find_many_impl(select, &tx).await?)?;
+            }
+            file.finish()?
+        }};
+    }
+
+    let files = vec![
+        write_entities!(artist, artist_repo),
+        write_entities!(release, release_repo),
+        write_entities!(song, song_repo),
+        write_entities!(label, label_repo),
+        write_entities!(event, event_repo),
+        write_entities!(tag, tag_repo),
+        write_entities!(credit_role, credit_role_repo),
+        write_entities!(song_lyrics, song_lyrics_repo),
+    ];
+
+    tx.commit().await?;
+
+    Ok(files)
+}
+
+/// One file of a dump, read by id in batches. Ids are paged before the
+/// entities are loaded, so that a loader leaving out some entities of a
+/// batch doesn't end the file early
+struct EntityFile {
+    entity_type: &'static str,
+    path: String,
+    writer: JsonlWriter,
+    after: i32,
+}
+
+impl EntityFile {
+    fn create(dir: &Path, entity_type: &'static str) -> Result<Self, Error> {
+        let path = format!("{entity_type}.jsonl.gz");
+        let writer = block_in_place(|| JsonlWriter::create(&dir.join(&path)))?;
+
+        Ok(Self {
+            entity_type,
+            path,
+            writer,
+            after: 0,
+        })
+    }
+
+    async fn next_batch<E>(
+        &mut self,
+        tx: &DatabaseTransaction,
+        id: E::Column,
+    ) -> Result<Option<Select<E>>, Error>
+    where
+        E: EntityTrait,
+    {
+        let ids: Vec<i32> = E::find()
+            .select_only()
+            .column(id)
+            .filter(id.gt(self.after))
+            .order_by_asc(id)
+            .limit(BATCH_SIZE)
+            .into_tuple()
+            .all(tx)
+            .await?;
+
+        let Some(&last) = ids.last() else {
+            return Ok(None);
+        };
+        self.after = last;
+
+        Ok(Some(E::find().filter(id.is_in(ids)).order_by_asc(id)))
+    }
+
+    fn write<T: Serialize>(&mut self, entities: &[T]) -> Result<(), Error> {
+        block_in_place(|| self.writer.write(entities))?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<ManifestFile, Error> {
+        let summary = block_in_place(|| self.writer.finish())?;
+
+        Ok(ManifestFile {
+            entity_type: self.entity_type.to_owned(),
+            path: self.path,
+            count: summary.count,
+            size: summary.size,
+            sha256: summary.sha256,
+        })
+    }
+}
+
+/// Dump names are timestamps, so they sort by age
+async fn remove_old_dumps(root: &Path, keep: usize) -> Result<(), Error> {
+    let mut names = vec![];
+    let mut entries = fs::read_dir(root).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if entry.file_type().await?.is_dir() {
+            names.push(name);
+        }
+    }
+
+    names.sort_unstable();
+
+    for name in names.iter().rev().skip(keep) {
+        fs::remove_dir_all(root.join(name)).await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Written next to the dump files as `manifest.json`, and copied to
+/// `latest.json` in the dump directory once the dump is complete
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Directory of the dump, relative to the dump directory
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub files: Vec<ManifestFile>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub entity_type: String,
+    /// Relative to the directory of the dump
+    pub path: String,
+    /// Number of lines, one entity per line
+    pub count: u64,
+    /// Size of the compressed file in bytes
+    pub size: u64,
+    /// SHA-256 of the compressed file, in lowercase hex
+    pub sha256: String,
+}
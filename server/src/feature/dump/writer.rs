@@ -0,0 +1,114 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Counts and hashes the bytes on their way to the file, so the checksum
+/// is of the compressed output
+struct HashWriter<W> {
+    inner: W,
+    hasher: Sha256,
+    size: u64,
+}
+
+impl<W: Write> Write for HashWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+pub struct FileSummary {
+    pub count: u64,
+    pub size: u64,
+    pub sha256: String,
+}
+
+/// Gzip compressed JSON Lines
+pub struct JsonlWriter {
+    encoder: GzEncoder<HashWriter<BufWriter<File>>>,
+    count: u64,
+}
+
+impl JsonlWriter {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        let writer = HashWriter {
+            inner: file,
+            hasher: Sha256::new(),
+            size: 0,
+        };
+
+        Ok(Self {
+            encoder: GzEncoder::new(writer, Compression::default()),
+            count: 0,
+        })
+    }
+
+    pub fn write<T: Serialize>(&mut self, items: &[T]) -> io::Result<()> {
+        for item in items {
+            serde_json::to_writer(&mut self.encoder, item)?;
+            self.encoder.write_all(b"\n")?;
+            self.count += 1;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> io::Result<FileSummary> {
+        let mut writer = self.encoder.finish()?;
+        writer.flush()?;
+        writer.inner.into_inner()?.sync_all()?;
+
+        Ok(FileSummary {
+            count: self.count,
+            size: writer.size,
+            sha256: format!("{:x}", writer.hasher.finalize()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+
+    use super::*;
+
+    #[test]
+    fn write_compressed_lines_with_checksum() {
+        let path = std::env::temp_dir()
+            .join(format!("thcdb-dump-test-{}.jsonl.gz", std::process::id()));
+
+        let mut writer = JsonlWriter::create(&path).unwrap();
+        writer
+            .write(&[serde_json::json!({ "id": 1 }), serde_json::json!("二")])
+            .unwrap();
+        writer.write::<u8>(&[]).unwrap();
+        let summary = writer.finish().unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut lines = String::new();
+        GzDecoder::new(bytes.as_slice())
+            .read_to_string(&mut lines)
+            .unwrap();
+
+        assert_eq!(lines, "{\"id\":1}\n\"二\"\n");
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.size, bytes.len() as u64);
+        assert_eq!(summary.sha256, format!("{:x}", Sha256::digest(&bytes)));
+    }
+}
@@ -2,8 +2,13 @@ use utoipa_axum::router::OpenApiRouter;
 
 use crate::adapter::inbound::rest::state::ArcAppState;
 
-mod find;
+mod calendar;
+mod catalogue;
+pub mod find;
 
 pub fn router() -> OpenApiRouter<ArcAppState> {
-    OpenApiRouter::new().merge(find::router())
+    OpenApiRouter::new()
+        .merge(calendar::router())
+        .merge(catalogue::router())
+        .merge(find::router())
 }
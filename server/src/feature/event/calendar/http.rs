@@ -0,0 +1,108 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::ics::Calendar;
+use super::repo::CalendarFilter;
+use crate::adapter::inbound::rest::AppRouter;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::domain::event::Event;
+use crate::infra::error::Error;
+
+const TAG: &str = "Event";
+
+const CALENDAR_CONTENT_TYPE: &str = "text/calendar; charset=utf-8";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| {
+            r.routes(routes!(event_calendar))
+                .routes(routes!(single_event_calendar))
+        })
+        .finish()
+}
+
+#[derive(Deserialize, IntoParams)]
+struct CalendarQuery {
+    /// Defaults to today, so that subscribers get upcoming events
+    start_date_from: Option<NaiveDate>,
+    start_date_to: Option<NaiveDate>,
+    /// Matched case-insensitively, as are province and city
+    country: Option<String>,
+    province: Option<String>,
+    city: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/event/calendar.ics",
+    params(CalendarQuery),
+    responses(
+        (status = 200, body = String, content_type = "text/calendar"),
+        Error,
+    ),
+)]
+async fn event_calendar(
+    State(repo): State<state::SeaOrmRepository>,
+    Query(query): Query<CalendarQuery>,
+) -> Result<Response, Error> {
+    let filter = CalendarFilter {
+        start_date_from: query
+            .start_date_from
+            .unwrap_or_else(|| Utc::now().date_naive()),
+        start_date_to: query.start_date_to,
+        country: query.country,
+        province: query.province,
+        city: query.city,
+    };
+
+    let events = super::repo::find_calendar_events(&repo, filter).await?;
+
+    Ok(calendar_response(Some("Touhou Cloud Db events"), &events))
+}
+
+/// Also served at `/event/{id}.ics`
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/event/{id}/calendar.ics",
+    params(
+        ("id" = i32, Path),
+    ),
+    responses(
+        (status = 200, body = String, content_type = "text/calendar"),
+        (status = 404, description = "Event not found"),
+        Error,
+    ),
+)]
+async fn single_event_calendar(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Response, Error> {
+    let event = super::repo::find_event(&repo, id).await?;
+
+    Ok(event.map_or_else(
+        || StatusCode::NOT_FOUND.into_response(),
+        |event| calendar_response(None, &[event]),
+    ))
+}
+
+fn calendar_response(name: Option<&str>, events: &[Event]) -> Response {
+    let calendar = Calendar {
+        name,
+        events,
+        stamp: Utc::now(),
+    };
+
+    (
+        [(header::CONTENT_TYPE, CALENDAR_CONTENT_TYPE)],
+        calendar.render(),
+    )
+        .into_response()
+}
@@ -0,0 +1,292 @@
+//! Minimal iCalendar (RFC 5545) writer for event calendars
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, Utc};
+use entity::enums::DatePrecision;
+use itertools::Itertools;
+
+use crate::domain::event::Event;
+use crate::domain::shared::DateWithPrecision;
+
+const PRODID: &str = "-//Touhou Cloud Db//Event Calendar//EN";
+
+/// Content lines longer than this many octets are folded
+const MAX_LINE_OCTETS: usize = 75;
+
+pub struct Calendar<'a> {
+    pub name: Option<&'a str>,
+    pub events: &'a [Event],
+    pub stamp: DateTime<Utc>,
+}
+
+impl Calendar<'_> {
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        line(&mut out, "BEGIN:VCALENDAR");
+        line(&mut out, "VERSION:2.0");
+        line(&mut out, &format!("PRODID:{PRODID}"));
+        line(&mut out, "CALSCALE:GREGORIAN");
+        if let Some(name) = self.name {
+            line(&mut out, &format!("X-WR-CALNAME:{}", escape(name)));
+        }
+
+        for event in self.events {
+            self.render_event(&mut out, event);
+        }
+
+        line(&mut out, "END:VCALENDAR");
+
+        out
+    }
+
+    /// Events without at least a month to go by are left out
+    fn render_event(&self, out: &mut String, event: &Event) {
+        let Some(span) = AllDaySpan::new(event.start_date, event.end_date)
+        else {
+            return;
+        };
+
+        line(out, "BEGIN:VEVENT");
+        line(out, &format!("UID:event-{}@thcdb", event.id));
+        line(
+            out,
+            &format!("DTSTAMP:{}", self.stamp.format("%Y%m%dT%H%M%SZ")),
+        );
+        line(out, &format!("DTSTART;VALUE=DATE:{}", ics_date(span.start)));
+        line(out, &format!("DTEND;VALUE=DATE:{}", ics_date(span.end)));
+        line(out, &format!("SUMMARY:{}", escape(&event.name)));
+
+        let description = description(event, &span);
+        if !description.is_empty() {
+            line(out, &format!("DESCRIPTION:{}", escape(&description)));
+        }
+
+        let location = [
+            &event.location.city,
+            &event.location.province,
+            &event.location.country,
+        ]
+        .into_iter()
+        .flatten()
+        .join(", ");
+        if !location.is_empty() {
+            line(out, &format!("LOCATION:{}", escape(&location)));
+        }
+
+        if span.month_only {
+            // Don't block a whole month in the subscriber's calendar
+            line(out, "TRANSP:TRANSPARENT");
+        }
+
+        line(out, "END:VEVENT");
+    }
+}
+
+/// Dates of an all-day event, the end is exclusive
+struct AllDaySpan {
+    start: NaiveDate,
+    end: NaiveDate,
+    /// Only the month of the start or end is known
+    month_only: bool,
+}
+
+impl AllDaySpan {
+    fn new(
+        start: Option<DateWithPrecision>,
+        end: Option<DateWithPrecision>,
+    ) -> Option<Self> {
+        let start = start?;
+        let start_day = match start.precision {
+            DatePrecision::Day => start.value,
+            DatePrecision::Month => start.value.with_day(1)?,
+            DatePrecision::Year => return None,
+        };
+
+        let end_day = match end {
+            Some(end) if end.precision == DatePrecision::Day => {
+                end.value.checked_add_days(Days::new(1))
+            }
+            Some(end) if end.precision == DatePrecision::Month => {
+                next_month(end.value)
+            }
+            _ => match start.precision {
+                DatePrecision::Day => start_day.checked_add_days(Days::new(1)),
+                _ => next_month(start_day),
+            },
+        }?;
+
+        let month_only = start.precision == DatePrecision::Month
+            || end.is_some_and(|end| end.precision == DatePrecision::Month);
+
+        Some(Self {
+            start: start_day,
+            end: end_day.max(start_day.checked_add_days(Days::new(1))?),
+            month_only,
+        })
+    }
+}
+
+fn next_month(date: NaiveDate) -> Option<NaiveDate> {
+    date.with_day(1)?.checked_add_months(Months::new(1))
+}
+
+fn ics_date(date: NaiveDate) -> String {
+    date.format("%Y%m%d").to_string()
+}
+
+fn description(event: &Event, span: &AllDaySpan) -> String {
+    let mut paragraphs = vec![];
+
+    if span.month_only {
+        paragraphs.push("The exact dates are not known yet.".to_owned());
+    }
+    if !event.short_description.is_empty() {
+        paragraphs.push(event.short_description.clone());
+    }
+    if !event.alternative_names.is_empty() {
+        paragraphs.push(format!(
+            "Also known as: {}",
+            event.alternative_names.iter().map(|x| &x.name).join(", ")
+        ));
+    }
+
+    paragraphs.join("\n\n")
+}
+
+/// Escapes a TEXT value
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Writes a content line, folded without splitting characters
+fn line(out: &mut String, content: &str) {
+    let mut octets = 0;
+    for c in content.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space counts towards the line length
+            octets = 1;
+        }
+        octets += c.len_utf8();
+        out.push(c);
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::domain::event::AlternativeName;
+    use crate::domain::shared::Location;
+
+    fn date(value: &str, precision: DatePrecision) -> DateWithPrecision {
+        DateWithPrecision {
+            value: value.parse().unwrap(),
+            precision,
+        }
+    }
+
+    fn event(
+        start: Option<DateWithPrecision>,
+        end: Option<DateWithPrecision>,
+    ) -> Event {
+        Event {
+            id: 1,
+            name: "博麗神社例大祭".to_owned(),
+            short_description: String::new(),
+            description: String::new(),
+            location: Location {
+                country: Some("Japan".to_owned()),
+                province: None,
+                city: Some("Tokyo".to_owned()),
+            },
+            start_date: start,
+            end_date: end,
+            series: None,
+            alternative_names: vec![AlternativeName {
+                id: 1,
+                name: "Reitaisai".to_owned(),
+            }],
+            links: vec![],
+            external_ids: vec![],
+            circles: vec![],
+            releases: vec![],
+            circle_count: None,
+            release_count: None,
+        }
+    }
+
+    fn render(events: &[Event]) -> String {
+        Calendar {
+            name: None,
+            events,
+            stamp: Utc.with_ymd_and_hms(2026, 1, 2, 3, 4, 5).unwrap(),
+        }
+        .render()
+    }
+
+    #[test]
+    fn renders_day_precision_as_inclusive_all_day_span() {
+        let ics = render(&[event(
+            Some(date("2026-05-03", DatePrecision::Day)),
+            Some(date("2026-05-04", DatePrecision::Day)),
+        )]);
+
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260503\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20260505\r\n"));
+        assert!(ics.contains("DTSTAMP:20260102T030405Z\r\n"));
+        assert!(ics.contains("LOCATION:Tokyo\\, Japan\r\n"));
+        assert!(ics.contains("DESCRIPTION:Also known as: Reitaisai\r\n"));
+        assert!(!ics.contains("TRANSP"));
+    }
+
+    #[test]
+    fn renders_month_precision_as_whole_month() {
+        let ics = render(&[event(
+            Some(date("2026-08-15", DatePrecision::Month)),
+            None,
+        )]);
+
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260801\r\n"));
+        assert!(ics.contains("DTEND;VALUE=DATE:20260901\r\n"));
+        assert!(ics.contains("TRANSP:TRANSPARENT\r\n"));
+    }
+
+    #[test]
+    fn skips_events_without_a_month() {
+        let ics = render(&[
+            event(None, None),
+            event(Some(date("2026-01-01", DatePrecision::Year)), None),
+        ]);
+
+        assert!(!ics.contains("BEGIN:VEVENT"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn folds_long_lines_on_char_boundaries() {
+        let mut out = String::new();
+        line(&mut out, &format!("SUMMARY:{}", "東方".repeat(30)));
+
+        for content_line in out.split("\r\n") {
+            assert!(content_line.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(
+            out.replace("\r\n ", ""),
+            format!("SUMMARY:{}\r\n", "東方".repeat(30))
+        );
+    }
+}
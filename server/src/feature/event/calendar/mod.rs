@@ -0,0 +1,5 @@
+mod http;
+mod ics;
+mod repo;
+
+pub use http::router;
@@ -0,0 +1,70 @@
+use chrono::NaiveDate;
+use entity::event;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use sea_query::{ExprTrait, Func};
+
+use crate::domain::Connection;
+use crate::domain::event::Event;
+use crate::feature::event::find::repo::find_many_impl;
+
+/// Calendar feeds are not paginated, so they are capped instead
+const MAX_CALENDAR_EVENTS: u64 = 1000;
+
+pub(super) struct CalendarFilter {
+    pub start_date_from: NaiveDate,
+    pub start_date_to: Option<NaiveDate>,
+    pub country: Option<String>,
+    pub province: Option<String>,
+    pub city: Option<String>,
+}
+
+pub(super) async fn find_event<R>(
+    repo: &R,
+    id: i32,
+) -> Result<Option<Event>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let select = event::Entity::find().filter(event::Column::Id.eq(id));
+
+    find_many_impl(select, repo.conn())
+        .await
+        .map(|mut events| events.pop())
+}
+
+pub(super) async fn find_calendar_events<R>(
+    repo: &R,
+    filter: CalendarFilter,
+) -> Result<Vec<Event>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let mut select = event::Entity::find()
+        .filter(event::Column::StartDate.gte(filter.start_date_from))
+        .order_by_asc(event::Column::StartDate)
+        .order_by_asc(event::Column::Id)
+        .limit(MAX_CALENDAR_EVENTS);
+
+    if let Some(start_date_to) = filter.start_date_to {
+        select = select.filter(event::Column::StartDate.lte(start_date_to));
+    }
+
+    for (column, value) in [
+        (event::Column::LocationCountry, filter.country),
+        (event::Column::LocationProvince, filter.province),
+        (event::Column::LocationCity, filter.city),
+    ] {
+        if let Some(value) = value {
+            select = select.filter(
+                Func::lower(column.into_expr()).eq(value.to_lowercase()),
+            );
+        }
+    }
+
+    find_many_impl(select, repo.conn()).await
+}
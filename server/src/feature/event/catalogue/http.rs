@@ -0,0 +1,39 @@
+use axum::extract::{Path, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::EventCatalogue;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Event";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(find_event_catalogue)))
+        .finish()
+}
+
+data!(DataOptionEventCatalogue, Option<EventCatalogue>);
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/event/{id}/catalogue",
+    params(
+        ("id" = i32, Path),
+    ),
+    responses(
+        (status = 200, body = DataOptionEventCatalogue),
+        Error,
+    ),
+)]
+async fn find_event_catalogue(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<EventCatalogue>>, Error> {
+    super::repo::find_catalogue(&repo, id).await.bimap_into()
+}
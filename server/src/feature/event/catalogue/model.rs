@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::artist::SimpleArtist;
+use crate::domain::event::{Booth, SimpleEvent};
+use crate::utils::natural_sort::natural_cmp;
+
+/// Circles and releases at an event, grouped by day, hall and space
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct EventCatalogue {
+    pub event: SimpleEvent,
+    pub days: Vec<CatalogueDay>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CatalogueDay {
+    /// Day of the event, missing for entries without a known day
+    pub day: Option<i16>,
+    pub halls: Vec<CatalogueHall>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CatalogueHall {
+    pub hall: Option<String>,
+    pub spaces: Vec<CatalogueSpace>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CatalogueSpace {
+    pub space: Option<String>,
+    pub circles: Vec<SimpleArtist>,
+    pub releases: Vec<CatalogueRelease>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CatalogueRelease {
+    pub id: i32,
+    pub title: String,
+    pub artists: Vec<SimpleArtist>,
+}
+
+impl EventCatalogue {
+    /// Releases without a booth of their own are listed at the booth of the
+    /// first of their artists that exhibits at the event
+    pub fn new(
+        event: SimpleEvent,
+        circles: &[(SimpleArtist, Booth)],
+        releases: Vec<(CatalogueRelease, Booth)>,
+    ) -> Self {
+        let releases = releases.into_iter().map(|(release, booth)| {
+            let booth = if booth.is_empty() {
+                release
+                    .artists
+                    .iter()
+                    .find_map(|artist| {
+                        circles
+                            .iter()
+                            .find(|(circle, _)| circle.id == artist.id)
+                    })
+                    .map_or_else(Booth::default, |(_, booth)| booth.clone())
+            } else {
+                booth
+            };
+            (booth, Entry::Release(release))
+        });
+
+        let entries = circles
+            .iter()
+            .cloned()
+            .map(|(circle, booth)| (booth, Entry::Circle(circle)))
+            .chain(releases)
+            .sorted_by(|(a, _), (b, _)| cmp_booth(a, b))
+            .collect_vec();
+
+        let days = entries
+            .into_iter()
+            .chunk_by(|(booth, _)| booth.day)
+            .into_iter()
+            .map(|(day, entries)| CatalogueDay {
+                day,
+                halls: entries
+                    .chunk_by(|(booth, _)| booth.hall.clone())
+                    .into_iter()
+                    .map(|(hall, entries)| CatalogueHall {
+                        hall,
+                        spaces: entries
+                            .chunk_by(|(booth, _)| booth.space.clone())
+                            .into_iter()
+                            .map(|(space, entries)| {
+                                let (circles, releases) = entries
+                                    .partition_map(|(_, entry)| match entry {
+                                        Entry::Circle(x) => {
+                                            itertools::Either::Left(x)
+                                        }
+                                        Entry::Release(x) => {
+                                            itertools::Either::Right(x)
+                                        }
+                                    });
+                                CatalogueSpace {
+                                    space,
+                                    circles,
+                                    releases,
+                                }
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Self { event, days }
+    }
+}
+
+enum Entry {
+    Circle(SimpleArtist),
+    Release(CatalogueRelease),
+}
+
+/// Orders by day, hall and space, with unknown parts last
+fn cmp_booth(a: &Booth, b: &Booth) -> Ordering {
+    fn cmp_option<T>(
+        a: Option<&T>,
+        b: Option<&T>,
+        cmp: impl FnOnce(&T, &T) -> Ordering,
+    ) -> Ordering
+    where
+        T: ?Sized,
+    {
+        match (a, b) {
+            (Some(a), Some(b)) => cmp(a, b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    cmp_option(a.day.as_ref(), b.day.as_ref(), Ord::cmp)
+        .then_with(|| {
+            cmp_option(a.hall.as_deref(), b.hall.as_deref(), natural_cmp)
+        })
+        .then_with(|| {
+            cmp_option(a.space.as_deref(), b.space.as_deref(), natural_cmp)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn artist(id: i32) -> SimpleArtist {
+        SimpleArtist {
+            id,
+            name: format!("circle {id}"),
+        }
+    }
+
+    fn booth(day: i16, hall: &str, space: &str) -> Booth {
+        Booth {
+            day: Some(day),
+            hall: Some(hall.to_owned()),
+            space: Some(space.to_owned()),
+        }
+    }
+
+    fn release(id: i32, artists: &[i32]) -> CatalogueRelease {
+        CatalogueRelease {
+            id,
+            title: format!("release {id}"),
+            artists: artists.iter().copied().map(artist).collect(),
+        }
+    }
+
+    #[test]
+    fn groups_by_day_hall_and_space() {
+        let catalogue = EventCatalogue::new(
+            SimpleEvent {
+                id: 1,
+                name: "C105".to_owned(),
+            },
+            &[
+                (artist(1), booth(2, "東3ホール", "ケ-12a")),
+                (artist(2), booth(2, "東3ホール", "ケ-3b")),
+                (artist(3), booth(1, "西1ホール", "あ-1a")),
+            ],
+            vec![
+                (release(10, &[1]), Booth::default()),
+                (release(11, &[4]), booth(2, "東3ホール", "ケ-3b")),
+                (release(12, &[5]), Booth::default()),
+            ],
+        );
+
+        let layout = catalogue
+            .days
+            .iter()
+            .map(|day| {
+                (
+                    day.day,
+                    day.halls
+                        .iter()
+                        .flat_map(|hall| &hall.spaces)
+                        .map(|space| {
+                            (
+                                space.space.clone(),
+                                space
+                                    .circles
+                                    .iter()
+                                    .map(|x| x.id)
+                                    .collect_vec(),
+                                space
+                                    .releases
+                                    .iter()
+                                    .map(|x| x.id)
+                                    .collect_vec(),
+                            )
+                        })
+                        .collect_vec(),
+                )
+            })
+            .collect_vec();
+
+        assert_eq!(
+            layout,
+            [
+                (Some(1), vec![(Some("あ-1a".to_owned()), vec![3], vec![])]),
+                (
+                    Some(2),
+                    vec![
+                        (Some("ケ-3b".to_owned()), vec![2], vec![11]),
+                        (Some("ケ-12a".to_owned()), vec![1], vec![10]),
+                    ]
+                ),
+                (None, vec![(None, vec![], vec![12])]),
+            ]
+        );
+    }
+}
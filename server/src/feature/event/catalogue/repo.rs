@@ -0,0 +1,100 @@
+use entity::{
+    artist, event, event_circle, release, release_artist, release_event,
+};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use super::model::{CatalogueRelease, EventCatalogue};
+use crate::domain::Connection;
+use crate::domain::artist::SimpleArtist;
+use crate::domain::event::{Booth, SimpleEvent};
+
+pub(super) async fn find_catalogue<R>(
+    repo: &R,
+    event_id: i32,
+) -> Result<Option<EventCatalogue>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let Some(event) = event::Entity::find_by_id(event_id).one(db).await? else {
+        return Ok(None);
+    };
+
+    let (circles, releases) = tokio::try_join!(
+        event_circle::Entity::find()
+            .filter(event_circle::Column::EventId.eq(event_id))
+            .find_also_related(artist::Entity)
+            .all(db),
+        release_event::Entity::find()
+            .filter(release_event::Column::EventId.eq(event_id))
+            .find_also_related(release::Entity)
+            .order_by_asc(release_event::Column::ReleaseId)
+            .all(db),
+    )?;
+
+    let release_artists = release_artist::Entity::find()
+        .filter(
+            release_artist::Column::ReleaseId
+                .is_in(releases.iter().map(|(x, _)| x.release_id)),
+        )
+        .find_also_related(artist::Entity)
+        .order_by_asc(release_artist::Column::ArtistId)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|(link, artist)| {
+            Some((link.release_id, SimpleArtist::from(artist?)))
+        })
+        .into_group_map();
+
+    let circles = circles
+        .into_iter()
+        .filter_map(|(circle, artist)| {
+            Some((
+                SimpleArtist::from(artist?),
+                Booth::from_columns(
+                    circle.booth_day,
+                    circle.booth_hall,
+                    circle.booth_space,
+                )
+                .unwrap_or_default(),
+            ))
+        })
+        .collect_vec();
+
+    let releases = releases
+        .into_iter()
+        .filter_map(|(link, release)| {
+            Some((
+                CatalogueRelease {
+                    id: link.release_id,
+                    title: release?.title,
+                    artists: release_artists
+                        .get(&link.release_id)
+                        .cloned()
+                        .unwrap_or_default(),
+                },
+                Booth::from_columns(
+                    link.booth_day,
+                    link.booth_hall,
+                    link.booth_space,
+                )
+                .unwrap_or_default(),
+            ))
+        })
+        .collect();
+
+    Ok(Some(EventCatalogue::new(
+        SimpleEvent {
+            id: event.id,
+            name: event.name,
+        },
+        &circles,
+        releases,
+    )))
+}
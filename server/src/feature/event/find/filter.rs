@@ -15,6 +15,9 @@ pub struct EventFilter {
 
     pub start_date_to: Option<NaiveDate>,
 
+    /// Only editions of this event series
+    pub series_id: Option<i32>,
+
     #[serde(default)]
     pub sort_field: Option<CorrectionSortField>,
 
@@ -41,6 +44,9 @@ impl EventFilter {
         if let Some(start_date_to) = self.start_date_to {
             select = select.filter(event::Column::StartDate.lte(start_date_to));
         }
+        if let Some(series_id) = self.series_id {
+            select = select.filter(event::Column::SeriesId.eq(series_id));
+        }
 
         select
     }
@@ -10,7 +10,7 @@ use crate::adapter::inbound::rest::api_response::Data;
 use crate::adapter::inbound::rest::state::{self, ArcAppState};
 use crate::adapter::inbound::rest::{AppRouter, data};
 use crate::domain::event::Event;
-use crate::domain::shared::Paginated;
+use crate::domain::shared::{Paginated, SearchHit};
 use crate::infra::error::Error;
 
 const TAG: &str = "Event";
@@ -20,6 +20,7 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
         .with_public(|r| {
             r.routes(routes!(find_event_by_id))
                 .routes(routes!(find_event_by_keyword))
+                .routes(routes!(search_event_description))
                 .routes(routes!(explore_event))
         })
         .finish()
@@ -28,6 +29,7 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
 data! {
     DataOptionEvent, Option<Event>
     DataVecEvent, Vec<Event>
+    DataVecSearchHitEvent, Vec<SearchHit<Event>>
     DataPaginatedEvent, Paginated<Event>
 }
 
@@ -71,6 +73,28 @@ async fn find_event_by_keyword(
         .bimap_into()
 }
 
+/// Full-text search on the short description and description, best
+/// matches first with the matched parts highlighted
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/event/search",
+    params(
+        KeywordQuery
+    ),
+    responses(
+        (status = 200, body = DataVecSearchHitEvent),
+    ),
+)]
+async fn search_event_description(
+    State(repo): State<state::SeaOrmRepository>,
+    Query(query): Query<KeywordQuery>,
+) -> Result<Data<Vec<SearchHit<Event>>>, Error> {
+    super::repo::find_by_description(&repo, &query.keyword)
+        .await
+        .bimap_into()
+}
+
 #[utoipa::path(
     get,
     tag = TAG,
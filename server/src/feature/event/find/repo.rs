@@ -1,18 +1,25 @@
-use entity::{event, event_alternative_name};
+use entity::enums::EntityType;
+use entity::{
+    artist, event, event_alternative_name, event_circle, event_link,
+    event_series, release, release_event,
+};
 use itertools::{Itertools, izip};
 use sea_orm::{
-    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, LoaderTrait, QueryFilter,
-    QueryOrder,
+    ColumnTrait, ConnectionTrait, DbErr, EntityName, EntityTrait, LoaderTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
 };
 use sea_query::extension::postgres::PgBinOper;
 use sea_query::{ExprTrait, Func};
 
 use crate::domain::Connection;
-use crate::domain::event::{AlternativeName, Event};
-use crate::domain::shared::{DateWithPrecision, Location};
-use crate::infra::database::sea_orm::utils;
+use crate::domain::event::{
+    AlternativeName, Booth, Event, EventCircle, EventRelease,
+};
+use crate::domain::event_series::EventSeriesRef;
+use crate::domain::shared::{DateWithPrecision, Link, Location, SearchHit};
+use crate::infra::database::sea_orm::{description_search, external_id, utils};
 
-pub(super) async fn find_by_id<R>(
+pub(crate) async fn find_by_id<R>(
     repo: &R,
     id: i32,
 ) -> Result<Option<Event>, DbErr>
@@ -22,9 +29,87 @@ where
 {
     let select = event::Entity::find().filter(event::Column::Id.eq(id));
 
-    find_many_impl(select, repo.conn())
-        .await
-        .map(|mut events| events.pop())
+    let Some(mut event) = find_many_impl(select, repo.conn()).await?.pop()
+    else {
+        return Ok(None);
+    };
+
+    let (circles, releases, circle_count, release_count) = tokio::try_join!(
+        find_circles(id, repo.conn()),
+        find_releases(id, repo.conn()),
+        event_circle::Entity::find()
+            .filter(event_circle::Column::EventId.eq(id))
+            .count(repo.conn()),
+        release_event::Entity::find()
+            .filter(release_event::Column::EventId.eq(id))
+            .count(repo.conn()),
+    )?;
+    event.circles = circles;
+    event.releases = releases;
+    event.circle_count = Some(circle_count);
+    event.release_count = Some(release_count);
+
+    Ok(Some(event))
+}
+
+/// Largest number of circles and releases embedded in an event
+const EMBEDDED_LIMIT: u64 = 100;
+
+async fn find_circles(
+    event_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<EventCircle>, DbErr> {
+    Ok(event_circle::Entity::find()
+        .filter(event_circle::Column::EventId.eq(event_id))
+        .find_also_related(artist::Entity)
+        .order_by_asc(event_circle::Column::BoothDay)
+        .order_by_asc(event_circle::Column::BoothHall)
+        .order_by_asc(event_circle::Column::BoothSpace)
+        .order_by_asc(event_circle::Column::ArtistId)
+        .limit(EMBEDDED_LIMIT)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|(circle, artist)| {
+            Some(EventCircle {
+                artist: artist?.into(),
+                booth: Booth::from_columns(
+                    circle.booth_day,
+                    circle.booth_hall,
+                    circle.booth_space,
+                ),
+            })
+        })
+        .collect())
+}
+
+async fn find_releases(
+    event_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<EventRelease>, DbErr> {
+    Ok(release_event::Entity::find()
+        .filter(release_event::Column::EventId.eq(event_id))
+        .find_also_related(release::Entity)
+        .order_by_asc(release_event::Column::BoothDay)
+        .order_by_asc(release_event::Column::BoothHall)
+        .order_by_asc(release_event::Column::BoothSpace)
+        .order_by_asc(release_event::Column::ReleaseId)
+        .limit(EMBEDDED_LIMIT)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|(release_event, release)| {
+            Some(EventRelease {
+                id: release_event.release_id,
+                title: release?.title,
+                booth: Booth::from_columns(
+                    release_event.booth_day,
+                    release_event.booth_hall,
+                    release_event.booth_space,
+                ),
+            })
+        })
+        .collect())
 }
 
 pub(super) async fn find_by_keyword<R>(
@@ -50,6 +135,46 @@ where
     find_many_impl(selector, repo.conn()).await
 }
 
+/// Search results are not paginated, only the best matches are returned
+const DESCRIPTION_SEARCH_LIMIT: u64 = 50;
+
+pub(super) async fn find_by_description<R>(
+    repo: &R,
+    query: &str,
+) -> Result<Vec<SearchHit<Event>>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let (ids, mut highlights) = description_search::find_ids(
+        repo.conn(),
+        event::Entity.table_name(),
+        query,
+        DESCRIPTION_SEARCH_LIMIT,
+    )
+    .await?;
+
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let select =
+        event::Entity::find().filter(event::Column::Id.is_in(ids.clone()));
+    let events = utils::sort_by_id_list(
+        find_many_impl(select, repo.conn()).await?,
+        &ids,
+        |event| event.id,
+    );
+
+    Ok(events
+        .into_iter()
+        .map(|event| SearchHit {
+            highlight: highlights.remove(&event.id),
+            item: event,
+        })
+        .collect())
+}
+
 pub(super) async fn find_by_filter<R>(
     repo: &R,
     filter: super::EventFilter,
@@ -123,6 +248,9 @@ where
     if let Some(start_date_to) = filter.start_date_to {
         select = select.filter(event::Column::StartDate.lte(start_date_to));
     }
+    if let Some(series_id) = filter.series_id {
+        select = select.filter(event::Column::SeriesId.eq(series_id));
+    }
 
     let mut events = find_many_impl(select, repo.conn()).await?;
 
@@ -135,7 +263,7 @@ where
     Ok(utils::paginate_by_id(events, &pagination, |event| event.id))
 }
 
-async fn find_many_impl(
+pub(crate) async fn find_many_impl(
     selector: sea_orm::Select<event::Entity>,
     db: &impl ConnectionTrait,
 ) -> Result<Vec<Event>, sea_orm::DbErr> {
@@ -144,8 +272,19 @@ async fn find_many_impl(
     let alt_names =
         events.load_many(event_alternative_name::Entity, db).await?;
 
-    Ok(izip!(events, alt_names)
-        .map(|(event, alt_name)| Event {
+    let links = events.load_many(event_link::Entity, db).await?;
+
+    let series = events.load_one(event_series::Entity, db).await?;
+
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::Event,
+        events.iter().map(|event| event.id),
+        db,
+    )
+    .await?;
+
+    Ok(izip!(events, alt_names, links, series)
+        .map(|(event, alt_name, links, series)| Event {
             id: event.id,
             name: event.name,
             short_description: event.short_description,
@@ -169,6 +308,11 @@ async fn find_many_impl(
                 }),
                 _ => None,
             },
+            series: series.map(|series| EventSeriesRef {
+                id: series.id,
+                name: series.name,
+                ordinal: event.series_ordinal,
+            }),
             alternative_names: alt_name
                 .into_iter()
                 .map(|name| AlternativeName {
@@ -176,6 +320,16 @@ async fn find_many_impl(
                     name: name.name,
                 })
                 .collect_vec(),
+            links: links
+                .into_iter()
+                .sorted_by_key(|link| link.id)
+                .map(|link| Link::from_stored(link.url))
+                .collect_vec(),
+            external_ids: external_ids.remove(&event.id).unwrap_or_default(),
+            circles: vec![],
+            releases: vec![],
+            circle_count: None,
+            release_count: None,
         })
         .collect())
 }
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use entity::enums::EntityType;
+use entity::{event, event_series, release_event};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait, Select,
+};
+use sea_query::extension::postgres::PgBinOper::{
+    Similarity, SimilarityDistance,
+};
+use sea_query::{Expr, ExprTrait, Func, NullOrdering, Order, SimpleExpr};
+
+use crate::domain::Connection;
+use crate::domain::event::SimpleEvent;
+use crate::domain::event_series::{EventSeries, EventSeriesEdition};
+use crate::domain::shared::DateWithPrecision;
+use crate::infra::database::sea_orm::external_id;
+
+pub(super) async fn find_by_id<R>(
+    repo: &R,
+    id: i32,
+) -> Result<Option<EventSeries>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let select =
+        event_series::Entity::find().filter(event_series::Column::Id.eq(id));
+
+    find_many_impl(select, repo.conn())
+        .await
+        .map(|mut series| series.pop())
+}
+
+pub(super) async fn find_by_keyword<R>(
+    repo: &R,
+    keyword: &str,
+) -> Result<Vec<EventSeries>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    find_many_impl(keyword_select(keyword), repo.conn()).await
+}
+
+fn keyword_select(keyword: &str) -> Select<event_series::Entity> {
+    let search_term = Func::lower(keyword);
+
+    event_series::Entity::find()
+        .filter(
+            Func::lower(event_series::Column::Name.into_expr())
+                .binary(Similarity, search_term.clone()),
+        )
+        .order_by_asc(
+            Func::lower(event_series::Column::Name.into_expr())
+                .binary(SimilarityDistance, search_term),
+        )
+}
+
+async fn find_many_impl(
+    select: Select<event_series::Entity>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<EventSeries>, DbErr> {
+    let series = select.all(db).await?;
+    if series.is_empty() {
+        return Ok(vec![]);
+    }
+    let series_ids = series.iter().map(|x| x.id).collect_vec();
+
+    let events = editions_select(&series_ids).all(db).await?;
+
+    let edition_release_counts: HashMap<i32, i64> =
+        edition_release_counts_select(events.iter().map(|x| x.id))
+            .into_tuple::<(i32, i64)>()
+            .all(db)
+            .await?
+            .into_iter()
+            .collect();
+
+    let series_release_counts: HashMap<i32, i64> =
+        series_release_counts_select(&series_ids)
+            .into_tuple::<(i32, i64)>()
+            .all(db)
+            .await?
+            .into_iter()
+            .collect();
+
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::EventSeries,
+        series_ids,
+        db,
+    )
+    .await?;
+
+    let mut editions = events
+        .into_iter()
+        .map(|event| {
+            let series_id = event.series_id;
+            let edition = EventSeriesEdition {
+                ordinal: event.series_ordinal,
+                start_date: DateWithPrecision::from_option(
+                    event.start_date,
+                    event.start_date_precision,
+                ),
+                release_count: edition_release_counts
+                    .get(&event.id)
+                    .copied()
+                    .unwrap_or_default(),
+                event: SimpleEvent {
+                    id: event.id,
+                    name: event.name,
+                },
+            };
+            (series_id, edition)
+        })
+        .into_group_map();
+
+    Ok(series
+        .into_iter()
+        .map(|series| EventSeries {
+            editions: editions.remove(&Some(series.id)).unwrap_or_default(),
+            release_count: series_release_counts
+                .get(&series.id)
+                .copied()
+                .unwrap_or_default(),
+            id: series.id,
+            name: series.name,
+            description: series.description,
+            external_ids: external_ids.remove(&series.id).unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// Numbered editions first, the rest by date
+fn editions_select(series_ids: &[i32]) -> Select<event::Entity> {
+    event::Entity::find()
+        .filter(event::Column::SeriesId.is_in(series_ids.iter().copied()))
+        .order_by_with_nulls(
+            event::Column::SeriesOrdinal,
+            Order::Asc,
+            NullOrdering::Last,
+        )
+        .order_by_with_nulls(
+            event::Column::StartDate,
+            Order::Asc,
+            NullOrdering::Last,
+        )
+        .order_by_asc(event::Column::Id)
+}
+
+fn edition_release_counts_select(
+    event_ids: impl IntoIterator<Item = i32>,
+) -> Select<release_event::Entity> {
+    release_event::Entity::find()
+        .select_only()
+        .column(release_event::Column::EventId)
+        .column_as(release_event::Column::ReleaseId.count(), "count")
+        .filter(release_event::Column::EventId.is_in(event_ids))
+        .group_by(release_event::Column::EventId)
+}
+
+/// A release sold at several editions is only counted once
+fn series_release_counts_select(
+    series_ids: &[i32],
+) -> Select<release_event::Entity> {
+    release_event::Entity::find()
+        .select_only()
+        .column(event::Column::SeriesId)
+        .column_as(
+            SimpleExpr::from(Func::count_distinct(Expr::col((
+                release_event::Entity,
+                release_event::Column::ReleaseId,
+            )))),
+            "count",
+        )
+        .join(JoinType::InnerJoin, release_event::Relation::Event.def())
+        .filter(event::Column::SeriesId.is_in(series_ids.iter().copied()))
+        .group_by(event::Column::SeriesId)
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::QueryTrait;
+
+    use super::*;
+
+    fn build<E: EntityTrait>(select: Select<E>) -> String {
+        select.build(sea_orm::DatabaseBackend::Postgres).to_string()
+    }
+
+    #[test]
+    fn keyword_ranked_by_similarity() {
+        assert_eq!(
+            build(
+                keyword_select("Reitaisai")
+                    .select_only()
+                    .column(event_series::Column::Id)
+            ),
+            r#"SELECT "event_series"."id" FROM "event_series" WHERE LOWER("event_series"."name") % LOWER('Reitaisai') ORDER BY LOWER("event_series"."name") <-> LOWER('Reitaisai') ASC"#
+        );
+    }
+
+    #[test]
+    fn editions_numbered_first_then_by_date() {
+        assert_eq!(
+            build(
+                editions_select(&[1, 2])
+                    .select_only()
+                    .column(event::Column::Id)
+            ),
+            r#"SELECT "event"."id" FROM "event" WHERE "event"."series_id" IN (1, 2) ORDER BY "event"."series_ordinal" ASC NULLS LAST, "event"."start_date" ASC NULLS LAST, "event"."id" ASC"#
+        );
+    }
+
+    #[test]
+    fn release_counts_per_edition() {
+        assert_eq!(
+            build(edition_release_counts_select([3, 4])),
+            r#"SELECT "release_event"."event_id", COUNT("release_event"."release_id") AS "count" FROM "release_event" WHERE "release_event"."event_id" IN (3, 4) GROUP BY "release_event"."event_id""#
+        );
+    }
+
+    #[test]
+    fn release_counts_per_series_are_distinct() {
+        assert_eq!(
+            build(series_release_counts_select(&[1])),
+            r#"SELECT "event"."series_id", COUNT(DISTINCT "release_event"."release_id") AS "count" FROM "release_event" INNER JOIN "event" ON "release_event"."event_id" = "event"."id" WHERE "event"."series_id" IN (1) GROUP BY "event"."series_id""#
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use axum::extract::{Path, Query, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::LabelCatalog;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+use crate::shared::http::PaginationQuery;
+
+const TAG: &str = "Label";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(find_label_releases)))
+        .finish()
+}
+
+data! {
+    DataOptionLabelCatalog, Option<LabelCatalog>
+}
+
+/// Releases of a label ordered naturally by catalog number, so that
+/// "ABCD-0010" follows "ABCD-0009".
+/// The cursor is the id of the last catalog entry of the previous page.
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/label/{id}/releases",
+    params(
+        ("id" = i32, Path),
+        PaginationQuery,
+    ),
+    responses(
+        (status = 200, body = DataOptionLabelCatalog),
+        Error,
+    ),
+)]
+async fn find_label_releases(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Data<Option<LabelCatalog>>, Error> {
+    super::repo::find_catalog(&repo, id, pagination.cursor, pagination.limit())
+        .await
+        .bimap_into()
+}
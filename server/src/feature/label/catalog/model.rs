@@ -0,0 +1,229 @@
+use std::cmp::Ordering;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::shared::DateWithPrecision;
+use crate::utils::natural_sort::natural_cmp;
+
+/// A page of a label's releases in catalog number order
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LabelCatalog {
+    pub entries: Vec<CatalogEntry>,
+    /// Holes in the numbering following any entry of this page, including
+    /// the one between the last entry and the first of the next page
+    pub gaps: Vec<CatalogGap>,
+    /// Id of the last entry of this page
+    pub next_cursor: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CatalogEntry {
+    pub id: i32,
+    pub catalog_number: String,
+    pub release: CatalogRelease,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CatalogRelease {
+    pub id: i32,
+    pub title: String,
+    pub release_date: Option<DateWithPrecision>,
+}
+
+/// Missing catalog numbers between two consecutive entries of the same
+/// series, e.g. "ABCD-0010" to "ABCD-0011" between "ABCD-0009" and
+/// "ABCD-0012"
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, ToSchema)]
+pub struct CatalogGap {
+    pub after: String,
+    pub before: String,
+    pub first_missing: String,
+    pub last_missing: String,
+    pub missing_count: u64,
+}
+
+impl LabelCatalog {
+    /// Sorts all entries of a label and cuts the page following the entry
+    /// with the id of `cursor`
+    pub fn paginate(
+        mut entries: Vec<CatalogEntry>,
+        cursor: Option<i32>,
+        limit: u32,
+    ) -> Self {
+        entries.sort_by(cmp_entry);
+
+        let start = cursor.map_or(0, |cursor| {
+            entries
+                .iter()
+                .position(|entry| entry.id == cursor)
+                .map_or(entries.len(), |index| index + 1)
+        });
+        let end = entries.len().min(start + limit as usize);
+
+        let gaps = entries[start..entries.len().min(end + 1)]
+            .windows(2)
+            .filter_map(|pair| {
+                CatalogGap::between(
+                    &pair[0].catalog_number,
+                    &pair[1].catalog_number,
+                )
+            })
+            .collect();
+
+        let next_cursor = if end < entries.len() {
+            entries.get(end.wrapping_sub(1)).map(|entry| entry.id)
+        } else {
+            None
+        };
+
+        Self {
+            entries: entries.drain(start..end).collect(),
+            gaps,
+            next_cursor,
+        }
+    }
+}
+
+fn cmp_entry(a: &CatalogEntry, b: &CatalogEntry) -> Ordering {
+    natural_cmp(&a.catalog_number, &b.catalog_number)
+        .then_with(|| a.id.cmp(&b.id))
+}
+
+/// A catalog number split around its last run of digits
+struct Numbered<'a> {
+    prefix: &'a str,
+    digits: &'a str,
+    suffix: &'a str,
+}
+
+impl<'a> Numbered<'a> {
+    fn parse(catalog_number: &'a str) -> Option<Self> {
+        let end = catalog_number.rfind(|c: char| c.is_ascii_digit())? + 1;
+        let start = catalog_number[..end]
+            .rfind(|c: char| !c.is_ascii_digit())
+            .map_or(0, |index| index + 1);
+
+        Some(Self {
+            prefix: &catalog_number[..start],
+            digits: &catalog_number[start..end],
+            suffix: &catalog_number[end..],
+        })
+    }
+
+    fn value(&self) -> Option<u64> {
+        self.digits.parse().ok()
+    }
+
+    fn format(&self, value: u64) -> String {
+        format!(
+            "{}{:0width$}{}",
+            self.prefix,
+            value,
+            self.suffix,
+            width = self.digits.len()
+        )
+    }
+
+    fn same_series(&self, other: &Self) -> bool {
+        self.prefix == other.prefix && self.suffix == other.suffix
+    }
+}
+
+impl CatalogGap {
+    fn between(after: &str, before: &str) -> Option<Self> {
+        let lower = Numbered::parse(after)?;
+        let upper = Numbered::parse(before)?;
+        if !lower.same_series(&upper) {
+            return None;
+        }
+
+        let (from, to) = (lower.value()?, upper.value()?);
+        let missing_count = to.checked_sub(from)?.checked_sub(1)?;
+        if missing_count == 0 {
+            return None;
+        }
+
+        Some(Self {
+            after: after.to_owned(),
+            before: before.to_owned(),
+            first_missing: lower.format(from + 1),
+            last_missing: lower.format(to - 1),
+            missing_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i32, catalog_number: &str) -> CatalogEntry {
+        CatalogEntry {
+            id,
+            catalog_number: catalog_number.to_owned(),
+            release: CatalogRelease {
+                id,
+                title: String::new(),
+                release_date: None,
+            },
+        }
+    }
+
+    fn numbers(catalog: &LabelCatalog) -> Vec<&str> {
+        catalog
+            .entries
+            .iter()
+            .map(|entry| entry.catalog_number.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn finds_gaps() {
+        assert_eq!(
+            CatalogGap::between("ABCD-0009", "ABCD-0012"),
+            Some(CatalogGap {
+                after: "ABCD-0009".to_owned(),
+                before: "ABCD-0012".to_owned(),
+                first_missing: "ABCD-0010".to_owned(),
+                last_missing: "ABCD-0011".to_owned(),
+                missing_count: 2,
+            })
+        );
+        assert_eq!(CatalogGap::between("ABCD-0009", "ABCD-0010"), None);
+        assert_eq!(CatalogGap::between("ABCD-0009", "XYZ-0012"), None);
+        assert_eq!(CatalogGap::between("ABCD-0009", "ABCD"), None);
+        assert_eq!(
+            CatalogGap::between("TH-1a", "TH-4a").map(|gap| gap.last_missing),
+            Some("TH-3a".to_owned())
+        );
+    }
+
+    #[test]
+    fn paginates_in_natural_order() {
+        let entries = vec![
+            entry(1, "ABCD-0010"),
+            entry(2, "ABCD-0009"),
+            entry(3, "ABCD-0013"),
+            entry(4, "ABCD-0002"),
+        ];
+
+        let first = LabelCatalog::paginate(entries.clone(), None, 2);
+        assert_eq!(numbers(&first), ["ABCD-0002", "ABCD-0009"]);
+        assert_eq!(first.next_cursor, Some(2));
+        assert_eq!(
+            first
+                .gaps
+                .iter()
+                .map(|gap| gap.missing_count)
+                .collect::<Vec<_>>(),
+            [6]
+        );
+
+        let second = LabelCatalog::paginate(entries, first.next_cursor, 2);
+        assert_eq!(numbers(&second), ["ABCD-0010", "ABCD-0013"]);
+        assert_eq!(second.next_cursor, None);
+        assert_eq!(second.gaps.len(), 1);
+        assert_eq!(second.gaps[0].first_missing, "ABCD-0011");
+    }
+}
@@ -0,0 +1,55 @@
+use entity::{label, release, release_catalog_number};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter,
+};
+
+use super::model::{CatalogEntry, CatalogRelease, LabelCatalog};
+use crate::domain::Connection;
+use crate::domain::shared::DateWithPrecision;
+
+pub(super) async fn find_catalog<R>(
+    repo: &R,
+    label_id: i32,
+    cursor: Option<i32>,
+    limit: u32,
+) -> Result<Option<LabelCatalog>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let exists = label::Entity::find_by_id(label_id).count(db).await? > 0;
+    if !exists {
+        return Ok(None);
+    }
+
+    // Natural order can't be expressed in SQL, so the whole catalog is
+    // sorted here before cutting the page
+    let entries = release_catalog_number::Entity::find()
+        .filter(release_catalog_number::Column::LabelId.eq(label_id))
+        .find_also_related(release::Entity)
+        .all(db)
+        .await?
+        .into_iter()
+        .filter_map(|(catalog_number, release)| {
+            let release = release?;
+            Some(CatalogEntry {
+                id: catalog_number.id,
+                catalog_number: catalog_number.catalog_number,
+                release: CatalogRelease {
+                    id: release.id,
+                    title: release.title,
+                    release_date: DateWithPrecision::from_option(
+                        release.release_date,
+                        release.release_date_precision,
+                    ),
+                },
+            })
+        })
+        .collect_vec();
+
+    Ok(Some(LabelCatalog::paginate(entries, cursor, limit)))
+}
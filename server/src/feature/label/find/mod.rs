@@ -1,6 +1,6 @@
 mod filter;
 mod http;
-mod repo;
+pub mod repo;
 
 pub use filter::{LabelFilter, PaginationQuery};
 pub use http::router;
@@ -1,4 +1,8 @@
-use entity::{label, label_founder, label_localized_name, language};
+use entity::enums::EntityType;
+use entity::{
+    label, label_founder, label_link, label_localized_name, label_relation,
+    language,
+};
 use itertools::{Itertools, izip};
 use sea_orm::{
     ColumnTrait, ConnectionTrait, DbErr, EntityTrait, LoaderTrait, QueryFilter,
@@ -8,9 +12,9 @@ use sea_query::extension::postgres::PgBinOper;
 use sea_query::{ExprTrait, Func};
 
 use crate::domain::Connection;
-use crate::domain::label::Label;
-use crate::domain::shared::{DateWithPrecision, LocalizedName};
-use crate::infra::database::sea_orm::utils;
+use crate::domain::label::{Label, LabelRelation, SimpleLabel};
+use crate::domain::shared::{DateWithPrecision, Link, LocalizedName};
+use crate::infra::database::sea_orm::{external_id, utils};
 
 pub(super) async fn find_by_id<R>(
     repo: &R,
@@ -143,7 +147,7 @@ where
     Ok(utils::paginate_by_id(labels, &pagination, |label| label.id))
 }
 
-async fn find_many_impl(
+pub(crate) async fn find_many_impl(
     select: sea_orm::Select<label::Entity>,
     db: &impl ConnectionTrait,
 ) -> Result<Vec<Label>, sea_orm::DbErr> {
@@ -154,6 +158,15 @@ async fn find_many_impl(
     let localized_names =
         labels.load_many(label_localized_name::Entity, db).await?;
 
+    let links = labels.load_many(label_link::Entity, db).await?;
+
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::Label,
+        labels.iter().map(|label| label.id),
+        db,
+    )
+    .await?;
+
     let langs = language::Entity::find()
         .filter(
             language::Column::Id.is_in(
@@ -165,25 +178,50 @@ async fn find_many_impl(
         .all(db)
         .await?;
 
-    Ok(izip!(labels, founders, localized_names)
-        .map(|(label, founders, names)| {
-            let founded_date =
-                match (label.founded_date, label.founded_date_precision) {
-                    (Some(date), precision) => Some(DateWithPrecision {
-                        value: date,
-                        precision,
-                    }),
-                    _ => None,
-                };
-
-            let dissolved_date =
-                match (label.dissolved_date, label.dissolved_date_precision) {
-                    (Some(date), precision) => Some(DateWithPrecision {
-                        value: date,
-                        precision,
-                    }),
-                    _ => None,
-                };
+    let relations = label_relation::Entity::find()
+        .filter(
+            label_relation::Column::LabelId
+                .is_in(labels.iter().map(|label| label.id)),
+        )
+        .all(db)
+        .await?;
+
+    let related_labels =
+        label::Entity::find()
+            .filter(label::Column::Id.is_in(
+                relations.iter().map(|relation| relation.related_label_id),
+            ))
+            .all(db)
+            .await?;
+
+    Ok(izip!(labels, founders, localized_names, links)
+        .map(|(label, founders, names, links)| {
+            let relations = relations
+                .iter()
+                .filter(|relation| relation.label_id == label.id)
+                .filter_map(|relation| {
+                    let related = related_labels
+                        .iter()
+                        .find(|x| x.id == relation.related_label_id)?;
+                    Some(LabelRelation {
+                        label: SimpleLabel {
+                            id: related.id,
+                            name: related.name.clone(),
+                        },
+                        r#type: relation.r#type,
+                    })
+                })
+                .sorted_by_key(|relation| relation.label.id)
+                .collect();
+
+            let founded_date = DateWithPrecision::from_option(
+                label.founded_date,
+                label.founded_date_precision,
+            );
+            let dissolved_date = DateWithPrecision::from_option(
+                label.dissolved_date,
+                label.dissolved_date_precision,
+            );
 
             let founders = founders.into_iter().map(|x| x.artist_id).collect();
 
@@ -207,6 +245,15 @@ async fn find_many_impl(
                 dissolved_date,
                 founders,
                 localized_names,
+                links: links
+                    .into_iter()
+                    .sorted_by_key(|link| link.id)
+                    .map(|link| Link::from_stored(link.url))
+                    .collect(),
+                external_ids: external_ids
+                    .remove(&label.id)
+                    .unwrap_or_default(),
+                relations,
             }
         })
         .collect_vec())
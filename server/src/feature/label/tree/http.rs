@@ -0,0 +1,36 @@
+use axum::extract::{Path, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::LabelTree;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Label";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(find_label_tree)))
+        .finish()
+}
+
+data!(DataOptionLabelTree, Option<LabelTree>);
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/label/{id}/tree",
+    responses(
+        (status = 200, body = DataOptionLabelTree),
+        Error,
+    ),
+)]
+async fn find_label_tree(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<LabelTree>>, Error> {
+    super::repo::find_tree(&repo, id).await.bimap_into()
+}
@@ -0,0 +1,50 @@
+use entity::sea_orm_active_enums::LabelRelationType;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::label::SimpleLabel;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LabelTree {
+    /// Parents, distributors and labels this one is an imprint of, and
+    /// theirs in turn
+    pub ancestors: Vec<LabelTreeNode>,
+    /// Imprints, subsidiaries and distributed labels, and theirs in turn
+    pub descendants: Vec<LabelTreeNode>,
+}
+
+/// A label reached while walking the hierarchy. A label reachable through
+/// several relations appears once per relation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LabelTreeNode {
+    pub label: SimpleLabel,
+    /// The label this one was reached from, one step closer to the root of
+    /// the traversal
+    pub reached_from: i32,
+    pub relation_type: LabelRelationType,
+    /// Number of relations between this label and the root, starting at 1
+    pub depth: i32,
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub(super) struct LabelTreeRow {
+    pub id: i32,
+    pub name: String,
+    pub reached_from: i32,
+    pub relation_type: LabelRelationType,
+    pub depth: i32,
+}
+
+impl From<LabelTreeRow> for LabelTreeNode {
+    fn from(row: LabelTreeRow) -> Self {
+        Self {
+            label: SimpleLabel {
+                id: row.id,
+                name: row.name,
+            },
+            reached_from: row.reached_from,
+            relation_type: row.relation_type,
+            depth: row.depth,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use entity::label;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter,
+};
+
+use super::model::{LabelTree, LabelTreeNode, LabelTreeRow};
+use crate::domain::Connection;
+use crate::infra::database::sea_orm::hierarchy::{self, Direction};
+
+pub(super) async fn find_tree<R>(
+    repo: &R,
+    id: i32,
+) -> Result<Option<LabelTree>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let exists = label::Entity::find()
+        .filter(label::Column::Id.eq(id))
+        .count(repo.conn())
+        .await?
+        > 0;
+
+    if !exists {
+        return Ok(None);
+    }
+
+    let ancestors = walk(repo.conn(), id, Direction::Ancestors).await?;
+    let descendants = walk(repo.conn(), id, Direction::Descendants).await?;
+
+    Ok(Some(LabelTree {
+        ancestors,
+        descendants,
+    }))
+}
+
+async fn walk(
+    db: &impl ConnectionTrait,
+    id: i32,
+    direction: Direction,
+) -> Result<Vec<LabelTreeNode>, DbErr> {
+    Ok(hierarchy::LABEL
+        .walk::<LabelTreeRow>(db, id, direction, "label.name")
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
@@ -0,0 +1,77 @@
+use axum::extract::{Query, State};
+use entity::enums::EntityType;
+use macros::{ApiError, IntoErrorSchema};
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::LookupResult;
+use super::repo;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::domain::shared::{
+    ExternalIdSource, InvalidExternalId, NewExternalId,
+};
+use crate::infra;
+
+const TAG: &str = "Lookup";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(lookup_external_id)))
+        .finish()
+}
+
+data!(DataVecLookupResult, Vec<LookupResult>);
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+enum Error {
+    #[snafu(transparent)]
+    InvalidId { source: InvalidExternalId },
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+}
+
+impl<E> From<E> for Error
+where
+    E: Into<infra::Error>,
+{
+    default fn from(err: E) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+struct LookupQuery {
+    #[param(inline)]
+    source: ExternalIdSource,
+    /// Id on the source, normalized the same way as submitted ids
+    id: String,
+    /// Only return entities of this type
+    #[param(inline)]
+    entity_type: Option<EntityType>,
+}
+
+/// Find the entities linked to an id of another database
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/lookup",
+    params(LookupQuery),
+    responses(
+        (status = 200, body = DataVecLookupResult),
+        Error,
+    ),
+)]
+async fn lookup_external_id(
+    State(repo): State<state::SeaOrmRepository>,
+    Query(query): Query<LookupQuery>,
+) -> Result<Data<Vec<LookupResult>>, Error> {
+    let id = NewExternalId::try_new(query.source, &query.id)?;
+
+    let results = repo::find_entities(&repo, &id, query.entity_type).await?;
+
+    Ok(Data::new(results))
+}
@@ -0,0 +1,10 @@
+use entity::enums::EntityType;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// A thcdb entity carrying the looked up external id
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct LookupResult {
+    pub entity_type: EntityType,
+    pub entity_id: i32,
+}
@@ -0,0 +1,39 @@
+use entity::enums::EntityType;
+use entity::external_id;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QueryTrait,
+};
+
+use super::model::LookupResult;
+use crate::domain::Connection;
+use crate::domain::shared::NewExternalId;
+
+/// Ids are not unique across entity types, e.g. `VGMdb` numbers artists
+/// and albums separately, so every match is returned
+pub async fn find_entities<R>(
+    repo: &R,
+    id: &NewExternalId,
+    entity_type: Option<EntityType>,
+) -> Result<Vec<LookupResult>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    Ok(external_id::Entity::find()
+        .filter(external_id::Column::Source.eq(id.source))
+        .filter(external_id::Column::Value.eq(&id.value))
+        .apply_if(entity_type, |query, entity_type| {
+            query.filter(external_id::Column::EntityType.eq(entity_type))
+        })
+        .order_by_asc(external_id::Column::EntityType)
+        .order_by_asc(external_id::Column::EntityId)
+        .all(repo.conn())
+        .await?
+        .into_iter()
+        .map(|model| LookupResult {
+            entity_type: model.entity_type,
+            entity_id: model.entity_id,
+        })
+        .collect())
+}
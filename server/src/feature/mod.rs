@@ -1,12 +1,20 @@
 pub mod artist;
+mod autocomplete;
 pub mod correction;
 pub mod credit_role;
+pub mod dump;
 pub mod enum_table;
 pub mod event;
+pub mod event_series;
 pub mod label;
+mod lookup;
+mod musicbrainz_import;
+pub mod page;
 pub mod release;
+pub mod release_group;
 pub mod song;
 pub mod song_lyrics;
+pub mod source_work;
 pub mod tag;
 mod tag_vote;
 pub mod user;
@@ -18,14 +26,20 @@ use crate::adapter::inbound::rest::state::ArcAppState;
 pub fn router() -> OpenApiRouter<ArcAppState> {
     OpenApiRouter::new()
         .merge(artist::router())
+        .merge(autocomplete::router())
         .merge(correction::router())
         .merge(credit_role::router())
         .merge(enum_table::router())
         .merge(event::router())
+        .merge(event_series::router())
         .merge(label::router())
+        .merge(lookup::router())
+        .merge(musicbrainz_import::router())
         .merge(release::router())
+        .merge(release_group::router())
         .merge(song::router())
         .merge(song_lyrics::router())
+        .merge(source_work::router())
         .merge(tag::router())
         .merge(tag_vote::router())
         .merge(user::router())
@@ -0,0 +1,96 @@
+use axum::body::Bytes;
+use axum::extract::State;
+use axum_typed_multipart::{FieldData, TryFromMultipart, TypedMultipart};
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::Error;
+use super::import::{self, Submitter};
+use super::model::ImportReport;
+use super::musicbrainz::Documents;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, CurrentUser, data};
+use crate::constant::IMPORT_BOT_USERNAME;
+use crate::domain::model::UserRoleEnum;
+use crate::domain::user::Repository;
+use crate::infra;
+
+const TAG: &str = "Import";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_private(|r| r.routes(routes!(import_musicbrainz)))
+        .finish()
+}
+
+data! {
+    DataImportReport, ImportReport
+}
+
+#[derive(Debug, ToSchema, TryFromMultipart)]
+pub struct MusicBrainzImportFormData {
+    /// Web service JSON, or a dump file with one entity per line
+    #[form_data(limit = "64MiB")]
+    #[schema(
+        value_type = String,
+        format = Binary,
+        maximum = 67108864, // 64 MiB
+    )]
+    pub data: FieldData<Bytes>,
+    /// Report what would be imported without submitting anything
+    #[form_data(default)]
+    pub dry_run: bool,
+}
+
+/// Import `MusicBrainz` releases and artists.
+/// Entities are matched by their `MusicBrainz` id, missing artists, songs
+/// and releases are submitted as corrections by the import bot, with their
+/// `MusicBrainz` id. Labels are only matched, never created.
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/admin/import/musicbrainz",
+    request_body(
+        content = MusicBrainzImportFormData,
+        content_type = "multipart/form-data"
+    ),
+    responses(
+        (status = 200, body = DataImportReport),
+        Error,
+    ),
+)]
+async fn import_musicbrainz(
+    CurrentUser(user): CurrentUser,
+    State(repo): State<state::SeaOrmRepository>,
+    State(artist): State<state::ArtistService>,
+    State(song): State<state::SongService>,
+    State(release): State<state::ReleaseService>,
+    TypedMultipart(form): TypedMultipart<MusicBrainzImportFormData>,
+) -> Result<Data<ImportReport>, Error> {
+    if !user.has_roles(&[UserRoleEnum::Admin]) {
+        return Err(Error::PermissionDenied);
+    }
+
+    let documents = Documents::parse(&form.data.contents)?;
+
+    let submitter =
+        if form.dry_run {
+            Submitter::dry_run()
+        } else {
+            let author =
+                repo.find_by_name(IMPORT_BOT_USERNAME).await?.ok_or_else(
+                    || infra::Error::custom("Import bot account is missing"),
+                )?;
+
+            Submitter::Live {
+                author,
+                artist,
+                song,
+                release,
+            }
+        };
+
+    Ok(Data::new(import::run(&repo, submitter, &documents).await?))
+}
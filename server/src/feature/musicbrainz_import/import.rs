@@ -0,0 +1,385 @@
+use std::collections::HashSet;
+
+use entity::enums::{CorrectionType, EntityType};
+use itertools::Itertools;
+
+use super::mapping::{self, IdMap};
+use super::model::{ImportItem, ImportOutcome, ImportReport};
+use super::musicbrainz::{self, ArtistCredit, ArtistRef, Documents, Release};
+use super::repo;
+use crate::adapter::inbound::rest::state::{
+    ArtistService, ReleaseService, SeaOrmRepository, SongService,
+};
+use crate::domain::artist::NewArtist;
+use crate::domain::correction::{CorrectionEntity, NewCorrection};
+use crate::domain::release::NewRelease;
+use crate::domain::song::NewSong;
+use crate::domain::user::User;
+use crate::infra;
+
+/// Submits the mapped entities, or only pretends to in a dry run
+#[expect(clippy::large_enum_variant, reason = "Only one is created per import")]
+pub(super) enum Submitter {
+    DryRun {
+        /// Placeholder ids are negative so they never collide with real ones
+        next_id: i32,
+    },
+    Live {
+        author: User,
+        artist: ArtistService,
+        song: SongService,
+        release: ReleaseService,
+    },
+}
+
+impl Submitter {
+    pub const fn dry_run() -> Self {
+        Self::DryRun { next_id: -1 }
+    }
+
+    const fn is_dry_run(&self) -> bool {
+        matches!(self, Self::DryRun { .. })
+    }
+
+    const fn placeholder(&mut self) -> Option<i32> {
+        match self {
+            Self::DryRun { next_id } => {
+                let id = *next_id;
+                *next_id -= 1;
+                Some(id)
+            }
+            Self::Live { .. } => None,
+        }
+    }
+
+    fn correction<T: CorrectionEntity>(
+        author: &User,
+        data: T,
+        source: &str,
+    ) -> NewCorrection<T> {
+        NewCorrection {
+            data,
+            author: author.clone(),
+            description: format!("Imported from {source}"),
+            r#type: CorrectionType::Create,
+        }
+    }
+
+    async fn artist(
+        &mut self,
+        data: NewArtist,
+        source: &str,
+    ) -> Result<i32, String> {
+        if let Some(id) = self.placeholder() {
+            return Ok(id);
+        }
+        let Self::Live { author, artist, .. } = self else {
+            unreachable!()
+        };
+
+        artist
+            .create(Self::correction(author, data, source))
+            .await
+            .map(|x| x.entity_id)
+            .map_err(|err| err.to_string())
+    }
+
+    async fn song(
+        &mut self,
+        data: NewSong,
+        source: &str,
+    ) -> Result<i32, String> {
+        if let Some(id) = self.placeholder() {
+            return Ok(id);
+        }
+        let Self::Live { author, song, .. } = self else {
+            unreachable!()
+        };
+
+        song.create(Self::correction(author, data, source))
+            .await
+            .map(|x| x.entity_id)
+            .map_err(|err| err.to_string())
+    }
+
+    async fn release(
+        &mut self,
+        data: NewRelease,
+        source: &str,
+    ) -> Result<i32, String> {
+        if let Some(id) = self.placeholder() {
+            return Ok(id);
+        }
+        let Self::Live {
+            author, release, ..
+        } = self
+        else {
+            unreachable!()
+        };
+
+        release
+            .create(Self::correction(author, data, source))
+            .await
+            .map(|x| x.entity_id)
+            .map_err(|err| err.to_string())
+    }
+
+    fn outcome(&self, result: Result<i32, String>) -> ImportOutcome {
+        match result {
+            Ok(_) if self.is_dry_run() => ImportOutcome::WouldCreate,
+            Ok(id) => ImportOutcome::Created { id },
+            Err(reason) => ImportOutcome::Failed { reason },
+        }
+    }
+}
+
+/// Artists, then songs, then releases, so that ids are known by the time
+/// they are referenced. Entities that fail are reported and the import
+/// goes on with the rest.
+pub(super) async fn run(
+    repo: &SeaOrmRepository,
+    mut submitter: Submitter,
+    documents: &Documents,
+) -> Result<ImportReport, infra::Error> {
+    let mut report = ImportReport {
+        dry_run: submitter.is_dry_run(),
+        ..Default::default()
+    };
+
+    let artists =
+        import_artists(repo, &mut submitter, documents, &mut report).await?;
+    let labels = resolve_labels(repo, &documents.releases, &mut report).await?;
+    let mut songs =
+        resolve_songs(repo, &documents.releases, &mut report).await?;
+
+    let release_mbids = documents
+        .releases
+        .iter()
+        .map(|x| x.id.as_str())
+        .collect_vec();
+    let releases =
+        repo::find_by_mbids(repo, EntityType::Release, &release_mbids).await?;
+
+    for release in &documents.releases {
+        let source = musicbrainz::release_url(&release.id);
+
+        if let Some(&id) = releases.get(&release.id) {
+            report.releases.push(ImportItem::new(
+                &release.id,
+                &release.title,
+                ImportOutcome::Existing { id },
+            ));
+            continue;
+        }
+
+        import_songs(
+            &mut submitter,
+            release,
+            &artists,
+            &mut songs,
+            &mut report,
+        )
+        .await;
+
+        let outcome =
+            match mapping::new_release(release, &artists, &labels, &songs) {
+                Ok(data) => {
+                    let result = submitter.release(data, &source).await;
+                    submitter.outcome(result)
+                }
+                Err(err) => ImportOutcome::Skipped {
+                    reason: err.to_string(),
+                },
+            };
+        report.releases.push(ImportItem::new(
+            &release.id,
+            &release.title,
+            outcome,
+        ));
+    }
+
+    Ok(report)
+}
+
+/// Every artist of the input, with full details when the input has them
+async fn import_artists(
+    repo: &SeaOrmRepository,
+    submitter: &mut Submitter,
+    documents: &Documents,
+    report: &mut ImportReport,
+) -> Result<IdMap, infra::Error> {
+    let credited: Vec<&ArtistRef> = documents
+        .releases
+        .iter()
+        .flat_map(release_credits)
+        .map(|credit| &credit.artist)
+        .unique_by(|artist| &artist.id)
+        .filter(|artist| !documents.artists.iter().any(|x| x.id == artist.id))
+        .collect();
+
+    let mbids = documents
+        .artists
+        .iter()
+        .map(|x| x.id.as_str())
+        .chain(credited.iter().map(|x| x.id.as_str()))
+        .collect_vec();
+    let mut ids = repo::find_by_mbids(repo, EntityType::Artist, &mbids).await?;
+
+    let mapped = documents
+        .artists
+        .iter()
+        .map(|x| (x.id.as_str(), x.name.as_str(), mapping::new_artist(x)))
+        .chain(credited.iter().map(|x| {
+            (
+                x.id.as_str(),
+                x.name.as_str(),
+                mapping::new_credited_artist(x),
+            )
+        }));
+
+    for (mbid, name, data) in mapped {
+        let outcome = if let Some(&id) = ids.get(mbid) {
+            ImportOutcome::Existing { id }
+        } else {
+            match data {
+                Ok(data) => {
+                    let source = musicbrainz::artist_url(mbid);
+                    let result = submitter.artist(data, &source).await;
+                    if let Ok(id) = result {
+                        ids.insert(mbid.to_owned(), id);
+                    }
+                    submitter.outcome(result)
+                }
+                Err(err) => ImportOutcome::Skipped {
+                    reason: err.to_string(),
+                },
+            }
+        };
+
+        report.artists.push(ImportItem::new(mbid, name, outcome));
+    }
+
+    Ok(ids)
+}
+
+/// Labels are never created, catalog numbers of unknown labels are kept
+/// without label
+async fn resolve_labels(
+    repo: &SeaOrmRepository,
+    releases: &[Release],
+    report: &mut ImportReport,
+) -> Result<IdMap, infra::Error> {
+    let labels = releases
+        .iter()
+        .flat_map(|release| &release.label_info)
+        .filter_map(|info| info.label.as_ref())
+        .unique_by(|label| &label.id)
+        .collect_vec();
+
+    let mbids = labels.iter().map(|x| x.id.as_str()).collect_vec();
+    let ids = repo::find_by_mbids(repo, EntityType::Label, &mbids).await?;
+
+    for label in labels {
+        let outcome = match ids.get(&label.id) {
+            Some(&id) => ImportOutcome::Existing { id },
+            None => ImportOutcome::Skipped {
+                reason: "No label has this MusicBrainz id".to_owned(),
+            },
+        };
+        report
+            .labels
+            .push(ImportItem::new(&label.id, &label.name, outcome));
+    }
+
+    Ok(ids)
+}
+
+/// Recordings that are already songs, they are reported once here rather
+/// than with each release
+async fn resolve_songs(
+    repo: &SeaOrmRepository,
+    releases: &[Release],
+    report: &mut ImportReport,
+) -> Result<IdMap, infra::Error> {
+    let recordings = releases
+        .iter()
+        .flat_map(|release| &release.media)
+        .flat_map(|medium| &medium.tracks)
+        .map(|track| &track.recording)
+        .unique_by(|recording| &recording.id)
+        .collect_vec();
+
+    let mbids = recordings.iter().map(|x| x.id.as_str()).collect_vec();
+    let ids = repo::find_by_mbids(repo, EntityType::Song, &mbids).await?;
+
+    for recording in recordings {
+        if let Some(&id) = ids.get(&recording.id) {
+            report.songs.push(ImportItem::new(
+                &recording.id,
+                &recording.title,
+                ImportOutcome::Existing { id },
+            ));
+        }
+    }
+
+    Ok(ids)
+}
+
+/// One song per recording, recordings used by several tracks or releases
+/// are only submitted once
+async fn import_songs(
+    submitter: &mut Submitter,
+    release: &Release,
+    artists: &IdMap,
+    songs: &mut IdMap,
+    report: &mut ImportReport,
+) {
+    let mut seen: HashSet<&str> = songs.keys().map(String::as_str).collect();
+    let mut created = vec![];
+
+    for track in release.media.iter().flat_map(|x| &x.tracks) {
+        let recording = &track.recording;
+        if !seen.insert(&recording.id) {
+            continue;
+        }
+
+        let fallback_credits = if track.artist_credit.is_empty() {
+            &release.artist_credit
+        } else {
+            &track.artist_credit
+        };
+
+        let outcome =
+            match mapping::new_song(recording, fallback_credits, artists) {
+                Ok(data) => {
+                    let source = musicbrainz::recording_url(&recording.id);
+                    let result = submitter.song(data, &source).await;
+                    if let Ok(id) = result {
+                        created.push((recording.id.clone(), id));
+                    }
+                    submitter.outcome(result)
+                }
+                Err(err) => ImportOutcome::Skipped {
+                    reason: err.to_string(),
+                },
+            };
+
+        report.songs.push(ImportItem::new(
+            &recording.id,
+            &recording.title,
+            outcome,
+        ));
+    }
+
+    songs.extend(created);
+}
+
+fn release_credits(release: &Release) -> impl Iterator<Item = &ArtistCredit> {
+    let tracks = release.media.iter().flat_map(|medium| &medium.tracks);
+
+    release
+        .artist_credit
+        .iter()
+        .chain(tracks.clone().flat_map(|track| &track.artist_credit))
+        .chain(tracks.flat_map(|track| &track.recording.artist_credit))
+}
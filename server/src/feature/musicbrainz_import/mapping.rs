@@ -0,0 +1,336 @@
+//! Conversion of `MusicBrainz` entities into thcdb inputs. Ids of other
+//! entities are looked up by `MusicBrainz` id, so dependencies have to be
+//! imported first.
+
+use std::collections::HashMap;
+
+use entity::enums::ReleaseType;
+use itertools::Itertools;
+use url::Url;
+
+use super::musicbrainz::{
+    self, Artist, ArtistCredit, ArtistRef, Recording, Release,
+};
+use crate::domain::artist::{ArtistType, NewArtist};
+use crate::domain::release::{NewCatalogNumber, NewDisc, NewRelease, NewTrack};
+use crate::domain::shared::{
+    DateWithPrecision, EntityIdent, ExternalIdSource, NewExternalId,
+};
+use crate::domain::song::NewSong;
+
+/// thcdb id by `MusicBrainz` id
+pub type IdMap = HashMap<String, i32>;
+
+#[derive(Debug, snafu::Snafu)]
+pub enum MappingError {
+    #[snafu(display("Invalid name \"{name}\""))]
+    InvalidName { name: String },
+    #[snafu(display("Artist {mbid} was not imported"))]
+    MissingArtist { mbid: String },
+    #[snafu(display("Recording {mbid} was not imported"))]
+    MissingSong { mbid: String },
+    #[snafu(display("Release has no tracks"))]
+    NoTracks,
+}
+
+fn ident(name: &str) -> Result<EntityIdent, MappingError> {
+    EntityIdent::try_new(name.trim()).map_err(|_| MappingError::InvalidName {
+        name: name.to_owned(),
+    })
+}
+
+/// Malformed ids are left out rather than failing the whole import
+fn mbids(id: &str) -> Vec<NewExternalId> {
+    NewExternalId::try_new(ExternalIdSource::MusicBrainz, id)
+        .into_iter()
+        .collect()
+}
+
+fn artist_type(mb_type: Option<&str>) -> ArtistType {
+    match mb_type {
+        Some("Person" | "Character") => ArtistType::Solo,
+        Some("Group" | "Orchestra" | "Choir") => ArtistType::Multiple,
+        _ => ArtistType::Unknown,
+    }
+}
+
+/// The `MusicBrainz` page is kept as a link, later imports find the artist
+/// by its `MusicBrainz` id
+pub fn new_artist(artist: &Artist) -> Result<NewArtist, MappingError> {
+    let name = ident(&artist.name)?;
+
+    let links = std::iter::once(musicbrainz::artist_url(&artist.id))
+        .chain(
+            artist
+                .relations
+                .iter()
+                .filter_map(|x| x.url.as_ref())
+                .map(|x| x.resource.clone()),
+        )
+        .filter_map(|url| Url::parse(&url).ok())
+        .collect_vec();
+
+    let text_aliases = artist
+        .aliases
+        .iter()
+        .map(|alias| alias.name.trim())
+        .filter(|alias| *alias != artist.name.trim())
+        .unique()
+        .filter_map(|alias| EntityIdent::try_new(alias).ok())
+        .collect_vec();
+
+    let life_span = artist.life_span.as_ref();
+
+    Ok(NewArtist {
+        name,
+        artist_type: artist_type(artist.r#type.as_deref()),
+        aliases: None,
+        text_aliases: Some(text_aliases).filter(|x| !x.is_empty()),
+        start_date: life_span
+            .and_then(|x| x.begin.as_deref())
+            .and_then(DateWithPrecision::parse_partial),
+        end_date: life_span
+            .and_then(|x| x.end.as_deref())
+            .and_then(DateWithPrecision::parse_partial),
+        links: Some(links),
+        external_ids: Some(mbids(&artist.id)),
+        localized_names: None,
+        start_location: None,
+        current_location: None,
+        memberships: None,
+    })
+}
+
+/// Artists that only appear in credits come without details
+pub fn new_credited_artist(
+    artist: &ArtistRef,
+) -> Result<NewArtist, MappingError> {
+    new_artist(&Artist {
+        id: artist.id.clone(),
+        name: artist.name.clone(),
+        r#type: artist.r#type.clone(),
+        life_span: None,
+        aliases: vec![],
+        relations: vec![],
+    })
+}
+
+fn artist_ids(
+    credits: &[ArtistCredit],
+    artists: &IdMap,
+) -> Result<Vec<i32>, MappingError> {
+    credits
+        .iter()
+        .map(|credit| {
+            artists.get(&credit.artist.id).copied().ok_or_else(|| {
+                MappingError::MissingArtist {
+                    mbid: credit.artist.id.clone(),
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|ids| ids.into_iter().unique().collect())
+}
+
+/// `fallback_credits` are used when the recording has no artist credit of
+/// its own, which is the case for recordings nested in releases
+pub fn new_song(
+    recording: &Recording,
+    fallback_credits: &[ArtistCredit],
+    artists: &IdMap,
+) -> Result<NewSong, MappingError> {
+    let credits = if recording.artist_credit.is_empty() {
+        fallback_credits
+    } else {
+        &recording.artist_credit
+    };
+
+    Ok(NewSong {
+        title: ident(&recording.title)?,
+        artists: Some(artist_ids(credits, artists)?),
+        credits: None,
+        languages: None,
+        localized_titles: None,
+        external_ids: Some(mbids(&recording.id)),
+        source_tracks: None,
+    })
+}
+
+fn release_type(release: &Release) -> ReleaseType {
+    let Some(group) = &release.group else {
+        return ReleaseType::Other;
+    };
+
+    if group.secondary_types.iter().any(|x| x == "Compilation") {
+        return ReleaseType::Compilation;
+    }
+    if group.secondary_types.iter().any(|x| x == "Demo") {
+        return ReleaseType::Demo;
+    }
+
+    match group.primary_type.as_deref() {
+        Some("Album") => ReleaseType::Album,
+        Some("EP") => ReleaseType::Ep,
+        Some("Single") => ReleaseType::Single,
+        _ => ReleaseType::Other,
+    }
+}
+
+/// Catalog numbers of labels that were not found are kept without label
+pub fn new_release(
+    release: &Release,
+    artists: &IdMap,
+    labels: &IdMap,
+    songs: &IdMap,
+) -> Result<NewRelease, MappingError> {
+    let release_artists = artist_ids(&release.artist_credit, artists)?;
+
+    let discs = release
+        .media
+        .iter()
+        .filter(|medium| !medium.tracks.is_empty())
+        .collect_vec();
+    if discs.is_empty() {
+        return Err(MappingError::NoTracks);
+    }
+
+    let mut tracks = vec![];
+    for (disc_index, medium) in (0..).zip(&discs) {
+        for track in &medium.tracks {
+            let song_id =
+                songs.get(&track.recording.id).copied().ok_or_else(|| {
+                    MappingError::MissingSong {
+                        mbid: track.recording.id.clone(),
+                    }
+                })?;
+
+            let track_artists = artist_ids(&track.artist_credit, artists)?;
+
+            tracks.push(NewTrack {
+                song_id,
+                track_number: track.number.clone(),
+                display_title: (track.title != track.recording.title)
+                    .then(|| track.title.clone()),
+                duration: track.length,
+                disc_index,
+                artists: if track_artists == release_artists {
+                    vec![]
+                } else {
+                    track_artists
+                },
+            });
+        }
+    }
+
+    Ok(NewRelease {
+        title: release.title.clone(),
+        release_type: release_type(release),
+        release_date: release
+            .date
+            .as_deref()
+            .and_then(DateWithPrecision::parse_partial),
+        recording_date_start: None,
+        recording_date_end: None,
+        artists: release_artists,
+        catalog_nums: release
+            .label_info
+            .iter()
+            .filter_map(|info| {
+                Some(NewCatalogNumber {
+                    catalog_number: info.catalog_number.clone()?,
+                    label_id: info
+                        .label
+                        .as_ref()
+                        .and_then(|label| labels.get(&label.id).copied()),
+                })
+            })
+            .collect(),
+        credits: vec![],
+        discs: discs
+            .iter()
+            .map(|medium| NewDisc {
+                name: medium.title.clone().filter(|x| !x.is_empty()),
+            })
+            .collect(),
+        events: vec![],
+        localized_titles: vec![],
+        external_ids: mbids(&release.id),
+        tracks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::musicbrainz_import::musicbrainz::Documents;
+    use crate::feature::musicbrainz_import::musicbrainz::tests::{
+        ARTIST, RELEASE,
+    };
+
+    fn ids(pairs: &[(&str, i32)]) -> IdMap {
+        pairs.iter().map(|(k, v)| ((*k).to_owned(), *v)).collect()
+    }
+
+    #[test]
+    fn map_artist() {
+        let docs = Documents::parse(ARTIST.as_bytes()).unwrap();
+        let artist = new_artist(&docs.artists[0]).unwrap();
+
+        assert_eq!(artist.name.to_string(), "ZUN");
+        assert_eq!(artist.artist_type, ArtistType::Solo);
+        assert_eq!(
+            artist.links.unwrap().iter().map(Url::as_str).collect_vec(),
+            [
+                "https://musicbrainz.org/artist/a1",
+                "https://twitter.com/korindo"
+            ]
+        );
+        assert_eq!(artist.text_aliases.unwrap()[0].to_string(), "太田順也");
+        assert!(artist.start_date.is_some());
+    }
+
+    #[test]
+    fn map_release() {
+        let docs = Documents::parse(RELEASE.as_bytes()).unwrap();
+        let mb_release = &docs.releases[0];
+        let artists = ids(&[("a1", 1), ("a2", 2)]);
+        let songs = ids(&[("rec1", 10), ("rec2", 11)]);
+
+        let release =
+            new_release(mb_release, &artists, &ids(&[]), &songs).unwrap();
+
+        assert_eq!(release.release_type, ReleaseType::Album);
+        assert_eq!(release.artists, [1]);
+        assert_eq!(release.catalog_nums[0].catalog_number, "TH06");
+        assert_eq!(release.catalog_nums[0].label_id, None);
+        assert_eq!(release.discs.len(), 1);
+        assert_eq!(
+            release.tracks.iter().map(|x| x.song_id).collect_vec(),
+            [10, 11, 10]
+        );
+        assert_eq!(release.tracks[1].artists, [1, 2]);
+        assert!(release.tracks[0].display_title.is_none());
+        assert_eq!(
+            release.tracks[2].display_title.as_deref(),
+            Some("赤より紅い夢 (reprise)")
+        );
+
+        let result = new_release(mb_release, &artists, &ids(&[]), &ids(&[]));
+        assert!(matches!(result, Err(MappingError::MissingSong { .. })));
+    }
+
+    #[test]
+    fn map_song() {
+        let docs = Documents::parse(RELEASE.as_bytes()).unwrap();
+        let release = &docs.releases[0];
+        let recording = &release.media[0].tracks[0].recording;
+
+        let song =
+            new_song(recording, &release.artist_credit, &ids(&[("a1", 1)]))
+                .unwrap();
+        assert_eq!(song.artists, Some(vec![1]));
+
+        let result = new_song(recording, &release.artist_credit, &ids(&[]));
+        assert!(matches!(result, Err(MappingError::MissingArtist { .. })));
+    }
+}
@@ -0,0 +1,34 @@
+mod http;
+mod import;
+mod mapping;
+mod model;
+mod musicbrainz;
+mod repo;
+
+use axum::http::StatusCode;
+pub use http::router;
+use macros::{ApiError, IntoErrorSchema};
+
+use crate::infra;
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(display("Only admins can import"))]
+    #[api_error(
+        status_code = StatusCode::FORBIDDEN,
+    )]
+    PermissionDenied,
+    #[snafu(transparent)]
+    Parse { source: musicbrainz::ParseError },
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+}
+
+impl<E> From<E> for Error
+where
+    E: Into<infra::Error>,
+{
+    default fn from(err: E) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
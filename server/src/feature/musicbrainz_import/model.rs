@@ -0,0 +1,49 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// What was done, or would be done in a dry run, for each `MusicBrainz`
+/// entity of the input
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub artists: Vec<ImportItem>,
+    pub labels: Vec<ImportItem>,
+    pub songs: Vec<ImportItem>,
+    pub releases: Vec<ImportItem>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ImportItem {
+    pub musicbrainz_id: String,
+    pub name: String,
+    pub outcome: ImportOutcome,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ImportOutcome {
+    /// Already in the database, nothing was submitted
+    Existing { id: i32 },
+    /// Submitted as a correction
+    Created { id: i32 },
+    /// Dry run only
+    WouldCreate,
+    /// Could not be mapped, or depends on an entity that was skipped
+    Skipped { reason: String },
+    /// The submission was rejected
+    Failed { reason: String },
+}
+
+impl ImportItem {
+    pub fn new(
+        musicbrainz_id: &str,
+        name: &str,
+        outcome: ImportOutcome,
+    ) -> Self {
+        Self {
+            musicbrainz_id: musicbrainz_id.to_owned(),
+            name: name.to_owned(),
+            outcome,
+        }
+    }
+}
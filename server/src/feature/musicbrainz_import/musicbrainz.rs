@@ -0,0 +1,310 @@
+//! The subset of the `MusicBrainz` JSON format read by the importer. The
+//! shape is the one of the web service, with
+//! `inc=artist-credits+labels+recordings+release-groups+url-rels`, which is
+//! also what the JSON dumps contain, one entity per line.
+
+use axum::http::StatusCode;
+use macros::{ApiError, IntoErrorSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+pub const SITE: &str = "https://musicbrainz.org";
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[snafu(display("Invalid MusicBrainz JSON at line {line}: {reason}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ParseError {
+    pub line: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Documents {
+    pub artists: Vec<Artist>,
+    pub releases: Vec<Release>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Artist {
+    pub id: String,
+    pub name: String,
+    pub r#type: Option<String>,
+    pub life_span: Option<LifeSpan>,
+    #[serde(default)]
+    pub aliases: Vec<Alias>,
+    #[serde(default)]
+    pub relations: Vec<Relation>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LifeSpan {
+    pub begin: Option<String>,
+    pub end: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Alias {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Relation {
+    pub url: Option<UrlResource>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UrlResource {
+    pub resource: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Release {
+    pub id: String,
+    pub title: String,
+    pub date: Option<String>,
+    #[serde(default)]
+    pub artist_credit: Vec<ArtistCredit>,
+    #[serde(rename = "release-group")]
+    pub group: Option<ReleaseGroup>,
+    #[serde(default)]
+    pub label_info: Vec<LabelInfo>,
+    #[serde(default)]
+    pub media: Vec<Medium>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ArtistCredit {
+    pub artist: ArtistRef,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ArtistRef {
+    pub id: String,
+    pub name: String,
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ReleaseGroup {
+    pub primary_type: Option<String>,
+    #[serde(default)]
+    pub secondary_types: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LabelInfo {
+    pub catalog_number: Option<String>,
+    pub label: Option<LabelRef>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LabelRef {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Medium {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub tracks: Vec<Track>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Track {
+    pub number: Option<String>,
+    pub title: String,
+    /// Milliseconds
+    pub length: Option<i32>,
+    #[serde(default)]
+    pub artist_credit: Vec<ArtistCredit>,
+    pub recording: Recording,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct Recording {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub artist_credit: Vec<ArtistCredit>,
+}
+
+pub fn artist_url(mbid: &str) -> String {
+    format!("{SITE}/artist/{mbid}")
+}
+
+pub fn recording_url(mbid: &str) -> String {
+    format!("{SITE}/recording/{mbid}")
+}
+
+pub fn release_url(mbid: &str) -> String {
+    format!("{SITE}/release/{mbid}")
+}
+
+impl Documents {
+    /// Accepts a single entity, an array of entities, a search result such
+    /// as `{"releases": [...]}`, or one of those per line as in the dumps
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let mut documents = Self::default();
+
+        if let Ok(value) = serde_json::from_slice::<Value>(bytes) {
+            documents.push(value, 1)?;
+            return Ok(documents);
+        }
+
+        let text = std::str::from_utf8(bytes).map_err(|err| ParseError {
+            line: 1,
+            reason: err.to_string(),
+        })?;
+
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let value =
+                serde_json::from_str(line).map_err(|err| ParseError {
+                    line: idx + 1,
+                    reason: err.to_string(),
+                })?;
+            documents.push(value, idx + 1)?;
+        }
+
+        Ok(documents)
+    }
+
+    fn push(&mut self, value: Value, line: usize) -> Result<(), ParseError> {
+        let error = |reason: String| ParseError { line, reason };
+
+        match value {
+            Value::Array(values) => {
+                for value in values {
+                    self.push(value, line)?;
+                }
+            }
+            Value::Object(mut object) => {
+                if let Some(list) = object
+                    .remove("releases")
+                    .or_else(|| object.remove("artists"))
+                {
+                    return self.push(list, line);
+                }
+
+                // Only releases have media, only artists have a sort name
+                if object.contains_key("media") {
+                    let release = serde_json::from_value(Value::Object(object))
+                        .map_err(|err| error(err.to_string()))?;
+                    self.releases.push(release);
+                } else if object.contains_key("sort-name") {
+                    let artist = serde_json::from_value(Value::Object(object))
+                        .map_err(|err| error(err.to_string()))?;
+                    self.artists.push(artist);
+                } else {
+                    return Err(error(
+                        "expected a MusicBrainz release or artist".to_owned(),
+                    ));
+                }
+            }
+            _ => {
+                return Err(error("expected a JSON object".to_owned()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    pub const RELEASE: &str = r#"{
+        "id": "r1",
+        "title": "東方紅魔郷",
+        "date": "2002-08-11",
+        "artist-credit": [
+            {"name": "ZUN", "joinphrase": "", "artist": {"id": "a1", "name": "ZUN", "sort-name": "ZUN", "type": "Person"}}
+        ],
+        "release-group": {"primary-type": "Album", "secondary-types": ["Soundtrack"]},
+        "label-info": [
+            {"catalog-number": "TH06", "label": {"id": "l1", "name": "上海アリス幻樂団"}}
+        ],
+        "media": [{
+            "position": 1,
+            "title": null,
+            "tracks": [
+                {
+                    "number": "1",
+                    "title": "赤より紅い夢",
+                    "length": 120000,
+                    "recording": {"id": "rec1", "title": "赤より紅い夢"}
+                },
+                {
+                    "number": "2",
+                    "title": "ほおずきみたいに紅い魂",
+                    "length": null,
+                    "artist-credit": [
+                        {"name": "ZUN", "artist": {"id": "a1", "name": "ZUN"}},
+                        {"name": "Guest", "artist": {"id": "a2", "name": "Guest", "type": "Group"}}
+                    ],
+                    "recording": {"id": "rec2", "title": "ほおずきみたいに紅い魂"}
+                },
+                {
+                    "number": "3",
+                    "title": "赤より紅い夢 (reprise)",
+                    "recording": {"id": "rec1", "title": "赤より紅い夢"}
+                }
+            ]
+        }]
+    }"#;
+
+    pub const ARTIST: &str = r#"{
+        "id": "a1",
+        "name": "ZUN",
+        "sort-name": "ZUN",
+        "type": "Person",
+        "life-span": {"begin": "1977-03-18", "end": null},
+        "aliases": [{"name": "太田順也"}],
+        "relations": [{"url": {"resource": "https://twitter.com/korindo"}}]
+    }"#;
+
+    #[test]
+    fn parse_shapes() {
+        let docs = Documents::parse(RELEASE.as_bytes()).unwrap();
+        assert_eq!(docs.releases.len(), 1);
+        assert_eq!(docs.releases[0].media[0].tracks.len(), 3);
+
+        let search = format!(r#"{{"count": 1, "releases": [{RELEASE}]}}"#);
+        let docs = Documents::parse(search.as_bytes()).unwrap();
+        assert_eq!(docs.releases.len(), 1);
+
+        let lines = format!(
+            "{}\n\n{}\n",
+            RELEASE.replace('\n', ""),
+            ARTIST.replace('\n', "")
+        );
+        let docs = Documents::parse(lines.as_bytes()).unwrap();
+        assert_eq!(docs.releases.len(), 1);
+        assert_eq!(docs.artists.len(), 1);
+        assert_eq!(docs.artists[0].aliases[0].name, "太田順也");
+    }
+
+    #[test]
+    fn reject_unknown_documents() {
+        let err = Documents::parse(b"{\"id\": \"x\"}").unwrap_err();
+        assert_eq!(err.line, 1);
+
+        let lines = format!("{}\n{{", RELEASE.replace('\n', ""));
+        let err = Documents::parse(lines.as_bytes()).unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+}
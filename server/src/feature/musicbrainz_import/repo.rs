@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use entity::enums::EntityType;
+use entity::external_id;
+use sea_orm::{ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+
+use super::mapping::IdMap;
+use crate::domain::Connection;
+use crate::domain::shared::{ExternalIdSource, NewExternalId};
+
+/// Entities of `entity_type` that already have one of the `MusicBrainz` ids,
+/// keyed by the id as given
+pub(super) async fn find_by_mbids<R>(
+    repo: &R,
+    entity_type: EntityType,
+    mbids: &[&str],
+) -> Result<IdMap, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    // Stored ids are normalized, the input may not be
+    let by_value: HashMap<String, &str> = mbids
+        .iter()
+        .filter_map(|mbid| {
+            let id =
+                NewExternalId::try_new(ExternalIdSource::MusicBrainz, mbid)
+                    .ok()?;
+            Some((id.value, *mbid))
+        })
+        .collect();
+
+    if by_value.is_empty() {
+        return Ok(IdMap::new());
+    }
+
+    Ok(external_id::Entity::find()
+        .filter(external_id::Column::EntityType.eq(entity_type))
+        .filter(external_id::Column::Source.eq(ExternalIdSource::MusicBrainz))
+        .filter(external_id::Column::Value.is_in(by_value.keys()))
+        .all(repo.conn())
+        .await?
+        .into_iter()
+        .filter_map(|model| {
+            Some(((*by_value.get(&model.value)?).to_owned(), model.entity_id))
+        })
+        .collect())
+}
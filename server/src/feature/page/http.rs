@@ -0,0 +1,108 @@
+use axum::Router;
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use maud::Markup;
+
+use super::url::{PageKind, SITE_URL};
+use super::{layout, pages, repo, sitemap};
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::feature::artist::find::CommonFilter;
+use crate::feature::release::find::repo::FindReleaseFilter;
+use crate::feature::{artist, event, release, song};
+use crate::infra;
+
+pub fn router() -> Router<ArcAppState> {
+    Router::new()
+        .route("/page/artist/{id}", get(artist_page))
+        .route("/page/release/{id}", get(release_page))
+        .route("/page/song/{id}", get(song_page))
+        .route("/page/event/{id}", get(event_page))
+        .route("/sitemap.xml", get(sitemap_index))
+        .route("/sitemap/{kind}/{page}", get(sitemap_page))
+}
+
+fn render<T>(entity: Option<T>, page: impl FnOnce(&T) -> Markup) -> Response {
+    entity.map_or_else(
+        || (StatusCode::NOT_FOUND, layout::not_found()).into_response(),
+        |entity| page(&entity).into_response(),
+    )
+}
+
+fn xml(markup: Markup) -> Response {
+    (
+        [(header::CONTENT_TYPE, "application/xml")],
+        markup.into_string(),
+    )
+        .into_response()
+}
+
+async fn artist_page(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Response, infra::Error> {
+    let artist =
+        artist::find::repo::find_one(&repo, id, CommonFilter::default())
+            .await?;
+
+    Ok(render(artist, |x| pages::artist(x, &SITE_URL)))
+}
+
+async fn release_page(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Response, infra::Error> {
+    let release =
+        release::find::repo::find_one(&repo, FindReleaseFilter::Id(id)).await?;
+
+    Ok(render(release, |x| pages::release(x, &SITE_URL)))
+}
+
+async fn song_page(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Response, infra::Error> {
+    let song = song::find::repo::find_by_id(&repo, id).await?;
+
+    Ok(render(song, |x| pages::song(x, &SITE_URL)))
+}
+
+async fn event_page(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Response, infra::Error> {
+    let event = event::find::repo::find_by_id(&repo, id).await?;
+
+    Ok(render(event, |x| pages::event(x, &SITE_URL)))
+}
+
+async fn sitemap_index(
+    State(repo): State<state::SeaOrmRepository>,
+) -> Result<Response, infra::Error> {
+    let mut sitemaps = vec![];
+    for kind in PageKind::ALL {
+        let count = repo::count(&repo, kind).await?;
+        sitemaps.extend(
+            (0..count.div_ceil(repo::SITEMAP_PAGE_SIZE))
+                .map(|page| SITE_URL.sitemap(kind, page)),
+        );
+    }
+
+    Ok(xml(sitemap::index(sitemaps)))
+}
+
+async fn sitemap_page(
+    State(repo): State<state::SeaOrmRepository>,
+    Path((kind, page)): Path<(PageKind, u64)>,
+) -> Result<Response, infra::Error> {
+    let ids = repo::find_ids(&repo, kind, page).await?;
+
+    if ids.is_empty() {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    }
+
+    Ok(xml(sitemap::urlset(
+        ids.into_iter().map(|id| SITE_URL.page(kind, id)),
+    )))
+}
@@ -0,0 +1,255 @@
+//! schema.org descriptions of entities. Unknown values are left out
+//! rather than set to null.
+
+use entity::enums::ReleaseType;
+use itertools::Itertools;
+use serde_json::{Value, json};
+
+use super::url::{PageKind, SiteUrl};
+use crate::domain::artist::Artist;
+use crate::domain::event::Event;
+use crate::domain::release::Release;
+use crate::domain::shared::{DateWithPrecision, ExternalId, Link, Location};
+use crate::domain::song::Song;
+
+const CONTEXT: &str = "https://schema.org";
+
+pub fn music_group(artist: &Artist, site: &SiteUrl) -> Value {
+    let alternate_names = artist
+        .localized_names
+        .iter()
+        .map(|x| x.name.as_str())
+        .chain(artist.text_aliases.iter().flatten().map(String::as_str))
+        .collect_vec();
+
+    compact(json!({
+        "@context": CONTEXT,
+        "@type": "MusicGroup",
+        "url": site.page(PageKind::Artist, artist.id),
+        "name": artist.name,
+        "alternateName": alternate_names,
+        "image": artist.profile_image_url.as_deref().and_then(|x| site.image(x)),
+        "foundingDate": artist.start_date.map(DateWithPrecision::to_iso_string),
+        "dissolutionDate": artist.end_date.map(DateWithPrecision::to_iso_string),
+        "sameAs": same_as(&artist.links, &artist.external_ids),
+    }))
+}
+
+pub fn music_album(release: &Release, site: &SiteUrl) -> Value {
+    let tracks = release
+        .tracks
+        .iter()
+        .enumerate()
+        .map(|(idx, track)| {
+            json!({
+                "@type": "MusicRecording",
+                "url": site.page(PageKind::Song, track.song.id),
+                "name": track.display_title.as_ref().unwrap_or(&track.song.title),
+                "position": idx + 1,
+                "duration": track.duration.map(iso_duration),
+            })
+        })
+        .collect_vec();
+
+    let labels = release
+        .catalog_nums
+        .iter()
+        .filter_map(|x| x.label.as_ref())
+        .unique_by(|label| label.id)
+        .map(|label| json!({ "@type": "Organization", "name": label.name }))
+        .collect_vec();
+
+    compact(json!({
+        "@context": CONTEXT,
+        "@type": "MusicAlbum",
+        "url": site.page(PageKind::Release, release.id),
+        "name": release.title,
+        "alternateName": release
+            .localized_titles
+            .iter()
+            .map(|x| &x.title)
+            .collect_vec(),
+        "image": release.cover_art_url.as_deref().and_then(|x| site.image(x)),
+        "albumReleaseType": album_release_type(release.release_type),
+        "albumProductionType": album_production_type(release.release_type),
+        "datePublished": release.release_date.map(DateWithPrecision::to_iso_string),
+        "byArtist": release
+            .artists
+            .iter()
+            .map(|x| music_group_ref(x.id, &x.name, site))
+            .collect_vec(),
+        "recordLabel": labels,
+        "numTracks": release.tracks.len(),
+        "track": tracks,
+        "sameAs": same_as(&[], &release.external_ids),
+    }))
+}
+
+pub fn music_recording(song: &Song, site: &SiteUrl) -> Value {
+    compact(json!({
+        "@context": CONTEXT,
+        "@type": "MusicRecording",
+        "url": site.page(PageKind::Song, song.id),
+        "name": song.title,
+        "alternateName": song
+            .localized_titles
+            .iter()
+            .map(|x| &x.title)
+            .collect_vec(),
+        "byArtist": song
+            .artists
+            .iter()
+            .map(|x| music_group_ref(x.id, &x.name, site))
+            .collect_vec(),
+        "inAlbum": song
+            .releases
+            .iter()
+            .map(|release| json!({
+                "@type": "MusicAlbum",
+                "url": site.page(PageKind::Release, release.id),
+                "name": release.title,
+            }))
+            .collect_vec(),
+        "inLanguage": song.languages.iter().map(|x| &x.code).collect_vec(),
+        "sameAs": same_as(&[], &song.external_ids),
+    }))
+}
+
+pub fn event(event: &Event, site: &SiteUrl) -> Value {
+    compact(json!({
+        "@context": CONTEXT,
+        "@type": "Event",
+        "url": site.page(PageKind::Event, event.id),
+        "name": event.name,
+        "alternateName": event
+            .alternative_names
+            .iter()
+            .map(|x| &x.name)
+            .collect_vec(),
+        "description": event.short_description,
+        "startDate": event.start_date.map(DateWithPrecision::to_iso_string),
+        "endDate": event.end_date.map(DateWithPrecision::to_iso_string),
+        "location": place(&event.location),
+        "superEvent": event.series.as_ref().map(|series| json!({
+            "@type": "EventSeries",
+            "name": series.name,
+        })),
+        "performer": event
+            .circles
+            .iter()
+            .map(|x| music_group_ref(x.artist.id, &x.artist.name, site))
+            .collect_vec(),
+        "sameAs": same_as(&event.links, &event.external_ids),
+    }))
+}
+
+/// <https://schema.org/MusicAlbumReleaseType>
+const fn album_release_type(release_type: ReleaseType) -> Option<&'static str> {
+    match release_type {
+        ReleaseType::Album => Some("https://schema.org/AlbumRelease"),
+        ReleaseType::Ep => Some("https://schema.org/EPRelease"),
+        ReleaseType::Single => Some("https://schema.org/SingleRelease"),
+        _ => None,
+    }
+}
+
+/// <https://schema.org/MusicAlbumProductionType>
+const fn album_production_type(
+    release_type: ReleaseType,
+) -> Option<&'static str> {
+    match release_type {
+        ReleaseType::Compilation => Some("https://schema.org/CompilationAlbum"),
+        ReleaseType::Demo => Some("https://schema.org/DemoAlbum"),
+        _ => None,
+    }
+}
+
+fn music_group_ref(id: i32, name: &str, site: &SiteUrl) -> Value {
+    json!({
+        "@type": "MusicGroup",
+        "url": site.page(PageKind::Artist, id),
+        "name": name,
+    })
+}
+
+fn place(location: &Location) -> Option<Value> {
+    (!location.is_empty()).then(|| {
+        json!({
+            "@type": "Place",
+            "address": {
+                "@type": "PostalAddress",
+                "addressCountry": location.country,
+                "addressRegion": location.province,
+                "addressLocality": location.city,
+            },
+        })
+    })
+}
+
+fn same_as(links: &[Link], external_ids: &[ExternalId]) -> Vec<String> {
+    links
+        .iter()
+        .map(|link| link.url.clone())
+        .chain(external_ids.iter().filter_map(|id| id.url.clone()))
+        .unique()
+        .collect()
+}
+
+/// ISO 8601 duration of a track length in milliseconds, e.g. `PT3M25S`
+fn iso_duration(ms: i32) -> String {
+    let secs = ms / 1000;
+    format!("PT{}M{}S", secs / 60, secs % 60)
+}
+
+/// Remove nulls, empty strings and empty arrays
+fn compact(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, compact(value)))
+                .filter(|(_, value)| match value {
+                    Value::Null => false,
+                    Value::String(x) => !x.is_empty(),
+                    Value::Array(x) => !x.is_empty(),
+                    _ => true,
+                })
+                .collect(),
+        ),
+        Value::Array(values) => {
+            Value::Array(values.into_iter().map(compact).collect())
+        }
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_track_duration() {
+        assert_eq!(iso_duration(205_999), "PT3M25S");
+        assert_eq!(iso_duration(59_000), "PT0M59S");
+    }
+
+    #[test]
+    fn leave_out_unknown_values() {
+        let value = compact(json!({
+            "name": "x",
+            "image": null,
+            "description": "",
+            "sameAs": [],
+            "track": [{ "name": "y", "duration": null }],
+            "address": { "addressCountry": null },
+        }));
+
+        assert_eq!(
+            value,
+            json!({
+                "name": "x",
+                "track": [{ "name": "y" }],
+                "address": {},
+            })
+        );
+    }
+}
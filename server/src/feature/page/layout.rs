@@ -0,0 +1,104 @@
+use maud::{DOCTYPE, Markup, PreEscaped, html};
+use serde_json::Value;
+
+const SITE_NAME: &str = "Touhou Cloud Db";
+
+pub struct PageMeta {
+    pub title: String,
+    pub description: String,
+    /// Canonical url of the page
+    pub url: String,
+    pub image: Option<String>,
+    /// <https://ogp.me/#types>
+    pub og_type: &'static str,
+    pub json_ld: Value,
+}
+
+pub fn page(meta: &PageMeta, body: &Markup) -> Markup {
+    let twitter_card = if meta.image.is_some() {
+        "summary_large_image"
+    } else {
+        "summary"
+    };
+
+    html! {
+        (DOCTYPE)
+        html {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1";
+                title { (meta.title) " - " (SITE_NAME) }
+                meta name="description" content=(meta.description);
+                link rel="canonical" href=(meta.url);
+
+                meta property="og:site_name" content=(SITE_NAME);
+                meta property="og:type" content=(meta.og_type);
+                meta property="og:title" content=(meta.title);
+                meta property="og:description" content=(meta.description);
+                meta property="og:url" content=(meta.url);
+                @if let Some(image) = &meta.image {
+                    meta property="og:image" content=(image);
+                }
+
+                meta name="twitter:card" content=(twitter_card);
+                meta name="twitter:title" content=(meta.title);
+                meta name="twitter:description" content=(meta.description);
+                @if let Some(image) = &meta.image {
+                    meta name="twitter:image" content=(image);
+                }
+
+                script type="application/ld+json" {
+                    (json_ld_script(&meta.json_ld))
+                }
+            }
+            body { (body) }
+        }
+    }
+}
+
+pub fn not_found() -> Markup {
+    html! {
+        (DOCTYPE)
+        html {
+            head {
+                meta charset="utf-8";
+                title { "Not found - " (SITE_NAME) }
+            }
+            body {
+                h1 { "Not found" }
+                p { a href="/" { "Back to home" } }
+            }
+        }
+    }
+}
+
+/// Script contents are not escaped by HTML, `<` is escaped in JSON instead
+/// so that a value can't close the script element
+fn json_ld_script(value: &Value) -> PreEscaped<String> {
+    PreEscaped(value.to_string().replace('<', "\\u003c"))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn escape_json_ld_script() {
+        let meta = PageMeta {
+            title: "</script><b>".to_owned(),
+            description: String::new(),
+            url: "https://example.com/page/song/1".to_owned(),
+            image: None,
+            og_type: "music.song",
+            json_ld: json!({ "name": "</script><b>" }),
+        };
+
+        let html = page(&meta, &html! {}).into_string();
+
+        assert!(!html.contains("</script><b>"));
+        assert!(html.contains(r#"{"name":"\u003c/script>\u003cb>"}"#));
+        assert!(html.contains(r#"content="summary""#));
+    }
+}
@@ -0,0 +1,13 @@
+//! Server rendered pages of entities, for link previews and search
+//! engines. Each page describes its entity with schema.org JSON-LD and
+//! `OpenGraph` tags, browsing the site is left to the web client.
+
+mod http;
+mod json_ld;
+mod layout;
+mod pages;
+mod repo;
+mod sitemap;
+mod url;
+
+pub use http::router;
@@ -0,0 +1,223 @@
+use itertools::Itertools;
+use maud::{Markup, html};
+use sea_orm::ActiveEnum;
+
+use super::json_ld;
+use super::layout::{self, PageMeta};
+use super::url::{PageKind, SiteUrl};
+use crate::domain::artist::{Artist, ArtistType};
+use crate::domain::event::Event;
+use crate::domain::release::Release;
+use crate::domain::shared::{DateWithPrecision, Link};
+use crate::domain::song::Song;
+
+pub fn artist(artist: &Artist, site: &SiteUrl) -> Markup {
+    let dates = date_range(artist.start_date, artist.end_date);
+    let kind = match artist.artist_type {
+        ArtistType::Solo => "Solo artist",
+        ArtistType::Multiple => "Group",
+        ArtistType::Unknown => "Artist",
+    };
+    let description = [Some(kind.to_owned()), dates]
+        .into_iter()
+        .flatten()
+        .join(", ");
+
+    let meta = PageMeta {
+        title: artist.name.clone(),
+        description,
+        url: site.page(PageKind::Artist, artist.id),
+        image: artist
+            .profile_image_url
+            .as_deref()
+            .and_then(|x| site.image(x)),
+        og_type: "profile",
+        json_ld: json_ld::music_group(artist, site),
+    };
+
+    layout::page(
+        &meta,
+        &html! {
+            h1 { (artist.name) }
+            p { (meta.description) }
+            @if let Some(aliases) = &artist.text_aliases {
+                p { "Also known as " (aliases.iter().join(", ")) }
+            }
+            (links(&artist.links))
+        },
+    )
+}
+
+pub fn release(release: &Release, site: &SiteUrl) -> Markup {
+    let artists = release.artists.iter().map(|x| &x.name).join(", ");
+    let description = [
+        Some(release.release_type.to_value()),
+        Some(artists)
+            .filter(|x| !x.is_empty())
+            .map(|x| format!("by {x}")),
+        release.release_date.map(DateWithPrecision::to_iso_string),
+        Some(format!("{} tracks", release.tracks.len())),
+    ]
+    .into_iter()
+    .flatten()
+    .join(", ");
+
+    let meta = PageMeta {
+        title: release.title.clone(),
+        description,
+        url: site.page(PageKind::Release, release.id),
+        image: release.cover_art_url.as_deref().and_then(|x| site.image(x)),
+        og_type: "music.album",
+        json_ld: json_ld::music_album(release, site),
+    };
+
+    layout::page(
+        &meta,
+        &html! {
+            h1 { (release.title) }
+            p { (meta.description) }
+            p {
+                @for (idx, artist) in release.artists.iter().enumerate() {
+                    @if idx > 0 { ", " }
+                    a href=(site.page(PageKind::Artist, artist.id)) { (artist.name) }
+                }
+            }
+            ol {
+                @for track in &release.tracks {
+                    li {
+                        a href=(site.page(PageKind::Song, track.song.id)) {
+                            (track.display_title.as_ref().unwrap_or(&track.song.title))
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+pub fn song(song: &Song, site: &SiteUrl) -> Markup {
+    let artists = song.artists.iter().map(|x| &x.name).join(", ");
+    let description = [
+        Some(artists)
+            .filter(|x| !x.is_empty())
+            .map(|x| format!("by {x}")),
+        song.releases.first().map(|x| format!("from {}", x.title)),
+    ]
+    .into_iter()
+    .flatten()
+    .join(", ");
+
+    let meta = PageMeta {
+        title: song.title.clone(),
+        description,
+        url: site.page(PageKind::Song, song.id),
+        image: song
+            .releases
+            .iter()
+            .find_map(|x| x.cover_art_url.as_deref())
+            .and_then(|x| site.image(x)),
+        og_type: "music.song",
+        json_ld: json_ld::music_recording(song, site),
+    };
+
+    layout::page(
+        &meta,
+        &html! {
+            h1 { (song.title) }
+            p {
+                @for (idx, artist) in song.artists.iter().enumerate() {
+                    @if idx > 0 { ", " }
+                    a href=(site.page(PageKind::Artist, artist.id)) { (artist.name) }
+                }
+            }
+            @if !song.releases.is_empty() {
+                h2 { "Releases" }
+                ul {
+                    @for release in &song.releases {
+                        li {
+                            a href=(site.page(PageKind::Release, release.id)) {
+                                (release.title)
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+pub fn event(event: &Event, site: &SiteUrl) -> Markup {
+    let description = if event.short_description.is_empty() {
+        date_range(event.start_date, event.end_date).unwrap_or_default()
+    } else {
+        event.short_description.clone()
+    };
+
+    let meta = PageMeta {
+        title: event.name.clone(),
+        description,
+        url: site.page(PageKind::Event, event.id),
+        image: None,
+        og_type: "website",
+        json_ld: json_ld::event(event, site),
+    };
+
+    layout::page(
+        &meta,
+        &html! {
+            h1 { (event.name) }
+            p { (meta.description) }
+            @if !event.circles.is_empty() {
+                h2 { "Circles" }
+                ul {
+                    @for circle in &event.circles {
+                        li {
+                            a href=(site.page(PageKind::Artist, circle.artist.id)) {
+                                (circle.artist.name)
+                            }
+                        }
+                    }
+                }
+            }
+            @if !event.releases.is_empty() {
+                h2 { "Releases" }
+                ul {
+                    @for release in &event.releases {
+                        li {
+                            a href=(site.page(PageKind::Release, release.id)) {
+                                (release.title)
+                            }
+                        }
+                    }
+                }
+            }
+            (links(&event.links))
+        },
+    )
+}
+
+fn date_range(
+    start: Option<DateWithPrecision>,
+    end: Option<DateWithPrecision>,
+) -> Option<String> {
+    match (start, end) {
+        (None, None) => None,
+        (start, end) => Some(format!(
+            "{} – {}",
+            start.map_or_else(String::new, DateWithPrecision::to_iso_string),
+            end.map_or_else(String::new, DateWithPrecision::to_iso_string),
+        )),
+    }
+}
+
+fn links(links: &[Link]) -> Markup {
+    html! {
+        @if !links.is_empty() {
+            ul {
+                @for link in links {
+                    li { a href=(link.url) rel="nofollow" { (link.url) } }
+                }
+            }
+        }
+    }
+}
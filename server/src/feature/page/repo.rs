@@ -0,0 +1,70 @@
+use entity::{artist, event, release, song};
+use sea_orm::{
+    ConnectionTrait, DbErr, EntityTrait, PaginatorTrait, QueryOrder,
+    QuerySelect,
+};
+use sea_query::{Alias, Expr};
+
+use super::url::PageKind;
+use crate::domain::Connection;
+
+/// Largest number of urls allowed in a sitemap
+pub const SITEMAP_PAGE_SIZE: u64 = 50_000;
+
+pub async fn count<R>(repo: &R, kind: PageKind) -> Result<u64, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+    match kind {
+        PageKind::Artist => artist::Entity::find().count(db).await,
+        PageKind::Release => release::Entity::find().count(db).await,
+        PageKind::Song => song::Entity::find().count(db).await,
+        PageKind::Event => event::Entity::find().count(db).await,
+    }
+}
+
+/// Ids of one sitemap page, in ascending order. Pages too far out to be
+/// queried are empty, like any other page past the end
+pub async fn find_ids<R>(
+    repo: &R,
+    kind: PageKind,
+    page: u64,
+) -> Result<Vec<i32>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    // Postgres offsets are signed
+    let Some(offset) = page
+        .checked_mul(SITEMAP_PAGE_SIZE)
+        .filter(|offset| i64::try_from(*offset).is_ok())
+    else {
+        return Ok(vec![]);
+    };
+
+    let db = repo.conn();
+    match kind {
+        PageKind::Artist => find_ids_of(artist::Entity, offset, db).await,
+        PageKind::Release => find_ids_of(release::Entity, offset, db).await,
+        PageKind::Song => find_ids_of(song::Entity, offset, db).await,
+        PageKind::Event => find_ids_of(event::Entity, offset, db).await,
+    }
+}
+
+async fn find_ids_of<E: EntityTrait>(
+    _: E,
+    offset: u64,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<i32>, DbErr> {
+    E::find()
+        .select_only()
+        .expr(Expr::col(Alias::new("id")))
+        .order_by_asc(Expr::col(Alias::new("id")))
+        .offset(offset)
+        .limit(SITEMAP_PAGE_SIZE)
+        .into_tuple()
+        .all(db)
+        .await
+}
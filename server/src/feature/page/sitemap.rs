@@ -0,0 +1,52 @@
+//! <https://www.sitemaps.org/protocol.html>
+
+use maud::{Markup, PreEscaped, html};
+
+const XML_DECLARATION: PreEscaped<&str> =
+    PreEscaped(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+
+const XMLNS: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+pub fn index(sitemaps: impl IntoIterator<Item = String>) -> Markup {
+    html! {
+        (XML_DECLARATION)
+        sitemapindex xmlns=(XMLNS) {
+            @for url in sitemaps {
+                sitemap { loc { (url) } }
+            }
+        }
+    }
+}
+
+pub fn urlset(urls: impl IntoIterator<Item = String>) -> Markup {
+    html! {
+        (XML_DECLARATION)
+        urlset xmlns=(XMLNS) {
+            @for url in urls {
+                url { loc { (url) } }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_urlset() {
+        let xml =
+            urlset(["https://example.com/page/song/1?a=1&b=2".to_owned()])
+                .into_string();
+
+        assert_eq!(
+            xml,
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+                r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#,
+                "<url><loc>https://example.com/page/song/1?a=1&amp;b=2</loc></url>",
+                "</urlset>",
+            )
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+use url::Url;
+
+use crate::infra::singleton::APP_CONFIG;
+
+pub static SITE_URL: LazyLock<SiteUrl> =
+    LazyLock::new(|| SiteUrl::new(APP_CONFIG.app.public_url.clone()));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PageKind {
+    Artist,
+    Release,
+    Song,
+    Event,
+}
+
+impl PageKind {
+    pub const ALL: [Self; 4] =
+        [Self::Artist, Self::Release, Self::Song, Self::Event];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Artist => "artist",
+            Self::Release => "release",
+            Self::Song => "song",
+            Self::Event => "event",
+        }
+    }
+}
+
+/// Absolute links to the site
+pub struct SiteUrl {
+    base: Url,
+}
+
+impl SiteUrl {
+    pub fn new(mut base: Url) -> Self {
+        // Otherwise the last segment would be replaced when joining
+        if !base.path().ends_with('/') {
+            base.set_path(&format!("{}/", base.path()));
+        }
+
+        Self { base }
+    }
+
+    fn join(&self, path: &str) -> String {
+        self.base
+            .join(path)
+            .expect("Relative paths are always valid")
+            .into()
+    }
+
+    pub fn page(&self, kind: PageKind, id: i32) -> String {
+        self.join(&format!("page/{}/{id}", kind.as_str()))
+    }
+
+    pub fn sitemap(&self, kind: PageKind, page: u64) -> String {
+        self.join(&format!("sitemap/{}/{page}", kind.as_str()))
+    }
+
+    /// Image urls of the API are relative to the image directory
+    pub fn image(&self, url: &str) -> Option<String> {
+        self.base
+            .join("public/image/")
+            .and_then(|base| base.join(url))
+            .ok()
+            .map(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn join_under_base_path() {
+        let site =
+            SiteUrl::new(Url::parse("https://example.com/thcdb").unwrap());
+
+        assert_eq!(
+            site.page(PageKind::Release, 3),
+            "https://example.com/thcdb/page/release/3"
+        );
+        assert_eq!(
+            site.image("ab/cd.png").as_deref(),
+            Some("https://example.com/thcdb/public/image/ab/cd.png")
+        );
+        assert_eq!(
+            site.sitemap(PageKind::Song, 1),
+            "https://example.com/thcdb/sitemap/song/1"
+        );
+    }
+}
@@ -2,8 +2,13 @@ use utoipa_axum::router::OpenApiRouter;
 
 use crate::adapter::inbound::rest::state::ArcAppState;
 
+mod cue_import;
+mod export;
 pub mod find;
 
 pub fn router() -> OpenApiRouter<ArcAppState> {
-    OpenApiRouter::new().merge(find::router())
+    OpenApiRouter::new()
+        .merge(find::router())
+        .merge(cue_import::router())
+        .merge(export::router())
 }
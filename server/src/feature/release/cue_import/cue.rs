@@ -0,0 +1,307 @@
+//! A lenient CUE sheet reader. Only the commands needed to draft a
+//! tracklist are read, the others are skipped.
+
+use axum::http::StatusCode;
+use macros::{ApiError, IntoErrorSchema};
+
+/// CD frames per second, the unit of the last field of a timestamp
+const FRAMES_PER_SECOND: u32 = 75;
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+#[snafu(display("Invalid CUE sheet at line {line}: {reason}"))]
+#[api_error(
+    status_code = StatusCode::BAD_REQUEST
+)]
+pub struct ParseError {
+    pub line: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CueSheet {
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    /// Value of `REM DATE`
+    pub date: Option<String>,
+    pub tracks: Vec<CueTrack>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CueTrack {
+    pub number: u32,
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    /// Index of the `FILE` the track belongs to
+    pub file: usize,
+    /// `INDEX 01` in frames from the start of the file
+    pub start: Option<u32>,
+}
+
+impl CueSheet {
+    /// Reads UTF-8 sheets, with or without a BOM, and falls back to
+    /// `Shift_JIS`, which most Japanese rippers still write
+    pub fn decode(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (text, _, _) = encoding_rs::UTF_8.decode(bytes);
+        if !text.contains('\u{FFFD}') {
+            return Self::parse(&text);
+        }
+
+        let (text, _, _) = encoding_rs::SHIFT_JIS.decode(bytes);
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut sheet = Self::default();
+        let mut file: Option<usize> = None;
+
+        for (idx, line) in text.lines().enumerate() {
+            let line_number = idx + 1;
+            let error = |reason: &str| ParseError {
+                line: line_number,
+                reason: reason.to_owned(),
+            };
+
+            let line = line.trim();
+            let (command, rest) =
+                line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let rest = rest.trim();
+
+            match command.to_ascii_uppercase().as_str() {
+                "FILE" => {
+                    file = Some(file.map_or(0, |file| file + 1));
+                }
+                "TRACK" => {
+                    let number = rest
+                        .split_whitespace()
+                        .next()
+                        .and_then(|number| number.parse().ok())
+                        .ok_or_else(|| error("invalid track number"))?;
+
+                    sheet.tracks.push(CueTrack {
+                        number,
+                        title: None,
+                        performer: None,
+                        file: file.ok_or_else(|| error("TRACK before FILE"))?,
+                        start: None,
+                    });
+                }
+                "INDEX" => {
+                    let track = sheet
+                        .tracks
+                        .last_mut()
+                        .ok_or_else(|| error("INDEX outside of a track"))?;
+                    let mut fields = rest.split_whitespace();
+                    let (Some(index), Some(time)) =
+                        (fields.next(), fields.next())
+                    else {
+                        return Err(error("INDEX needs a number and a time"));
+                    };
+
+                    if index.parse::<u32>() == Ok(1) {
+                        track.start = Some(
+                            parse_time(time)
+                                .ok_or_else(|| error("invalid INDEX time"))?,
+                        );
+                    }
+                }
+                "TITLE" | "PERFORMER" => {
+                    let value = Some(unquote(rest)).filter(|x| !x.is_empty());
+                    let target = match sheet.tracks.last_mut() {
+                        Some(track) => (&mut track.title, &mut track.performer),
+                        None => (&mut sheet.title, &mut sheet.performer),
+                    };
+                    if command.eq_ignore_ascii_case("TITLE") {
+                        *target.0 = value;
+                    } else {
+                        *target.1 = value;
+                    }
+                }
+                "REM" => {
+                    if let Some((key, value)) =
+                        rest.split_once(char::is_whitespace)
+                        && key.eq_ignore_ascii_case("DATE")
+                        && sheet.tracks.is_empty()
+                    {
+                        sheet.date = Some(unquote(value.trim()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if sheet.tracks.is_empty() {
+            return Err(ParseError {
+                line: text.lines().count(),
+                reason: "no tracks".to_owned(),
+            });
+        }
+
+        Ok(sheet)
+    }
+
+    /// Track durations in milliseconds, from the gap between consecutive
+    /// `INDEX 01` of the same file. The last track is measured against
+    /// `total_duration`, the length of the last file in milliseconds.
+    pub fn durations(&self, total_duration: Option<i32>) -> Vec<Option<i32>> {
+        self.tracks
+            .iter()
+            .enumerate()
+            .map(|(idx, track)| {
+                let start = track.start?;
+                let end = match self.tracks.get(idx + 1) {
+                    Some(next) if next.file == track.file => next.start?,
+                    Some(_) => return None,
+                    None => {
+                        let total = u32::try_from(total_duration?).ok()?;
+                        total.checked_mul(FRAMES_PER_SECOND)? / 1000
+                    }
+                };
+
+                let frames = end.checked_sub(start).filter(|x| *x > 0)?;
+                i32::try_from(
+                    u64::from(frames) * 1000 / u64::from(FRAMES_PER_SECOND),
+                )
+                .ok()
+            })
+            .collect()
+    }
+}
+
+/// `mm:ss:ff`, minutes may go past 99
+fn parse_time(time: &str) -> Option<u32> {
+    let mut fields = time.split(':').map(|x| x.parse::<u32>().ok());
+    let (Some(Some(minutes)), Some(Some(seconds)), Some(Some(frames)), None) =
+        (fields.next(), fields.next(), fields.next(), fields.next())
+    else {
+        return None;
+    };
+
+    if seconds >= 60 || frames >= FRAMES_PER_SECOND {
+        return None;
+    }
+
+    (minutes * 60 + seconds)
+        .checked_mul(FRAMES_PER_SECOND)?
+        .checked_add(frames)
+}
+
+fn unquote(value: &str) -> String {
+    value
+        .strip_prefix('"')
+        .and_then(|x| x.strip_suffix('"'))
+        .unwrap_or(value)
+        .trim()
+        .to_owned()
+}
+
+/// Splits a `PERFORMER` listing several artists, e.g.
+/// "ZUN / 上海アリス幻樂団" or "Alice feat. Marisa"
+pub fn split_performers(performer: &str) -> Vec<String> {
+    const SEPARATORS: [&str; 6] =
+        ["/", "／", "、", " feat. ", " ft. ", " vs. "];
+
+    let mut names = vec![performer.to_owned()];
+    for separator in SEPARATORS {
+        names = names
+            .iter()
+            .flat_map(|name| name.split(separator))
+            .map(|name| name.trim().to_owned())
+            .filter(|name| !name.is_empty())
+            .collect();
+    }
+
+    names.dedup();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = r#"REM GENRE "Touhou"
+REM DATE 2019
+PERFORMER "Circle"
+TITLE "Album"
+FILE "album.flac" WAVE
+  TRACK 01 AUDIO
+    TITLE "First"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE "Second"
+    PERFORMER "Alice feat. Marisa"
+    INDEX 00 03:20:00
+    INDEX 01 03:22:30
+  TRACK 03 AUDIO
+    TITLE Third
+    INDEX 01 07:00:00
+"#;
+
+    #[test]
+    fn parse_sheet() {
+        let sheet = CueSheet::parse(SHEET).unwrap();
+
+        assert_eq!(sheet.title.as_deref(), Some("Album"));
+        assert_eq!(sheet.performer.as_deref(), Some("Circle"));
+        assert_eq!(sheet.date.as_deref(), Some("2019"));
+        assert_eq!(sheet.tracks.len(), 3);
+        assert_eq!(sheet.tracks[1].title.as_deref(), Some("Second"));
+        assert_eq!(
+            sheet.tracks[1].performer.as_deref(),
+            Some("Alice feat. Marisa")
+        );
+        assert_eq!(sheet.tracks[1].start, Some((3 * 60 + 22) * 75 + 30));
+        assert_eq!(sheet.tracks[2].title.as_deref(), Some("Third"));
+    }
+
+    #[test]
+    fn compute_durations() {
+        let sheet = CueSheet::parse(SHEET).unwrap();
+
+        assert_eq!(
+            sheet.durations(Some(600_000)),
+            [Some(202_400), Some(217_600), Some(180_000)]
+        );
+        assert_eq!(sheet.durations(None)[2], None);
+    }
+
+    #[test]
+    fn no_duration_across_files() {
+        let sheet = CueSheet::parse(
+            "FILE a.flac WAVE\nTRACK 1 AUDIO\nINDEX 01 00:00:00\n\
+             FILE b.flac WAVE\nTRACK 2 AUDIO\nINDEX 01 00:00:00\n",
+        )
+        .unwrap();
+
+        assert_eq!(sheet.durations(Some(1000)), [None, Some(1000)]);
+    }
+
+    #[test]
+    fn reject_invalid_sheets() {
+        assert_eq!(CueSheet::parse("TITLE x").unwrap_err().reason, "no tracks");
+        assert_eq!(
+            CueSheet::parse("FILE a WAVE\nTRACK 1 AUDIO\nINDEX 01 1:99:00")
+                .unwrap_err()
+                .line,
+            3
+        );
+    }
+
+    #[test]
+    fn decode_shift_jis() {
+        let (bytes, _, _) = encoding_rs::SHIFT_JIS
+            .encode("FILE a WAVE\nTRACK 1 AUDIO\nTITLE 幻想郷");
+        let sheet = CueSheet::decode(&bytes).unwrap();
+
+        assert_eq!(sheet.tracks[0].title.as_deref(), Some("幻想郷"));
+    }
+
+    #[test]
+    fn split_performer_lists() {
+        assert_eq!(
+            split_performers("ZUN / 上海アリス幻樂団"),
+            ["ZUN", "上海アリス幻樂団"]
+        );
+        assert_eq!(split_performers("Alice feat. Marisa"), ["Alice", "Marisa"]);
+        assert_eq!(split_performers("Alice"), ["Alice"]);
+    }
+}
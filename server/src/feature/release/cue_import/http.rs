@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum_typed_multipart::{FieldData, TryFromMultipart, TypedMultipart};
+use itertools::Itertools;
+use utoipa::ToSchema;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::cue::{CueSheet, split_performers};
+use super::model::{
+    DiscDraft, EntityMatch, MatchCandidate, ReleaseDraft, TrackDraft,
+};
+use super::{Error, repo};
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, CurrentUser, data};
+use crate::domain::shared::DateWithPrecision;
+
+const TAG: &str = "Release";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_private(|r| r.routes(routes!(import_cue)))
+        .finish()
+}
+
+data! {
+    DataReleaseDraft, ReleaseDraft
+}
+
+#[derive(Debug, ToSchema, TryFromMultipart)]
+pub struct CueImportFormData {
+    #[form_data(limit = "1MiB")]
+    #[schema(
+        value_type = String,
+        format = Binary,
+        maximum = 1048576, // 1 MiB
+    )]
+    pub data: FieldData<Bytes>,
+    /// Length of the last audio file in milliseconds, used for the duration
+    /// of the last track
+    pub total_duration: Option<i32>,
+}
+
+/// Draft a release from a CUE sheet.
+/// Nothing is saved, titles and performers are matched against existing
+/// songs and artists, and uncertain matches are flagged for review.
+#[utoipa::path(
+    post,
+    tag = TAG,
+    path = "/release/import/cue",
+    request_body(
+        content = CueImportFormData,
+        content_type = "multipart/form-data"
+    ),
+    responses(
+        (status = 200, body = DataReleaseDraft),
+        Error,
+    ),
+)]
+async fn import_cue(
+    CurrentUser(_user): CurrentUser,
+    State(repo): State<state::SeaOrmRepository>,
+    TypedMultipart(form): TypedMultipart<CueImportFormData>,
+) -> Result<Data<ReleaseDraft>, Error> {
+    let sheet = CueSheet::decode(&form.data.contents)?;
+    let durations = sheet.durations(form.total_duration);
+
+    let release_artists = sheet
+        .performer
+        .as_deref()
+        .map_or_else(Vec::new, split_performers);
+    let track_artists = sheet
+        .tracks
+        .iter()
+        .map(|track| {
+            track
+                .performer
+                .as_deref()
+                .map_or_else(Vec::new, split_performers)
+        })
+        .collect_vec();
+
+    let artist_names = release_artists
+        .iter()
+        .chain(track_artists.iter().flatten())
+        .cloned()
+        .collect_vec();
+    let song_titles = sheet
+        .tracks
+        .iter()
+        .filter_map(|track| track.title.clone())
+        .collect_vec();
+
+    let artist_matches = repo::match_artists(&repo, &artist_names).await?;
+    let song_matches = repo::match_songs(&repo, &song_titles).await?;
+
+    let to_match = |matches: &HashMap<String, MatchCandidate>,
+                    query: String| {
+        let candidate = matches.get(&query).cloned();
+        EntityMatch::new(query, candidate)
+    };
+
+    let tracks = sheet
+        .tracks
+        .into_iter()
+        .zip(durations)
+        .zip(track_artists)
+        .map(|((track, duration), artists)| TrackDraft {
+            track_number: Some(track.number.to_string()),
+            song: track
+                .title
+                .clone()
+                .map(|title| to_match(&song_matches, title)),
+            display_title: track.title,
+            duration,
+            disc_index: 0,
+            artists: artists
+                .into_iter()
+                .map(|name| to_match(&artist_matches, name))
+                .collect(),
+        })
+        .collect();
+
+    Ok(Data::new(ReleaseDraft {
+        title: sheet.title,
+        // `REM DATE` is usually a bare year, some rippers write a full date
+        release_date: sheet
+            .date
+            .as_deref()
+            .and_then(DateWithPrecision::parse_partial),
+        artists: release_artists
+            .into_iter()
+            .map(|name| to_match(&artist_matches, name))
+            .collect(),
+        discs: vec![DiscDraft { name: None }],
+        tracks,
+    }))
+}
@@ -0,0 +1,26 @@
+mod cue;
+mod http;
+mod model;
+mod repo;
+
+pub use http::router;
+use macros::{ApiError, IntoErrorSchema};
+
+use crate::infra;
+
+#[derive(Debug, snafu::Snafu, ApiError, IntoErrorSchema)]
+pub enum Error {
+    #[snafu(transparent)]
+    Parse { source: cue::ParseError },
+    #[snafu(transparent)]
+    Infra { source: infra::Error },
+}
+
+impl<E> From<E> for Error
+where
+    E: Into<infra::Error>,
+{
+    default fn from(err: E) -> Self {
+        Self::Infra { source: err.into() }
+    }
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::shared::DateWithPrecision;
+
+/// Matches below this trigram similarity have to be confirmed by the user
+pub const CONFIDENT_SIMILARITY: f32 = 0.8;
+
+/// A release prefilled from a CUE sheet. Its shape follows `NewRelease`,
+/// but songs and artists are suggestions that still have to be reviewed
+/// before submitting.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReleaseDraft {
+    pub title: Option<String>,
+    pub release_date: Option<DateWithPrecision>,
+    pub artists: Vec<EntityMatch>,
+    pub discs: Vec<DiscDraft>,
+    pub tracks: Vec<TrackDraft>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DiscDraft {
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrackDraft {
+    pub track_number: Option<String>,
+    pub display_title: Option<String>,
+    /// Milliseconds
+    pub duration: Option<i32>,
+    pub disc_index: u8,
+    /// Matched against the display title
+    pub song: Option<EntityMatch>,
+    pub artists: Vec<EntityMatch>,
+}
+
+/// A name from the sheet and the closest existing entity
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct EntityMatch {
+    pub query: String,
+    pub candidate: Option<MatchCandidate>,
+    /// Set when there is no candidate or its similarity is below
+    /// [`CONFIDENT_SIMILARITY`]
+    pub needs_confirmation: bool,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MatchCandidate {
+    pub id: i32,
+    pub name: String,
+    pub similarity: f32,
+}
+
+impl EntityMatch {
+    pub fn new(query: String, candidate: Option<MatchCandidate>) -> Self {
+        let needs_confirmation = candidate
+            .as_ref()
+            .is_none_or(|x| x.similarity < CONFIDENT_SIMILARITY);
+
+        Self {
+            query,
+            candidate,
+            needs_confirmation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_low_confidence() {
+        let candidate = |similarity| MatchCandidate {
+            id: 1,
+            name: "x".to_owned(),
+            similarity,
+        };
+
+        assert!(EntityMatch::new("x".to_owned(), None).needs_confirmation);
+        assert!(
+            EntityMatch::new("x".to_owned(), Some(candidate(0.5)))
+                .needs_confirmation
+        );
+        assert!(
+            !EntityMatch::new("x".to_owned(), Some(candidate(1.0)))
+                .needs_confirmation
+        );
+    }
+}
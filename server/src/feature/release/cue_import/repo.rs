@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use entity::{artist, song};
+use itertools::Itertools;
+use sea_orm::{
+    ConnectionTrait, DbErr, EntityName, FromQueryResult, IdenStatic, Statement,
+};
+
+use super::model::MatchCandidate;
+use crate::domain::Connection;
+
+#[derive(FromQueryResult)]
+struct MatchRow {
+    query: String,
+    id: i32,
+    name: String,
+    similarity: f32,
+}
+
+pub(super) async fn match_artists<R>(
+    repo: &R,
+    names: &[String],
+) -> Result<HashMap<String, MatchCandidate>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let table = artist::Entity.table_name();
+    let name = artist::Column::Name.as_str();
+
+    find_best_matches(repo, table, name, names).await
+}
+
+pub(super) async fn match_songs<R>(
+    repo: &R,
+    titles: &[String],
+) -> Result<HashMap<String, MatchCandidate>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let table = song::Entity.table_name();
+    let name = song::Column::Title.as_str();
+
+    find_best_matches(repo, table, name, titles).await
+}
+
+/// The most similar row for each query, queries without any trigram
+/// match are left out
+async fn find_best_matches<R>(
+    repo: &R,
+    table: &str,
+    name: &str,
+    queries: &[String],
+) -> Result<HashMap<String, MatchCandidate>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let queries = queries.iter().unique().cloned().collect_vec();
+    if queries.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let sql = format!(
+        r"
+        SELECT q.query, m.id, m.name, m.similarity
+        FROM unnest($1::text[]) AS q(query)
+        CROSS JOIN LATERAL (
+            SELECT e.id, e.{name} AS name,
+                similarity(lower(e.{name}), lower(q.query)) AS similarity
+            FROM {table} e
+            WHERE lower(e.{name}) % lower(q.query)
+            ORDER BY similarity DESC, e.id
+            LIMIT 1
+        ) AS m
+        "
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        repo.conn().get_database_backend(),
+        sql,
+        [queries.into()],
+    );
+
+    Ok(MatchRow::find_by_statement(stmt)
+        .all(repo.conn())
+        .await?
+        .into_iter()
+        .map(|row| {
+            let candidate = MatchCandidate {
+                id: row.id,
+                name: row.name,
+                similarity: row.similarity,
+            };
+            (row.query, candidate)
+        })
+        .collect())
+}
@@ -0,0 +1,173 @@
+//! CUE sheet writer, the counterpart of the CUE import
+
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use super::metadata::TrackMetadata;
+
+/// CD frames per second, the unit of the last field of a timestamp
+const FRAMES_PER_SECOND: i64 = 75;
+
+/// Same separator the importer splits performers on
+const PERFORMER_SEPARATOR: &str = " / ";
+
+/// Each disc gets its own `FILE`, as if ripped to one image per disc.
+/// `INDEX 01` is written as long as every track before it on the disc has a
+/// duration.
+pub fn render(tracks: &[TrackMetadata<'_>]) -> String {
+    let mut out = String::new();
+    let Some(first) = tracks.first() else {
+        return out;
+    };
+
+    if let Some(date) = &first.date {
+        line(&mut out, 0, &format!("REM DATE {date}"));
+    }
+    for catalog_number in &first.catalog_numbers {
+        line(
+            &mut out,
+            0,
+            &format!("REM CATALOGNUMBER {}", quote(catalog_number)),
+        );
+    }
+    if !first.album_artists.is_empty() {
+        line(
+            &mut out,
+            0,
+            &command(
+                "PERFORMER",
+                &first.album_artists.join(PERFORMER_SEPARATOR),
+            ),
+        );
+    }
+    line(&mut out, 0, &command("TITLE", first.album));
+
+    for (number, track) in (1..).zip(tracks) {
+        let first_on_disc =
+            number == 1 || tracks[number - 2].disc_number != track.disc_number;
+        if first_on_disc {
+            line(
+                &mut out,
+                0,
+                &format!("FILE {} WAVE", quote(&file_name(track))),
+            );
+        }
+
+        line(&mut out, 1, &format!("TRACK {number:02} AUDIO"));
+        line(&mut out, 2, &command("TITLE", track.title));
+        if track.artists != track.album_artists {
+            line(
+                &mut out,
+                2,
+                &command("PERFORMER", &track.artists.join(PERFORMER_SEPARATOR)),
+            );
+        }
+        if !track.composers.is_empty() {
+            line(
+                &mut out,
+                2,
+                &command(
+                    "SONGWRITER",
+                    &track.composers.join(PERFORMER_SEPARATOR),
+                ),
+            );
+        }
+        if let Some(start) = start_on_disc(tracks, number - 1) {
+            line(&mut out, 2, &format!("INDEX 01 {}", timestamp(start)));
+        }
+    }
+
+    out
+}
+
+/// Milliseconds from the start of the disc to the track at `idx`
+fn start_on_disc(tracks: &[TrackMetadata<'_>], idx: usize) -> Option<i64> {
+    let disc = tracks[idx].disc_number;
+
+    tracks[..idx]
+        .iter()
+        .filter(|track| track.disc_number == disc)
+        .map(|track| track.duration.map(i64::from))
+        .fold_options(0, |acc, x| acc + x)
+}
+
+fn file_name(track: &TrackMetadata<'_>) -> String {
+    if track.disc_total > 1 {
+        format!("{} (Disc {}).wav", track.album, track.disc_number)
+    } else {
+        format!("{}.wav", track.album)
+    }
+}
+
+fn command(name: &str, value: &str) -> String {
+    format!("{name} {}", quote(value))
+}
+
+/// CUE has no escapes, so double quotes inside a value are swapped for
+/// single quotes
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "'"))
+}
+
+/// `mm:ss:ff`, minutes may go past 99
+fn timestamp(ms: i64) -> String {
+    let frames = ms * FRAMES_PER_SECOND / 1000;
+    let seconds = frames / FRAMES_PER_SECOND;
+
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 60,
+        seconds % 60,
+        frames % FRAMES_PER_SECOND
+    )
+}
+
+fn line(out: &mut String, indent: usize, content: &str) {
+    writeln!(out, "{}{content}", "  ".repeat(indent))
+        .expect("Writing to a String never fails");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::release::export::metadata::tests::{credits, release};
+    use crate::feature::release::export::metadata::{self};
+
+    #[test]
+    fn render_sheet() {
+        let release = release();
+        let credits = credits();
+        let sheet = render(&metadata::collect(&release, &credits));
+
+        assert_eq!(
+            sheet,
+            r#"REM DATE 2019-08
+REM CATALOGNUMBER "ABCD-0001"
+PERFORMER "Circle"
+TITLE "Album"
+FILE "Album (Disc 1).wav" WAVE
+  TRACK 01 AUDIO
+    TITLE "First"
+    SONGWRITER "ZUN"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE "Second"
+    PERFORMER "Alice"
+    SONGWRITER "ZUN"
+    INDEX 01 03:00:00
+FILE "Album (Disc 2).wav" WAVE
+  TRACK 03 AUDIO
+    TITLE "Song 3"
+    SONGWRITER "ZUN / Yuka"
+    INDEX 01 00:00:00
+"#
+        );
+    }
+
+    #[test]
+    fn format_timestamp() {
+        assert_eq!(timestamp(200_500), "03:20:37");
+        assert_eq!(timestamp(6_000_000), "100:00:00");
+    }
+}
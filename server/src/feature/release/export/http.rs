@@ -0,0 +1,91 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::metadata;
+use super::tags::{TagExport, TagFormat};
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::feature::release::find::repo::{self, FindReleaseFilter};
+use crate::infra::error::Error;
+
+const TAG: &str = "Release";
+
+const CUE_CONTENT_TYPE: &str = "application/x-cue; charset=utf-8";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(export_release)))
+        .finish()
+}
+
+data! {
+    DataTagExport, TagExport
+}
+
+#[derive(Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+enum ExportFormat {
+    Cue,
+    Vorbis,
+    Id3,
+}
+
+#[derive(Deserialize, IntoParams)]
+struct ExportQuery {
+    #[param(inline)]
+    format: ExportFormat,
+}
+
+/// Release metadata for audio taggers, either as a CUE sheet or as
+/// per-track Vorbis comments or ID3 frames
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/release/{id}/export",
+    params(
+        ("id" = i32, Path),
+        ExportQuery,
+    ),
+    responses(
+        (status = 200, content(
+            (String = "application/x-cue"),
+            (DataTagExport = "application/json"),
+        )),
+        (status = 404, description = "Release not found"),
+        Error,
+    ),
+)]
+async fn export_release(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response, Error> {
+    let Some(release) =
+        repo::find_one(&repo, FindReleaseFilter::Id(id)).await?
+    else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    let credits = super::repo::find_credits(&repo, &release).await?;
+    let tracks = metadata::collect(&release, &credits);
+
+    let tag_format = match query.format {
+        ExportFormat::Cue => {
+            return Ok((
+                [(header::CONTENT_TYPE, CUE_CONTENT_TYPE)],
+                super::cue::render(&tracks),
+            )
+                .into_response());
+        }
+        ExportFormat::Vorbis => TagFormat::Vorbis,
+        ExportFormat::Id3 => TagFormat::Id3,
+    };
+
+    Ok(Data::new(TagExport::new(tag_format, &tracks)).into_response())
+}
@@ -0,0 +1,355 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::domain::release::Release;
+use crate::domain::shared::DateWithPrecision;
+
+/// Per-track metadata shared by all export formats
+#[derive(Debug)]
+pub struct TrackMetadata<'a> {
+    pub track_id: i32,
+    pub title: &'a str,
+    pub album: &'a str,
+    pub artists: Vec<&'a str>,
+    pub album_artists: Vec<&'a str>,
+    /// 1-based
+    pub disc_number: usize,
+    pub disc_total: usize,
+    pub disc_name: Option<&'a str>,
+    pub track_number: String,
+    pub track_total: usize,
+    /// Milliseconds
+    pub duration: Option<i32>,
+    pub date: Option<String>,
+    pub catalog_numbers: Vec<&'a str>,
+    pub labels: Vec<&'a str>,
+    pub performers: Vec<&'a str>,
+    pub composers: Vec<&'a str>,
+    pub arrangers: Vec<&'a str>,
+}
+
+/// Tag a credit is written to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CreditField {
+    Performer,
+    Composer,
+    Arranger,
+}
+
+impl CreditField {
+    pub const ALL: [Self; 3] =
+        [Self::Performer, Self::Composer, Self::Arranger];
+
+    /// Name of the credit role written to the field, along with all roles
+    /// below it in the hierarchy
+    pub const fn root_role(self) -> &'static str {
+        match self {
+            Self::Performer => "Performer",
+            Self::Composer => "Composer",
+            Self::Arranger => "Arranger",
+        }
+    }
+}
+
+/// Credit of the song of a track
+#[derive(Debug)]
+pub struct SongCredit {
+    pub role_id: i32,
+    pub artist: String,
+}
+
+/// Credits looked up alongside the release
+#[derive(Debug, Default)]
+pub struct Credits {
+    /// Fields each credit role is written to, by role id
+    pub fields: HashMap<i32, Vec<CreditField>>,
+    /// Credits with a role of the songs of the tracks, by song id
+    pub songs: HashMap<i32, Vec<SongCredit>>,
+}
+
+impl Credits {
+    fn is_written_to(&self, role_id: i32, field: CreditField) -> bool {
+        self.fields
+            .get(&role_id)
+            .is_some_and(|fields| fields.contains(&field))
+    }
+}
+
+/// Tracks in release order. Discs are numbered by their position, and
+/// tracks without a track number by their position on the disc.
+pub fn collect<'a>(
+    release: &'a Release,
+    credits: &'a Credits,
+) -> Vec<TrackMetadata<'a>> {
+    let album_artists = release
+        .artists
+        .iter()
+        .map(|artist| artist.name.as_str())
+        .collect_vec();
+    let date = release.release_date.map(DateWithPrecision::to_iso_string);
+    let catalog_numbers = release
+        .catalog_nums
+        .iter()
+        .map(|x| x.catalog_number.as_str())
+        .collect_vec();
+    let labels = release
+        .catalog_nums
+        .iter()
+        .filter_map(|x| x.label.as_ref())
+        .map(|label| label.name.as_str())
+        .unique()
+        .collect_vec();
+
+    let mut tracks = vec![];
+    let mut position = 0;
+
+    for (disc_idx, disc) in release.discs.iter().enumerate() {
+        let disc_tracks = release
+            .tracks
+            .iter()
+            .filter(|track| track.disc_id == disc.id)
+            .collect_vec();
+
+        for (track_idx, track) in disc_tracks.iter().enumerate() {
+            position += 1;
+
+            let artists = if track.artists.is_empty() {
+                album_artists.clone()
+            } else {
+                track.artists.iter().map(|x| x.name.as_str()).collect()
+            };
+
+            let credited = |field| {
+                credits_on(release, position, field, credits)
+                    .chain(song_credits(track.song.id, field, credits))
+                    .unique()
+                    .collect_vec()
+            };
+
+            tracks.push(TrackMetadata {
+                track_id: track.id,
+                title: track
+                    .display_title
+                    .as_deref()
+                    .unwrap_or(&track.song.title),
+                album: &release.title,
+                artists,
+                album_artists: album_artists.clone(),
+                disc_number: disc_idx + 1,
+                disc_total: release.discs.len(),
+                disc_name: disc.name.as_deref(),
+                track_number: track
+                    .track_number
+                    .clone()
+                    .unwrap_or_else(|| (track_idx + 1).to_string()),
+                track_total: disc_tracks.len(),
+                duration: track.duration,
+                date: date.clone(),
+                catalog_numbers: catalog_numbers.clone(),
+                labels: labels.clone(),
+                performers: credited(CreditField::Performer),
+                composers: credited(CreditField::Composer),
+                arrangers: credited(CreditField::Arranger),
+            });
+        }
+    }
+
+    tracks
+}
+
+/// Credits without `on` apply to every track, otherwise `on` lists the
+/// 1-based positions of the tracks in the release
+fn credits_on<'a>(
+    release: &'a Release,
+    position: usize,
+    field: CreditField,
+    credits: &Credits,
+) -> impl Iterator<Item = &'a str> {
+    release
+        .credits
+        .iter()
+        .filter(move |credit| credits.is_written_to(credit.role.id, field))
+        .filter(move |credit| {
+            credit.on.as_ref().is_none_or(|on| {
+                on.iter().any(|x| usize::try_from(*x) == Ok(position))
+            })
+        })
+        .map(|credit| credit.artist.name.as_str())
+}
+
+fn song_credits(
+    song_id: i32,
+    field: CreditField,
+    credits: &Credits,
+) -> impl Iterator<Item = &str> {
+    credits
+        .songs
+        .get(&song_id)
+        .into_iter()
+        .flatten()
+        .filter(move |credit| credits.is_written_to(credit.role_id, field))
+        .map(|credit| credit.artist.as_str())
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use chrono::NaiveDate;
+    use entity::enums::{DatePrecision, ReleaseType};
+
+    use super::*;
+    use crate::domain::credit_role::CreditRoleRef;
+    use crate::domain::label::SimpleLabel;
+    use crate::domain::release::{
+        CatalogNumber, ReleaseArtist, ReleaseCredit, ReleaseDisc, ReleaseTrack,
+    };
+    use crate::domain::song::SongRef;
+
+    fn artist(id: i32, name: &str) -> ReleaseArtist {
+        ReleaseArtist {
+            id,
+            name: name.to_owned(),
+        }
+    }
+
+    fn track(
+        id: i32,
+        disc_id: i32,
+        track_number: Option<&str>,
+        display_title: Option<&str>,
+        duration: Option<i32>,
+        artists: Vec<ReleaseArtist>,
+    ) -> ReleaseTrack {
+        ReleaseTrack {
+            id,
+            track_number: track_number.map(ToOwned::to_owned),
+            disc_id,
+            display_title: display_title.map(ToOwned::to_owned),
+            duration,
+            song: SongRef {
+                id,
+                title: format!("Song {id}"),
+            },
+            artists,
+        }
+    }
+
+    fn credit(
+        artist_id: i32,
+        name: &str,
+        (role_id, role): (i32, &str),
+        on: Option<Vec<i16>>,
+    ) -> ReleaseCredit {
+        ReleaseCredit {
+            artist: artist(artist_id, name),
+            role: CreditRoleRef {
+                id: role_id,
+                name: role.to_owned(),
+            },
+            on,
+        }
+    }
+
+    fn song_credit(role_id: i32, artist: &str) -> SongCredit {
+        SongCredit {
+            role_id,
+            artist: artist.to_owned(),
+        }
+    }
+
+    /// Two discs, the second track has its own artist and the last one
+    /// has neither a track number nor a display title
+    pub fn release() -> Release {
+        Release {
+            id: 1,
+            title: "Album".to_owned(),
+            release_type: ReleaseType::Album,
+            release_date: Some(DateWithPrecision {
+                value: NaiveDate::from_ymd_opt(2019, 8, 1).unwrap(),
+                precision: DatePrecision::Month,
+            }),
+            recording_date_start: None,
+            recording_date_end: None,
+            cover_art_url: None,
+            artists: vec![artist(1, "Circle")],
+            credits: vec![
+                credit(2, "ZUN", (1, "Composer"), None),
+                credit(3, "Alice", (2, "Arrange"), Some(vec![2])),
+                credit(4, "Marisa", (3, "Vocal"), Some(vec![3])),
+                credit(5, "Reimu", (4, "Illustration"), None),
+            ],
+            catalog_nums: vec![CatalogNumber {
+                catalog_number: "ABCD-0001".to_owned(),
+                label: Some(SimpleLabel {
+                    id: 1,
+                    name: "Label".to_owned(),
+                }),
+            }],
+            localized_titles: vec![],
+            external_ids: vec![],
+            discs: vec![
+                ReleaseDisc { id: 1, name: None },
+                ReleaseDisc {
+                    id: 2,
+                    name: Some("Bonus".to_owned()),
+                },
+            ],
+            tracks: vec![
+                track(1, 1, Some("1"), Some("First"), Some(180_000), vec![]),
+                track(
+                    2,
+                    1,
+                    Some("2"),
+                    Some("Second"),
+                    Some(200_500),
+                    vec![artist(3, "Alice")],
+                ),
+                track(3, 2, None, None, None, vec![]),
+            ],
+            events: vec![],
+        }
+    }
+
+    /// "Arrange" and "Vocal" are below the arranger and performer roles,
+    /// "Illustration" isn't written anywhere
+    pub fn credits() -> Credits {
+        Credits {
+            fields: HashMap::from([
+                (1, vec![CreditField::Composer]),
+                (2, vec![CreditField::Arranger]),
+                (3, vec![CreditField::Performer]),
+            ]),
+            songs: HashMap::from([
+                (1, vec![song_credit(1, "ZUN"), song_credit(4, "Reimu")]),
+                (3, vec![song_credit(1, "Yuka"), song_credit(3, "Marisa")]),
+            ]),
+        }
+    }
+
+    #[test]
+    fn collect_tracks() {
+        let release = release();
+        let credits = credits();
+        let tracks = collect(&release, &credits);
+
+        assert_eq!(tracks.len(), 3);
+        assert_eq!(tracks[0].artists, ["Circle"]);
+        assert_eq!(tracks[1].arrangers, ["Alice"]);
+        assert_eq!(tracks[2].title, "Song 3");
+        assert_eq!(tracks[2].track_number, "1");
+        assert_eq!(tracks[2].disc_number, 2);
+        assert!(tracks.iter().all(|x| !x.performers.contains(&"Reimu")));
+    }
+
+    #[test]
+    fn merge_song_credits() {
+        let release = release();
+        let credits = credits();
+        let tracks = collect(&release, &credits);
+
+        assert_eq!(tracks[0].composers, ["ZUN"]);
+        assert_eq!(tracks[1].composers, ["ZUN"]);
+        assert_eq!(tracks[2].composers, ["ZUN", "Yuka"]);
+        assert_eq!(tracks[2].performers, ["Marisa"]);
+    }
+}
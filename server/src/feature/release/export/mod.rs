@@ -0,0 +1,7 @@
+mod cue;
+mod http;
+mod metadata;
+mod repo;
+mod tags;
+
+pub use http::router;
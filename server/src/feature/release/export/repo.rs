@@ -0,0 +1,113 @@
+use std::collections::{HashMap, HashSet};
+
+use entity::{artist, credit_role, song_credit};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Select,
+};
+
+use super::metadata::{CreditField, Credits, SongCredit};
+use crate::domain::Connection;
+use crate::domain::release::Release;
+use crate::infra::database::sea_orm::hierarchy;
+
+/// Roles are user defined, so the fields are found from their root roles
+/// and the hierarchy below them
+pub(super) async fn find_credits<R>(
+    repo: &R,
+    release: &Release,
+) -> Result<Credits, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let roots = root_roles_select()
+        .into_tuple::<(i32, String)>()
+        .all(db)
+        .await?;
+
+    let mut fields: HashMap<i32, Vec<CreditField>> = HashMap::new();
+    for (root_id, name) in roots {
+        let Some(field) =
+            CreditField::ALL.into_iter().find(|x| x.root_role() == name)
+        else {
+            continue;
+        };
+
+        let mut role_ids = hierarchy::CREDIT_ROLE
+            .find_descendant_ids(db, root_id)
+            .await?;
+        role_ids.insert(root_id);
+
+        for role_id in role_ids {
+            fields.entry(role_id).or_default().push(field);
+        }
+    }
+
+    let song_ids: HashSet<i32> =
+        release.tracks.iter().map(|track| track.song.id).collect();
+    let songs = song_credits_select(song_ids)
+        .into_tuple::<(i32, i32, String)>()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|(song_id, role_id, artist)| {
+            (song_id, SongCredit { role_id, artist })
+        })
+        .into_group_map();
+
+    Ok(Credits { fields, songs })
+}
+
+fn root_roles_select() -> Select<credit_role::Entity> {
+    credit_role::Entity::find()
+        .select_only()
+        .columns([credit_role::Column::Id, credit_role::Column::Name])
+        .filter(
+            credit_role::Column::Name
+                .is_in(CreditField::ALL.map(CreditField::root_role)),
+        )
+}
+
+fn song_credits_select(
+    song_ids: impl IntoIterator<Item = i32>,
+) -> Select<song_credit::Entity> {
+    song_credit::Entity::find()
+        .select_only()
+        .columns([song_credit::Column::SongId, song_credit::Column::RoleId])
+        .column(artist::Column::Name)
+        .inner_join(artist::Entity)
+        .filter(song_credit::Column::SongId.is_in(song_ids))
+        .filter(song_credit::Column::RoleId.is_not_null())
+        .order_by_asc(song_credit::Column::Id)
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::QueryTrait;
+
+    use super::*;
+
+    #[test]
+    fn find_root_roles_by_name() {
+        assert_eq!(
+            root_roles_select()
+                .build(sea_orm::DatabaseBackend::Postgres)
+                .to_string(),
+            r#"SELECT "credit_role"."id", "credit_role"."name" FROM "credit_role" WHERE "credit_role"."name" IN ('Performer', 'Composer', 'Arranger')"#
+        );
+    }
+
+    #[test]
+    fn find_song_credits_with_a_role() {
+        assert_eq!(
+            song_credits_select([1, 2])
+                .build(sea_orm::DatabaseBackend::Postgres)
+                .to_string(),
+            r#"SELECT "song_credit"."song_id", "song_credit"."role_id", "artist"."name" FROM "song_credit" INNER JOIN "artist" ON "song_credit"."artist_id" = "artist"."id" WHERE "song_credit"."song_id" IN (1, 2) AND "song_credit"."role_id" IS NOT NULL ORDER BY "song_credit"."id" ASC"#
+        );
+    }
+}
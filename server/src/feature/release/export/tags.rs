@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::metadata::TrackMetadata;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TagFormat {
+    /// Vorbis comments, as read by FLAC and Ogg taggers
+    Vorbis,
+    /// ID3v2.4 frames, fields without a dedicated frame go to `TXXX`
+    Id3,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TagExport {
+    pub format: TagFormat,
+    pub tracks: Vec<TrackTags>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrackTags {
+    pub track_id: i32,
+    /// Tag name to values, a tag may be repeated
+    pub tags: BTreeMap<String, Vec<String>>,
+}
+
+impl TagExport {
+    pub fn new(format: TagFormat, tracks: &[TrackMetadata<'_>]) -> Self {
+        let tracks = tracks
+            .iter()
+            .map(|track| TrackTags {
+                track_id: track.track_id,
+                tags: match format {
+                    TagFormat::Vorbis => vorbis(track),
+                    TagFormat::Id3 => id3(track),
+                },
+            })
+            .collect();
+
+        Self { format, tracks }
+    }
+}
+
+fn vorbis(track: &TrackMetadata<'_>) -> BTreeMap<String, Vec<String>> {
+    let mut tags = Tags::default();
+
+    tags.one("TITLE", track.title);
+    tags.one("ALBUM", track.album);
+    tags.many("ARTIST", &track.artists);
+    tags.many("ALBUMARTIST", &track.album_artists);
+    tags.one("DISCNUMBER", track.disc_number);
+    tags.one("DISCTOTAL", track.disc_total);
+    tags.one("TRACKNUMBER", &track.track_number);
+    tags.one("TRACKTOTAL", track.track_total);
+    if let Some(date) = &track.date {
+        tags.one("DATE", date);
+    }
+    tags.many("CATALOGNUMBER", &track.catalog_numbers);
+    tags.many("LABEL", &track.labels);
+    tags.many("PERFORMER", &track.performers);
+    tags.many("COMPOSER", &track.composers);
+    tags.many("ARRANGER", &track.arrangers);
+
+    tags.0
+}
+
+fn id3(track: &TrackMetadata<'_>) -> BTreeMap<String, Vec<String>> {
+    let mut tags = Tags::default();
+
+    tags.one("TIT2", track.title);
+    tags.one("TALB", track.album);
+    tags.many("TPE1", &track.artists);
+    tags.many("TPE2", &track.album_artists);
+    tags.one(
+        "TPOS",
+        format!("{}/{}", track.disc_number, track.disc_total),
+    );
+    tags.one(
+        "TRCK",
+        format!("{}/{}", track.track_number, track.track_total),
+    );
+    if let Some(date) = &track.date {
+        tags.one("TDRC", date);
+    }
+    tags.many("TXXX:CATALOGNUMBER", &track.catalog_numbers);
+    tags.many("TPUB", &track.labels);
+    tags.many("TXXX:PERFORMER", &track.performers);
+    tags.many("TCOM", &track.composers);
+    tags.many("TXXX:ARRANGER", &track.arrangers);
+
+    tags.0
+}
+
+/// Empty tags are left out
+#[derive(Default)]
+struct Tags(BTreeMap<String, Vec<String>>);
+
+impl Tags {
+    fn one(&mut self, name: &str, value: impl ToString) {
+        self.many(name, &[value]);
+    }
+
+    fn many(&mut self, name: &str, values: &[impl ToString]) {
+        if !values.is_empty() {
+            self.0.insert(
+                name.to_owned(),
+                values.iter().map(ToString::to_string).collect(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feature::release::export::metadata::tests::{credits, release};
+    use crate::feature::release::export::metadata::{self};
+
+    #[test]
+    fn vorbis_tags() {
+        let release = release();
+        let credits = credits();
+        let tracks = metadata::collect(&release, &credits);
+        let export = TagExport::new(TagFormat::Vorbis, &tracks);
+        let tags = &export.tracks[1].tags;
+
+        assert_eq!(tags["TITLE"], ["Second"]);
+        assert_eq!(tags["ARTIST"], ["Alice"]);
+        assert_eq!(tags["ALBUMARTIST"], ["Circle"]);
+        assert_eq!(tags["DISCNUMBER"], ["1"]);
+        assert_eq!(tags["TRACKNUMBER"], ["2"]);
+        assert_eq!(tags["DATE"], ["2019-08"]);
+        assert_eq!(tags["LABEL"], ["Label"]);
+        assert_eq!(tags["COMPOSER"], ["ZUN"]);
+        assert_eq!(tags["ARRANGER"], ["Alice"]);
+        assert!(!tags.contains_key("PERFORMER"));
+
+        assert!(!export.tracks[0].tags.contains_key("ARRANGER"));
+    }
+
+    #[test]
+    fn id3_frames() {
+        let release = release();
+        let credits = credits();
+        let tracks = metadata::collect(&release, &credits);
+        let export = TagExport::new(TagFormat::Id3, &tracks);
+        let tags = &export.tracks[2].tags;
+
+        assert_eq!(tags["TIT2"], ["Song 3"]);
+        assert_eq!(tags["TPE1"], ["Circle"]);
+        assert_eq!(tags["TPOS"], ["2/2"]);
+        assert_eq!(tags["TRCK"], ["1/1"]);
+        assert_eq!(tags["TXXX:CATALOGNUMBER"], ["ABCD-0001"]);
+        assert_eq!(tags["TXXX:PERFORMER"], ["Marisa"]);
+    }
+}
@@ -1,10 +1,13 @@
-use entity::release;
 use entity::sea_orm_active_enums::ReleaseType;
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, Select};
+use entity::{release, release_tag_vote};
+use sea_orm::{
+    ColumnTrait, EntityName, EntityTrait, IdenStatic, QueryFilter, Select,
+};
 use serde::Deserialize;
-use serde_with::{OneOrMany, serde_as};
+use serde_with::{DisplayFromStr, OneOrMany, serde_as};
 use utoipa::{IntoParams, ToSchema};
 
+use crate::infra::database::sea_orm::{label_hierarchy, tag_hierarchy};
 pub use crate::shared::http::{
     CorrectionSortField, PaginationQuery, SortDirection,
 };
@@ -17,6 +20,22 @@ pub struct ReleaseFilter {
     #[serde(default, rename = "release_type", alias = "release_type[]")]
     pub release_types: Option<Vec<ReleaseType>>,
 
+    #[serde_as(as = "Option<OneOrMany<DisplayFromStr>>")]
+    #[serde(default, rename = "tag_id", alias = "tag_id[]")]
+    pub tag_ids: Option<Vec<i32>>,
+
+    /// Also match entities voted with a subtag of `tag_id`
+    #[serde(default)]
+    pub include_subtags: bool,
+
+    /// Releases with a catalog number of this label
+    pub label_id: Option<i32>,
+
+    /// Also match releases of imprints and subsidiaries below `label_id`,
+    /// not the ones of labels it only distributes
+    #[serde(default)]
+    pub include_child_labels: bool,
+
     #[serde(default)]
     pub sort_field: Option<CorrectionSortField>,
 
@@ -44,6 +63,23 @@ impl ReleaseFilter {
             );
         }
 
+        if let Some(tag_ids) = &self.tag_ids {
+            select = select.filter(tag_hierarchy::tagged_with(
+                release::Entity.table_name(),
+                release_tag_vote::Entity.table_name(),
+                release_tag_vote::Column::ReleaseId.as_str(),
+                tag_ids,
+                self.include_subtags,
+            ));
+        }
+
+        if let Some(label_id) = self.label_id {
+            select = select.filter(label_hierarchy::released_by(
+                label_id,
+                self.include_child_labels,
+            ));
+        }
+
         select
     }
 }
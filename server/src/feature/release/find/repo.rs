@@ -144,14 +144,10 @@ where
         return Ok(crate::domain::shared::Paginated::nothing());
     }
 
-    let mut select = release::Entity::find()
+    let select = filter
+        .into_select()
         .filter(release::Column::Id.is_in(entity_ids.clone()));
 
-    if let Some(release_types) = filter.release_types {
-        select =
-            select.filter(release::Column::ReleaseType.is_in(release_types));
-    }
-
     let mut releases = find_many_impl(select, repo.conn()).await?;
 
     releases = crate::infra::database::sea_orm::utils::sort_by_id_list(
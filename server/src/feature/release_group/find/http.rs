@@ -0,0 +1,67 @@
+use axum::extract::{Path, Query, State};
+use libfp::BifunctorExt;
+use serde::Deserialize;
+use utoipa::IntoParams;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::repo;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::domain::release_group::ReleaseGroup;
+use crate::infra::error::Error;
+
+const TAG: &str = "Release Group";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| {
+            r.routes(routes!(find_release_group_by_id))
+                .routes(routes!(find_release_group_by_keyword))
+        })
+        .finish()
+}
+
+data! {
+    DataOptionReleaseGroup, Option<ReleaseGroup>
+    DataVecReleaseGroup, Vec<ReleaseGroup>
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/release-group/{id}",
+    responses(
+        (status = 200, body = DataOptionReleaseGroup),
+    ),
+)]
+async fn find_release_group_by_id(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<ReleaseGroup>>, Error> {
+    repo::find_by_id(&repo, id).await.bimap_into()
+}
+
+#[derive(Deserialize, IntoParams)]
+struct KwQuery {
+    keyword: String,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/release-group",
+    params(KwQuery),
+    responses(
+        (status = 200, body = DataVecReleaseGroup),
+    ),
+)]
+async fn find_release_group_by_keyword(
+    State(repo): State<state::SeaOrmRepository>,
+    Query(query): Query<KwQuery>,
+) -> Result<Data<Vec<ReleaseGroup>>, Error> {
+    repo::find_by_keyword(&repo, &query.keyword)
+        .await
+        .bimap_into()
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use entity::enums::EntityType;
+use entity::{
+    release, release_catalog_number, release_group, release_group_release,
+};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    Select,
+};
+use sea_query::extension::postgres::PgBinOper::{
+    Similarity, SimilarityDistance,
+};
+use sea_query::{ExprTrait, Func};
+
+use crate::domain::Connection;
+use crate::domain::release_group::{ReleaseEdition, ReleaseGroup};
+use crate::domain::shared::DateWithPrecision;
+use crate::infra::database::sea_orm::external_id;
+
+pub(super) async fn find_by_id<R>(
+    repo: &R,
+    id: i32,
+) -> Result<Option<ReleaseGroup>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let select =
+        release_group::Entity::find().filter(release_group::Column::Id.eq(id));
+
+    find_many_impl(select, repo.conn())
+        .await
+        .map(|mut groups| groups.pop())
+}
+
+pub(super) async fn find_by_keyword<R>(
+    repo: &R,
+    keyword: &str,
+) -> Result<Vec<ReleaseGroup>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let search_term = Func::lower(keyword);
+
+    let select = release_group::Entity::find()
+        .filter(
+            Func::lower(release_group::Column::Title.into_expr())
+                .binary(Similarity, search_term.clone()),
+        )
+        .order_by_asc(
+            Func::lower(release_group::Column::Title.into_expr())
+                .binary(SimilarityDistance, search_term),
+        );
+
+    find_many_impl(select, repo.conn()).await
+}
+
+async fn find_many_impl(
+    select: Select<release_group::Entity>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<ReleaseGroup>, DbErr> {
+    let groups = select.all(db).await?;
+    if groups.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // Editions in release order, undated ones last
+    let editions = release_group_release::Entity::find()
+        .find_also_related(release::Entity)
+        .filter(
+            release_group_release::Column::GroupId
+                .is_in(groups.iter().map(|group| group.id)),
+        )
+        .order_by_asc(release::Column::ReleaseDate)
+        .order_by_asc(release::Column::Id)
+        .all(db)
+        .await?;
+
+    let mut catalog_numbers: HashMap<i32, Vec<String>> =
+        release_catalog_number::Entity::find()
+            .filter(
+                release_catalog_number::Column::ReleaseId.is_in(
+                    editions.iter().map(|(edition, _)| edition.release_id),
+                ),
+            )
+            .order_by_asc(release_catalog_number::Column::Id)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|model| (model.release_id, model.catalog_number))
+            .into_group_map();
+
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::ReleaseGroup,
+        groups.iter().map(|group| group.id),
+        db,
+    )
+    .await?;
+
+    let mut editions = editions
+        .into_iter()
+        .filter_map(|(edition, release)| {
+            let release = release?;
+
+            Some((
+                edition.group_id,
+                ReleaseEdition {
+                    release_id: release.id,
+                    title: release.title,
+                    release_date: DateWithPrecision::from_option(
+                        release.release_date,
+                        release.release_date_precision,
+                    ),
+                    catalog_numbers: catalog_numbers
+                        .remove(&release.id)
+                        .unwrap_or_default(),
+                    edition_type: edition.edition_type,
+                    notes: edition.notes,
+                },
+            ))
+        })
+        .into_group_map();
+
+    Ok(groups
+        .into_iter()
+        .map(|group| ReleaseGroup {
+            editions: editions.remove(&group.id).unwrap_or_default(),
+            id: group.id,
+            title: group.title,
+            description: group.description,
+            external_ids: external_ids.remove(&group.id).unwrap_or_default(),
+        })
+        .collect())
+}
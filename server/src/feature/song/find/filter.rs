@@ -1,12 +1,14 @@
-use entity::{song, song_language};
+use entity::{song, song_language, song_tag_vote};
 use sea_orm::{
-    ColumnTrait, EntityTrait, QueryFilter, QuerySelect, QueryTrait, Select,
+    ColumnTrait, EntityName, EntityTrait, IdenStatic, QueryFilter, QuerySelect,
+    QueryTrait, Select,
 };
 use sea_query::Expr;
 use serde::{Deserialize, Serialize};
 use serde_with::{DisplayFromStr, OneOrMany, serde_as};
 use utoipa::{IntoParams, ToSchema};
 
+use crate::infra::database::sea_orm::tag_hierarchy;
 pub use crate::shared::http::{
     CorrectionSortField, PaginationQuery, SortDirection,
 };
@@ -26,6 +28,14 @@ pub struct SongFilter {
     #[serde_as(as = "Option<OneOrMany<DisplayFromStr>>")]
     pub language_ids: Option<Vec<i32>>,
 
+    #[serde_as(as = "Option<OneOrMany<DisplayFromStr>>")]
+    #[serde(default, rename = "tag_id", alias = "tag_id[]")]
+    pub tag_ids: Option<Vec<i32>>,
+
+    /// Also match entities voted with a subtag of `tag_id`
+    #[serde(default)]
+    pub include_subtags: bool,
+
     #[serde(default)]
     pub sort_field: Option<CorrectionSortField>,
 
@@ -49,6 +59,16 @@ impl SongFilter {
             select = Self::apply_language_filter(select, language_ids.clone());
         }
 
+        if let Some(tag_ids) = &self.tag_ids {
+            select = select.filter(tag_hierarchy::tagged_with(
+                song::Entity.table_name(),
+                song_tag_vote::Entity.table_name(),
+                song_tag_vote::Column::SongId.as_str(),
+                tag_ids,
+                self.include_subtags,
+            ));
+        }
+
         select
     }
 
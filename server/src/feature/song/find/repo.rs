@@ -1,11 +1,12 @@
 use std::collections::HashMap;
 
-use entity::enums::StorageBackend;
+use entity::enums::{EntityType, StorageBackend};
 use entity::sea_orm_active_enums::ReleaseImageType;
 use entity::song::Column::{Id, Title};
 use entity::{
     artist, image, release_image, song, song_artist, song_credit,
-    song_language, song_localized_title, song_lyrics,
+    song_language, song_localized_title, song_lyrics, song_source_track,
+    source_work, source_work_track,
 };
 use itertools::{Itertools, izip};
 use libfp::FunctorExt;
@@ -25,14 +26,15 @@ use crate::domain::artist::SimpleArtist;
 use crate::domain::credit_role::CreditRoleRef;
 use crate::domain::image::Image;
 use crate::domain::release::SimpleRelease;
-use crate::domain::shared::Language;
+use crate::domain::shared::{ExternalId, Language};
 use crate::domain::song::{LocalizedTitle, Song, SongCredit};
 use crate::domain::song_lyrics::SongLyrics;
+use crate::domain::source_work::{SimpleSourceWork, SourceTrackRef};
 use crate::infra::database::sea_orm::cache::LANGUAGE_CACHE;
-use crate::infra::database::sea_orm::utils;
+use crate::infra::database::sea_orm::{external_id, utils};
 use crate::shared::http::{CorrectionSortField, SortDirection};
 
-pub(super) async fn find_by_id<R>(
+pub(crate) async fn find_by_id<R>(
     repo: &R,
     id: i32,
 ) -> Result<Option<Song>, DbErr>
@@ -104,7 +106,7 @@ where
 }
 
 #[expect(clippy::too_many_lines)]
-async fn find_many_impl(
+pub(crate) async fn find_many_impl(
     select: sea_orm::Select<song::Entity>,
     db: &impl ConnectionTrait,
 ) -> Result<Vec<Song>, sea_orm::DbErr> {
@@ -120,6 +122,7 @@ async fn find_many_impl(
         localized_titles_list,
         song_releases_list,
         song_lyrics_list,
+        song_source_tracks_list,
     ) = try_join!(
         songs.load_many_to_many(artist::Entity, song_artist::Entity, db),
         songs.load_many(song_credit::Entity, db),
@@ -131,6 +134,11 @@ async fn find_many_impl(
             db,
         ),
         songs.load_many(song_lyrics::Entity, db),
+        songs.load_many_to_many(
+            source_work_track::Entity,
+            song_source_track::Entity,
+            db,
+        ),
     )?;
 
     let (song_credits_artist_ids, song_credits_role_ids): (Vec<_>, Vec<_>) =
@@ -141,10 +149,34 @@ async fn find_many_impl(
             })
             .unzip();
 
-    let (song_credits_artist_map, credit_roles_map, lang_cache) = try_join!(
+    let source_work_ids = song_source_tracks_list
+        .iter()
+        .flat_map(|tracks| tracks.iter().map(|t| t.source_work_id))
+        .unique()
+        .collect_vec();
+
+    let (
+        song_credits_artist_map,
+        credit_roles_map,
+        lang_cache,
+        source_works_map,
+        mut external_ids,
+        mut lyrics_external_ids,
+    ) = try_join!(
         load_credit_artists(&song_credits_artist_ids, db),
         load_credit_roles(&song_credits_role_ids, db),
         LANGUAGE_CACHE.get_or_init(db),
+        load_source_works(&source_work_ids, db),
+        external_id::find_by_entity_ids(
+            EntityType::Song,
+            songs.iter().map(|song| song.id),
+            db
+        ),
+        external_id::find_by_entity_ids(
+            EntityType::SongLyrics,
+            song_lyrics_list.iter().flatten().map(|lyrics| lyrics.id),
+            db
+        ),
     )?;
 
     let song_release_ids: Vec<_> = song_releases_list
@@ -164,6 +196,7 @@ async fn find_many_impl(
         localized_titles_list,
         song_releases_list,
         song_lyrics_list,
+        song_source_tracks_list,
     )
     .map(
         |(
@@ -174,6 +207,7 @@ async fn find_many_impl(
             localized_titles,
             song_releases,
             lyrics,
+            source_tracks,
         )| {
             let artists = song_artists.fmap_into();
 
@@ -212,7 +246,11 @@ async fn find_many_impl(
                 })
                 .collect();
 
-            let lyrics = build_song_lyrics(lyrics, lang_cache);
+            let lyrics =
+                build_song_lyrics(lyrics, lang_cache, &mut lyrics_external_ids);
+
+            let source_tracks =
+                build_source_tracks(source_tracks, &source_works_map);
 
             Song {
                 id: song_model.id,
@@ -221,8 +259,12 @@ async fn find_many_impl(
                 credits,
                 languages,
                 localized_titles,
+                external_ids: external_ids
+                    .remove(&song_model.id)
+                    .unwrap_or_default(),
                 releases,
                 lyrics,
+                source_tracks,
             }
         },
     )
@@ -320,6 +362,25 @@ async fn load_credit_artists(
         .collect())
 }
 
+async fn load_source_works(
+    source_work_ids: &[i32],
+    db: &impl ConnectionTrait,
+) -> Result<HashMap<i32, SimpleSourceWork>, sea_orm::DbErr> {
+    if source_work_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let source_works = source_work::Entity::find()
+        .filter(source_work::Column::Id.is_in(source_work_ids.iter().copied()))
+        .all(db)
+        .await?;
+
+    Ok(source_works
+        .into_iter()
+        .map(|source_work| (source_work.id, source_work.into()))
+        .collect())
+}
+
 fn build_song_credits(
     credits: Vec<song_credit::Model>,
     artist_map: &HashMap<i32, SimpleArtist>,
@@ -341,6 +402,7 @@ fn build_song_credits(
 fn build_song_lyrics(
     lyrics: Vec<song_lyrics::Model>,
     lang_cache: &HashMap<i32, Language>,
+    external_ids: &mut HashMap<i32, Vec<ExternalId>>,
 ) -> Vec<SongLyrics> {
     lyrics
         .into_iter()
@@ -356,11 +418,33 @@ fn build_song_lyrics(
                 content: lyric.content,
                 is_main: lyric.is_main,
                 language,
+                external_ids: external_ids
+                    .remove(&lyric.id)
+                    .unwrap_or_default(),
             }
         })
         .collect()
 }
 
+fn build_source_tracks(
+    tracks: Vec<source_work_track::Model>,
+    source_work_map: &HashMap<i32, SimpleSourceWork>,
+) -> Vec<SourceTrackRef> {
+    tracks
+        .into_iter()
+        .filter_map(|track| {
+            let source_work =
+                source_work_map.get(&track.source_work_id).cloned()?;
+
+            Some(SourceTrackRef {
+                id: track.id,
+                title: track.title,
+                source_work,
+            })
+        })
+        .collect()
+}
+
 async fn find_sorted_by_correction<R>(
     repo: &R,
     filter: SongFilter,
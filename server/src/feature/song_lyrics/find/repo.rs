@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 
+use entity::enums::EntityType;
 use entity::song_lyrics;
-use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use sea_orm::{ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Select};
 
 use crate::domain::Connection;
-use crate::domain::shared::Language;
+use crate::domain::shared::{ExternalId, Language};
 use crate::domain::song_lyrics::SongLyrics;
 
 #[derive(Clone, Debug)]
@@ -20,6 +21,7 @@ pub enum FindManyFilter {
     Songs { song_ids: Vec<i32> },
 }
 use crate::infra::database::sea_orm::cache::LANGUAGE_CACHE;
+use crate::infra::database::sea_orm::external_id;
 
 pub(super) async fn find_one<R>(
     repo: &R,
@@ -46,7 +48,13 @@ where
 
     if let Some(model) = model {
         let lang_cache = LANGUAGE_CACHE.get_or_init(repo.conn()).await?;
-        Ok(Some(map_song_lyrics(model, lang_cache)))
+        let mut external_ids = external_id::find_by_entity_ids(
+            EntityType::SongLyrics,
+            [model.id],
+            repo.conn(),
+        )
+        .await?;
+        Ok(Some(map_song_lyrics(model, lang_cache, &mut external_ids)))
     } else {
         Ok(None)
     }
@@ -72,26 +80,38 @@ where
         }
     };
 
-    let models = song_lyrics::Entity::find()
-        .filter(condition)
-        .all(repo.conn())
-        .await?;
+    find_many_impl(song_lyrics::Entity::find().filter(condition), repo.conn())
+        .await
+}
+
+pub(crate) async fn find_many_impl(
+    select: Select<song_lyrics::Entity>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<SongLyrics>, sea_orm::DbErr> {
+    let models = select.all(db).await?;
 
     if models.is_empty() {
         return Ok(vec![]);
     }
 
-    let lang_cache = LANGUAGE_CACHE.get_or_init(repo.conn()).await?;
+    let lang_cache = LANGUAGE_CACHE.get_or_init(db).await?;
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::SongLyrics,
+        models.iter().map(|model| model.id),
+        db,
+    )
+    .await?;
 
     Ok(models
         .into_iter()
-        .map(|model| map_song_lyrics(model, lang_cache))
+        .map(|model| map_song_lyrics(model, lang_cache, &mut external_ids))
         .collect())
 }
 
 fn map_song_lyrics(
     model: song_lyrics::Model,
     lang_cache: &HashMap<i32, Language>,
+    external_ids: &mut HashMap<i32, Vec<ExternalId>>,
 ) -> SongLyrics {
     let language = lang_cache
         .get(&model.language_id)
@@ -104,5 +124,6 @@ fn map_song_lyrics(
         content: model.content,
         is_main: model.is_main,
         language,
+        external_ids: external_ids.remove(&model.id).unwrap_or_default(),
     }
 }
@@ -0,0 +1,12 @@
+use utoipa_axum::router::OpenApiRouter;
+
+use crate::adapter::inbound::rest::state::ArcAppState;
+
+mod arrangement;
+pub mod find;
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    OpenApiRouter::new()
+        .merge(arrangement::router())
+        .merge(find::router())
+}
@@ -0,0 +1,82 @@
+use axum::extract::{Path, Query, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::Arrangement;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::domain::shared::Paginated;
+use crate::infra::error::Error;
+use crate::shared::http::PaginationQuery;
+
+const TAG: &str = "Source Work";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| {
+            r.routes(routes!(find_source_track_arrangements))
+                .routes(routes!(find_source_work_arrangements))
+        })
+        .finish()
+}
+
+data! {
+    DataOptionPaginatedArrangement, Option<Paginated<Arrangement>>
+}
+
+/// Songs arranging an original track, ordered by song id.
+/// The cursor is the last song id of the previous page.
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/source-work-track/{id}/arrangements",
+    params(
+        ("id" = i32, Path),
+        PaginationQuery,
+    ),
+    responses(
+        (status = 200, body = DataOptionPaginatedArrangement),
+        Error,
+    ),
+)]
+async fn find_source_track_arrangements(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Data<Option<Paginated<Arrangement>>>, Error> {
+    super::repo::find_by_track(&repo, id, pagination.cursor, pagination.limit())
+        .await
+        .bimap_into()
+}
+
+/// Songs arranging any track of a source work, ordered by song id.
+/// The cursor is the last song id of the previous page.
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/source-work/{id}/arrangements",
+    params(
+        ("id" = i32, Path),
+        PaginationQuery,
+    ),
+    responses(
+        (status = 200, body = DataOptionPaginatedArrangement),
+        Error,
+    ),
+)]
+async fn find_source_work_arrangements(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Data<Option<Paginated<Arrangement>>>, Error> {
+    super::repo::find_by_source_work(
+        &repo,
+        id,
+        pagination.cursor,
+        pagination.limit(),
+    )
+    .await
+    .bimap_into()
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::artist::SimpleArtist;
+use crate::domain::shared::DateWithPrecision;
+use crate::domain::song::SongRef;
+use crate::domain::source_work::SourceTrackRef;
+
+/// A song arranging one or more original tracks
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct Arrangement {
+    pub song: SongRef,
+    pub artists: Vec<SimpleArtist>,
+    pub releases: Vec<ArrangementRelease>,
+    /// Every original track the song is based on, including those of
+    /// other works in the case of medleys
+    pub source_tracks: Vec<SourceTrackRef>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ArrangementRelease {
+    pub id: i32,
+    pub title: String,
+    pub release_date: Option<DateWithPrecision>,
+}
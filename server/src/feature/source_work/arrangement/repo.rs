@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use entity::{
+    artist, release, release_track, song, song_artist, song_source_track,
+    source_work, source_work_track,
+};
+use itertools::{Itertools, izip};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, LoaderTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+use tokio::try_join;
+
+use super::model::{Arrangement, ArrangementRelease};
+use crate::domain::Connection;
+use crate::domain::shared::{DateWithPrecision, Paginated};
+use crate::domain::song::SongRef;
+use crate::domain::source_work::{SimpleSourceWork, SourceTrackRef};
+
+pub(super) async fn find_by_track<R>(
+    repo: &R,
+    track_id: i32,
+    cursor: Option<i32>,
+    limit: u32,
+) -> Result<Option<Paginated<Arrangement>>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let exists = source_work_track::Entity::find_by_id(track_id)
+        .count(db)
+        .await?
+        > 0;
+    if !exists {
+        return Ok(None);
+    }
+
+    find_page(db, &[track_id], cursor, limit).await.map(Some)
+}
+
+pub(super) async fn find_by_source_work<R>(
+    repo: &R,
+    source_work_id: i32,
+    cursor: Option<i32>,
+    limit: u32,
+) -> Result<Option<Paginated<Arrangement>>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let db = repo.conn();
+
+    let exists = source_work::Entity::find_by_id(source_work_id)
+        .count(db)
+        .await?
+        > 0;
+    if !exists {
+        return Ok(None);
+    }
+
+    let track_ids: Vec<i32> = source_work_track::Entity::find()
+        .select_only()
+        .column(source_work_track::Column::Id)
+        .filter(source_work_track::Column::SourceWorkId.eq(source_work_id))
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    if track_ids.is_empty() {
+        return Ok(Some(Paginated::nothing()));
+    }
+
+    find_page(db, &track_ids, cursor, limit).await.map(Some)
+}
+
+async fn find_page(
+    db: &impl ConnectionTrait,
+    track_ids: &[i32],
+    cursor: Option<i32>,
+    limit: u32,
+) -> Result<Paginated<Arrangement>, DbErr> {
+    let mut songs = song::Entity::find()
+        .filter(
+            song::Column::Id.in_subquery(
+                song_source_track::Entity::find()
+                    .select_only()
+                    .column(song_source_track::Column::SongId)
+                    .filter(
+                        song_source_track::Column::SourceTrackId
+                            .is_in(track_ids.iter().copied()),
+                    )
+                    .into_query(),
+            ),
+        )
+        .apply_if(cursor, |select, cursor| {
+            select.filter(song::Column::Id.gt(cursor))
+        })
+        .order_by_asc(song::Column::Id)
+        .limit(u64::from(limit) + 1)
+        .all(db)
+        .await?;
+
+    let next_cursor = if songs.len() > limit as usize {
+        songs.pop();
+        songs.last().map(|song| song.id)
+    } else {
+        None
+    };
+
+    if songs.is_empty() {
+        return Ok(Paginated::nothing());
+    }
+
+    let (artists_list, releases_list, source_tracks_list) = try_join!(
+        songs.load_many_to_many(artist::Entity, song_artist::Entity, db),
+        songs.load_many_to_many(release::Entity, release_track::Entity, db),
+        songs.load_many_to_many(
+            source_work_track::Entity,
+            song_source_track::Entity,
+            db,
+        ),
+    )?;
+
+    let source_works: HashMap<i32, SimpleSourceWork> =
+        source_work::Entity::find()
+            .filter(
+                source_work::Column::Id.is_in(
+                    source_tracks_list
+                        .iter()
+                        .flatten()
+                        .map(|track| track.source_work_id)
+                        .unique(),
+                ),
+            )
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|work| (work.id, work.into()))
+            .collect();
+
+    let items = izip!(songs, artists_list, releases_list, source_tracks_list)
+        .map(|(song, artists, releases, source_tracks)| Arrangement {
+            song: SongRef {
+                id: song.id,
+                title: song.title,
+            },
+            artists: artists.into_iter().map_into().collect(),
+            releases: releases
+                .into_iter()
+                .unique_by(|release| release.id)
+                .sorted_by_key(|release| (release.release_date, release.id))
+                .map(|release| ArrangementRelease {
+                    id: release.id,
+                    title: release.title,
+                    release_date: DateWithPrecision::from_option(
+                        release.release_date,
+                        release.release_date_precision,
+                    ),
+                })
+                .collect(),
+            source_tracks: source_tracks
+                .into_iter()
+                .filter_map(|track| {
+                    Some(SourceTrackRef {
+                        source_work: source_works
+                            .get(&track.source_work_id)
+                            .cloned()?,
+                        id: track.id,
+                        title: track.title,
+                    })
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(Paginated { items, next_cursor })
+}
@@ -0,0 +1,4 @@
+mod http;
+pub mod repo;
+
+pub use http::router;
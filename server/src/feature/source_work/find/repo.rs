@@ -0,0 +1,104 @@
+use entity::enums::EntityType;
+use entity::{source_work, source_work_track};
+use itertools::Itertools;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, LoaderTrait, QueryFilter,
+    QueryOrder, Select,
+};
+use sea_query::extension::postgres::PgBinOper::{
+    Similarity, SimilarityDistance,
+};
+use sea_query::{ExprTrait, Func};
+
+use crate::domain::Connection;
+use crate::domain::shared::DateWithPrecision;
+use crate::domain::source_work::{SourceWork, SourceWorkTrack};
+use crate::infra::database::sea_orm::external_id;
+
+pub(super) async fn find_by_id<R>(
+    repo: &R,
+    id: i32,
+) -> Result<Option<SourceWork>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let select =
+        source_work::Entity::find().filter(source_work::Column::Id.eq(id));
+
+    find_many_impl(select, repo.conn())
+        .await
+        .map(|mut works| works.pop())
+}
+
+pub(super) async fn find_by_keyword<R>(
+    repo: &R,
+    keyword: &str,
+) -> Result<Vec<SourceWork>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let search_term = Func::lower(keyword);
+
+    let select = source_work::Entity::find()
+        .filter(
+            Func::lower(source_work::Column::Title.into_expr())
+                .binary(Similarity, search_term.clone()),
+        )
+        .order_by_asc(
+            Func::lower(source_work::Column::Title.into_expr())
+                .binary(SimilarityDistance, search_term),
+        );
+
+    find_many_impl(select, repo.conn()).await
+}
+
+async fn find_many_impl(
+    select: Select<source_work::Entity>,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<SourceWork>, DbErr> {
+    let works = select.all(db).await?;
+    if works.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let tracks = works
+        .load_many(
+            source_work_track::Entity::find()
+                .order_by_asc(source_work_track::Column::Id),
+            db,
+        )
+        .await?;
+
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::SourceWork,
+        works.iter().map(|work| work.id),
+        db,
+    )
+    .await?;
+
+    Ok(works
+        .into_iter()
+        .zip(tracks)
+        .map(|(work, tracks)| SourceWork {
+            id: work.id,
+            title: work.title,
+            kind: work.kind,
+            release_date: DateWithPrecision::from_option(
+                work.release_date,
+                work.release_date_precision,
+            ),
+            description: work.description,
+            external_ids: external_ids.remove(&work.id).unwrap_or_default(),
+            tracks: tracks
+                .into_iter()
+                .map(|track| SourceWorkTrack {
+                    id: track.id,
+                    track_number: track.track_number,
+                    title: track.title,
+                })
+                .collect_vec(),
+        })
+        .collect())
+}
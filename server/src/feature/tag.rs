@@ -2,8 +2,11 @@ use utoipa_axum::router::OpenApiRouter;
 
 use crate::adapter::inbound::rest::state::ArcAppState;
 
-mod find;
+pub mod find;
+mod tree;
 
 pub fn router() -> OpenApiRouter<ArcAppState> {
-    OpenApiRouter::new().merge(find::router())
+    OpenApiRouter::new()
+        .merge(find::router())
+        .merge(tree::router())
 }
@@ -9,7 +9,7 @@ use super::{PaginationQuery, TagFilter};
 use crate::adapter::inbound::rest::api_response::Data;
 use crate::adapter::inbound::rest::state::{self, ArcAppState};
 use crate::adapter::inbound::rest::{AppRouter, data};
-use crate::domain::shared::Paginated;
+use crate::domain::shared::{Paginated, SearchHit};
 use crate::domain::tag::Tag;
 use crate::infra::error::Error;
 
@@ -20,6 +20,7 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
         .with_public(|r| {
             r.routes(routes!(find_tag_by_id))
                 .routes(routes!(find_tag_by_keyword))
+                .routes(routes!(search_tag_description))
                 .routes(routes!(explore_tag))
         })
         .finish()
@@ -28,6 +29,7 @@ pub fn router() -> OpenApiRouter<ArcAppState> {
 data! {
     DataOptionTag, Option<Tag>
     DataVecTag, Vec<Tag>
+    DataVecSearchHitTag, Vec<SearchHit<Tag>>
     DataPaginatedTag, Paginated<Tag>
 }
 
@@ -69,6 +71,26 @@ async fn find_tag_by_keyword(
         .bimap_into()
 }
 
+/// Full-text search on the short description and description, best
+/// matches first with the matched parts highlighted
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/tag/search",
+    params(KwArgs),
+    responses(
+        (status = 200, body = DataVecSearchHitTag),
+    ),
+)]
+async fn search_tag_description(
+    State(repo): State<state::SeaOrmRepository>,
+    Query(query): Query<KwArgs>,
+) -> Result<Data<Vec<SearchHit<Tag>>>, Error> {
+    super::repo::find_by_description(&repo, &query.keyword)
+        .await
+        .bimap_into()
+}
+
 #[utoipa::path(
     get,
     tag = TAG,
@@ -1,6 +1,6 @@
 mod filter;
 mod http;
-mod repo;
+pub mod repo;
 
 pub use filter::{PaginationQuery, TagFilter};
 pub use http::router;
@@ -1,10 +1,11 @@
 use std::collections::{HashMap, HashSet};
 
+use entity::enums::EntityType;
 use entity::tag::Column::Name;
 use entity::{tag, tag_alternative_name, tag_relation};
 use sea_orm::{
-    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, LoaderTrait, QueryFilter,
-    QueryOrder,
+    ColumnTrait, ConnectionTrait, DbErr, EntityName, EntityTrait, LoaderTrait,
+    QueryFilter, QueryOrder,
 };
 use sea_query::extension::postgres::PgBinOper::{
     Similarity, SimilarityDistance,
@@ -12,8 +13,9 @@ use sea_query::extension::postgres::PgBinOper::{
 use sea_query::{ExprTrait, Func};
 
 use crate::domain::Connection;
+use crate::domain::shared::SearchHit;
 use crate::domain::tag::{AlternativeName, Tag, TagRef, TagRelation};
-use crate::infra::database::sea_orm::utils;
+use crate::infra::database::sea_orm::{description_search, external_id, utils};
 
 pub(super) async fn find_by_id<R>(
     repo: &R,
@@ -53,6 +55,45 @@ where
     find_many_impl(select, repo.conn()).await
 }
 
+/// Search results are not paginated, only the best matches are returned
+const DESCRIPTION_SEARCH_LIMIT: u64 = 50;
+
+pub(super) async fn find_by_description<R>(
+    repo: &R,
+    query: &str,
+) -> Result<Vec<SearchHit<Tag>>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let (ids, mut highlights) = description_search::find_ids(
+        repo.conn(),
+        tag::Entity.table_name(),
+        query,
+        DESCRIPTION_SEARCH_LIMIT,
+    )
+    .await?;
+
+    if ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let select = tag::Entity::find().filter(tag::Column::Id.is_in(ids.clone()));
+    let tags = utils::sort_by_id_list(
+        find_many_impl(select, repo.conn()).await?,
+        &ids,
+        |tag| tag.id,
+    );
+
+    Ok(tags
+        .into_iter()
+        .map(|tag| SearchHit {
+            highlight: highlights.remove(&tag.id),
+            item: tag,
+        })
+        .collect())
+}
+
 pub(super) async fn find_by_filter<R>(
     repo: &R,
     filter: super::TagFilter,
@@ -135,13 +176,19 @@ where
     Ok(utils::paginate_by_id(tags, &pagination, |tag| tag.id))
 }
 
-async fn find_many_impl(
+pub(crate) async fn find_many_impl(
     select: sea_orm::Select<tag::Entity>,
     db: &impl ConnectionTrait,
 ) -> Result<Vec<Tag>, sea_orm::DbErr> {
     let tags = select.all(db).await?;
     let alt_names = tags.load_many(tag_alternative_name::Entity, db).await?;
     let tag_relations = load_tag_relations(&tags, db).await?;
+    let mut external_ids = external_id::find_by_entity_ids(
+        EntityType::Tag,
+        tags.iter().map(|tag| tag.id),
+        db,
+    )
+    .await?;
 
     Ok(itertools::izip!(tags, alt_names, tag_relations)
         .map(|(tag, alt_names, relations)| Tag {
@@ -157,6 +204,7 @@ async fn find_many_impl(
                     name: m.name,
                 })
                 .collect(),
+            external_ids: external_ids.remove(&tag.id).unwrap_or_default(),
             relations,
         })
         .collect())
@@ -0,0 +1,36 @@
+use axum::extract::{Path, State};
+use libfp::BifunctorExt;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+use super::model::TagTree;
+use crate::adapter::inbound::rest::api_response::Data;
+use crate::adapter::inbound::rest::state::{self, ArcAppState};
+use crate::adapter::inbound::rest::{AppRouter, data};
+use crate::infra::error::Error;
+
+const TAG: &str = "Tag";
+
+pub fn router() -> OpenApiRouter<ArcAppState> {
+    AppRouter::new()
+        .with_public(|r| r.routes(routes!(find_tag_tree)))
+        .finish()
+}
+
+data!(DataOptionTagTree, Option<TagTree>);
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/tag/{id}/tree",
+    responses(
+        (status = 200, body = DataOptionTagTree),
+        Error,
+    ),
+)]
+async fn find_tag_tree(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+) -> Result<Data<Option<TagTree>>, Error> {
+    super::repo::find_tree(&repo, id).await.bimap_into()
+}
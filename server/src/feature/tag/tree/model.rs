@@ -0,0 +1,51 @@
+use entity::sea_orm_active_enums::{TagRelationType, TagType};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::tag::TagRef;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TagTree {
+    /// Parents of the tag, their parents and so on
+    pub ancestors: Vec<TagTreeNode>,
+    /// Children of the tag, their children and so on
+    pub descendants: Vec<TagTreeNode>,
+}
+
+/// A tag reached while walking the hierarchy. A tag reachable through
+/// several relations appears once per relation.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TagTreeNode {
+    pub tag: TagRef,
+    /// The tag this one was reached from, one step closer to the root of
+    /// the traversal
+    pub reached_from: i32,
+    pub relation_type: TagRelationType,
+    /// Number of relations between this tag and the root, starting at 1
+    pub depth: i32,
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub(super) struct TagTreeRow {
+    pub id: i32,
+    pub name: String,
+    pub r#type: TagType,
+    pub reached_from: i32,
+    pub relation_type: TagRelationType,
+    pub depth: i32,
+}
+
+impl From<TagTreeRow> for TagTreeNode {
+    fn from(row: TagTreeRow) -> Self {
+        Self {
+            tag: TagRef {
+                id: row.id,
+                name: row.name,
+                r#type: row.r#type,
+            },
+            reached_from: row.reached_from,
+            relation_type: row.relation_type,
+            depth: row.depth,
+        }
+    }
+}
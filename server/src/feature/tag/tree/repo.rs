@@ -0,0 +1,54 @@
+use entity::tag;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter,
+};
+
+use super::model::{TagTree, TagTreeNode, TagTreeRow};
+use crate::domain::Connection;
+use crate::infra::database::sea_orm::hierarchy::{self, Direction};
+
+pub(super) async fn find_tree<R>(
+    repo: &R,
+    id: i32,
+) -> Result<Option<TagTree>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let exists = tag::Entity::find()
+        .filter(tag::Column::Id.eq(id))
+        .count(repo.conn())
+        .await?
+        > 0;
+
+    if !exists {
+        return Ok(None);
+    }
+
+    let ancestors = walk(repo.conn(), id, Direction::Ancestors).await?;
+    let descendants = walk(repo.conn(), id, Direction::Descendants).await?;
+
+    Ok(Some(TagTree {
+        ancestors,
+        descendants,
+    }))
+}
+
+async fn walk(
+    db: &impl ConnectionTrait,
+    id: i32,
+    direction: Direction,
+) -> Result<Vec<TagTreeNode>, DbErr> {
+    Ok(hierarchy::TAG
+        .walk::<TagTreeRow>(
+            db,
+            id,
+            direction,
+            "tag.name, tag.type::text AS type",
+        )
+        .await?
+        .into_iter()
+        .map(Into::into)
+        .collect())
+}
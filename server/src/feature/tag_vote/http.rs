@@ -5,7 +5,9 @@ use utoipa::{IntoParams, ToSchema};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_axum::routes;
 
-use super::model::{EntityType, Score, TagAggregate};
+use super::model::{
+    EntityType, Score, TagAggregate, TagVoter, TaggedEntity, UserTagVote,
+};
 use super::{Error, repo};
 use crate::adapter::inbound::rest::api_response::Data;
 use crate::adapter::inbound::rest::state::{self, ArcAppState, AuthSession};
@@ -15,7 +17,15 @@ use crate::shared::http::PaginationQuery;
 
 const TAG: &str = "TagVote";
 
-data!(DataPaginatedTagAggregate, Paginated<TagAggregate>);
+data! {
+    DataPaginatedTagAggregate, Paginated<TagAggregate>
+    DataPaginatedUserTagVote, Paginated<UserTagVote>
+    DataPaginatedTaggedEntity, Paginated<TaggedEntity>
+    DataVecTagVoter, Vec<TagVoter>
+}
+
+const DEFAULT_TOP_VOTERS: u32 = 10;
+const MAX_TOP_VOTERS: u32 = 50;
 
 #[derive(Deserialize, IntoParams)]
 struct TagVotePath {
@@ -26,7 +36,12 @@ struct TagVotePath {
 
 pub fn router() -> OpenApiRouter<ArcAppState> {
     AppRouter::new()
-        .with_public(|r| r.routes(routes!(get_tags)))
+        .with_public(|r| {
+            r.routes(routes!(get_tags))
+                .routes(routes!(get_user_votes))
+                .routes(routes!(get_tagged_entities))
+                .routes(routes!(get_top_voters))
+        })
         .with_private(|r| {
             r.routes(routes!(vote_tag)).routes(routes!(delete_vote))
         })
@@ -113,3 +128,124 @@ async fn get_tags(
     .await?;
     Ok(tags.into())
 }
+
+#[derive(Deserialize, IntoParams)]
+struct UserVotesQuery {
+    #[param(inline)]
+    entity_type: Option<EntityType>,
+}
+
+/// Votes cast by a user, newest first. The cursor is an offset.
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/user/{name}/tag-votes",
+    params(
+        ("name" = String, Path),
+        UserVotesQuery,
+        PaginationQuery,
+    ),
+    responses(
+        (status = 200, body = DataPaginatedUserTagVote),
+        (status = 404, description = "User not found"),
+    ),
+)]
+async fn get_user_votes(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(name): Path<String>,
+    Query(query): Query<UserVotesQuery>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Data<Paginated<UserTagVote>>, Error> {
+    let user_id = repo::find_user_id_by_name(&repo, &name)
+        .await?
+        .ok_or(Error::UserNotFound(name))?;
+
+    let votes = repo::get_user_votes(
+        &repo,
+        user_id,
+        query.entity_type,
+        pagination.cursor.unwrap_or_default(),
+        pagination.limit(),
+    )
+    .await?;
+    Ok(votes.into())
+}
+
+#[derive(Deserialize, IntoParams)]
+struct TaggedEntitiesQuery {
+    #[param(inline)]
+    entity_type: EntityType,
+}
+
+/// Entities ranked by the relevance of the tag. The cursor is an offset.
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/tag/{id}/entities",
+    params(
+        ("id" = i32, Path),
+        TaggedEntitiesQuery,
+        PaginationQuery,
+    ),
+    responses(
+        (status = 200, body = DataPaginatedTaggedEntity),
+        (status = 404, description = "Tag not found"),
+    ),
+)]
+async fn get_tagged_entities(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(query): Query<TaggedEntitiesQuery>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Data<Paginated<TaggedEntity>>, Error> {
+    if !repo::tag_exists(&repo, id).await? {
+        return Err(Error::TagNotFound(id));
+    }
+
+    let entities = repo::get_tagged_entities(
+        &repo,
+        id,
+        query.entity_type,
+        pagination.cursor.unwrap_or_default(),
+        pagination.limit(),
+    )
+    .await?;
+    Ok(entities.into())
+}
+
+#[derive(Deserialize, IntoParams)]
+struct TopVotersQuery {
+    #[param(minimum = 1, maximum = 50)]
+    limit: Option<u32>,
+}
+
+#[utoipa::path(
+    get,
+    tag = TAG,
+    path = "/tag/{id}/top-voters",
+    params(
+        ("id" = i32, Path),
+        TopVotersQuery,
+    ),
+    responses(
+        (status = 200, body = DataVecTagVoter),
+        (status = 404, description = "Tag not found"),
+    ),
+)]
+async fn get_top_voters(
+    State(repo): State<state::SeaOrmRepository>,
+    Path(id): Path<i32>,
+    Query(query): Query<TopVotersQuery>,
+) -> Result<Data<Vec<TagVoter>>, Error> {
+    if !repo::tag_exists(&repo, id).await? {
+        return Err(Error::TagNotFound(id));
+    }
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_TOP_VOTERS)
+        .clamp(1, MAX_TOP_VOTERS);
+
+    let voters = repo::get_top_voters(&repo, id, limit).await?;
+    Ok(voters.into())
+}
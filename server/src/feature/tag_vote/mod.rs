@@ -14,6 +14,7 @@ pub enum Error {
     InvalidScore(InvalidScore),
     EntityNotFound(&'static str, i32),
     TagNotFound(i32),
+    UserNotFound(String),
     Db(sea_orm::DbErr),
 }
 
@@ -25,6 +26,7 @@ impl std::fmt::Display for Error {
                 write!(f, "{entity} with id {id} not found")
             }
             Self::TagNotFound(id) => write!(f, "Tag with id {id} not found"),
+            Self::UserNotFound(name) => write!(f, "User {name} not found"),
             Self::Db(e) => write!(f, "{e}"),
         }
     }
@@ -34,9 +36,9 @@ impl IntoResponse for Error {
     fn into_response(self) -> Response {
         let status = match &self {
             Self::InvalidScore(_) => StatusCode::BAD_REQUEST,
-            Self::EntityNotFound(_, _) | Self::TagNotFound(_) => {
-                StatusCode::NOT_FOUND
-            }
+            Self::EntityNotFound(_, _)
+            | Self::TagNotFound(_)
+            | Self::UserNotFound(_) => StatusCode::NOT_FOUND,
             Self::Db(_) => StatusCode::INTERNAL_SERVER_ERROR,
         };
         ApiError::new((self.to_string(), status)).into_response()
@@ -1,21 +1,51 @@
+use chrono::{DateTime, FixedOffset};
 use sea_orm::EntityName;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-#[derive(Clone, Copy, Deserialize, ToSchema)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, ToSchema,
+)]
 #[serde(rename_all = "kebab-case")]
 pub enum EntityType {
     Artist,
+    Event,
+    Label,
     Release,
     Song,
 }
 
 impl EntityType {
+    pub const ALL: [Self; 5] = [
+        Self::Artist,
+        Self::Event,
+        Self::Label,
+        Self::Release,
+        Self::Song,
+    ];
+
+    /// Same as the serialized name
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Artist => "artist",
+            Self::Event => "event",
+            Self::Label => "label",
+            Self::Release => "release",
+            Self::Song => "song",
+        }
+    }
+
+    pub fn from_name(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|x| x.as_str() == value)
+    }
+
     pub fn vote_table_name(self) -> &'static str {
         match self {
             Self::Release => entity::release_tag_vote::Entity.table_name(),
             Self::Song => entity::song_tag_vote::Entity.table_name(),
             Self::Artist => entity::artist_tag_vote::Entity.table_name(),
+            Self::Event => entity::event_tag_vote::Entity.table_name(),
+            Self::Label => entity::label_tag_vote::Entity.table_name(),
         }
     }
 
@@ -27,6 +57,8 @@ impl EntityType {
             }
             Self::Song => entity::song_tag_vote::Column::SongId.as_str(),
             Self::Artist => entity::artist_tag_vote::Column::ArtistId.as_str(),
+            Self::Event => entity::event_tag_vote::Column::EventId.as_str(),
+            Self::Label => entity::label_tag_vote::Column::LabelId.as_str(),
         }
     }
 
@@ -35,6 +67,19 @@ impl EntityType {
             Self::Release => entity::release::Entity.table_name(),
             Self::Song => entity::song::Entity.table_name(),
             Self::Artist => entity::artist::Entity.table_name(),
+            Self::Event => entity::event::Entity.table_name(),
+            Self::Label => entity::label::Entity.table_name(),
+        }
+    }
+
+    pub fn entity_name_column(self) -> &'static str {
+        use sea_orm::IdenStatic;
+        match self {
+            Self::Release => entity::release::Column::Title.as_str(),
+            Self::Song => entity::song::Column::Title.as_str(),
+            Self::Artist => entity::artist::Column::Name.as_str(),
+            Self::Event => entity::event::Column::Name.as_str(),
+            Self::Label => entity::label::Column::Name.as_str(),
         }
     }
 
@@ -43,6 +88,8 @@ impl EntityType {
             Self::Release => "Release",
             Self::Song => "Song",
             Self::Artist => "Artist",
+            Self::Event => "Event",
+            Self::Label => "Label",
         }
     }
 }
@@ -119,3 +166,57 @@ impl sea_query::Iden for TagAggregateFieldName {
         s.write_str(self.as_str()).unwrap();
     }
 }
+
+/// A vote cast by a user
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UserTagVote {
+    pub entity_type: EntityType,
+    pub entity_id: i32,
+    pub entity_name: String,
+    pub tag_id: i32,
+    pub tag_name: String,
+    pub score: i16,
+    pub voted_at: DateTime<FixedOffset>,
+}
+
+#[derive(sea_orm::FromQueryResult)]
+pub struct UserTagVoteRow {
+    pub entity_type: String,
+    pub entity_id: i32,
+    pub entity_name: String,
+    pub tag_id: i32,
+    pub tag_name: String,
+    pub score: i16,
+    pub voted_at: DateTime<FixedOffset>,
+}
+
+impl UserTagVoteRow {
+    pub fn into_vote(self) -> Option<UserTagVote> {
+        Some(UserTagVote {
+            entity_type: EntityType::from_name(&self.entity_type)?,
+            entity_id: self.entity_id,
+            entity_name: self.entity_name,
+            tag_id: self.tag_id,
+            tag_name: self.tag_name,
+            score: self.score,
+            voted_at: self.voted_at,
+        })
+    }
+}
+
+/// An entity ranked by the relevance of a tag
+#[derive(Debug, Clone, Serialize, ToSchema, sea_orm::FromQueryResult)]
+pub struct TaggedEntity {
+    pub id: i32,
+    pub name: String,
+    pub count: i64,
+    pub relevance: f64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema, sea_orm::FromQueryResult)]
+pub struct TagVoter {
+    pub user_id: i32,
+    pub name: String,
+    pub vote_count: i64,
+    pub last_voted_at: DateTime<FixedOffset>,
+}
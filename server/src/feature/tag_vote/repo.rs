@@ -2,9 +2,13 @@ use sea_orm::{ConnectionTrait, DbErr, EntityTrait, FromQueryResult};
 use sea_query::{Alias, Expr, Order, Query, SimpleExpr};
 
 use super::Error;
-use super::model::{EntityType, Score, TagAggregate, TagAggregateFieldName};
+use super::model::{
+    EntityType, Score, TagAggregate, TagAggregateFieldName, TagVoter,
+    TaggedEntity, UserTagVote, UserTagVoteRow,
+};
 use crate::domain::Connection;
 use crate::domain::shared::Paginated;
+use crate::infra::database::sea_orm::utils::paginate_by_offset;
 
 pub async fn entity_exists<R>(
     repo: &R,
@@ -217,3 +221,286 @@ where
 
     Ok(Paginated { items, next_cursor })
 }
+
+pub async fn find_user_id_by_name<R>(
+    repo: &R,
+    name: &str,
+) -> Result<Option<i32>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    use sea_orm::{ColumnTrait, QueryFilter};
+
+    Ok(entity::user::Entity::find()
+        .filter(entity::user::Column::Name.eq(name))
+        .one(repo.conn())
+        .await?
+        .map(|user| user.id))
+}
+
+/// Votes of a user, newest first
+///
+/// Votes have no single id, so the cursor is an offset
+pub async fn get_user_votes<R>(
+    repo: &R,
+    user_id: i32,
+    entity_type: Option<EntityType>,
+    offset: i32,
+    limit: u32,
+) -> Result<Paginated<UserTagVote>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let stmt = sea_orm::Statement::from_sql_and_values(
+        repo.conn().get_database_backend(),
+        user_votes_sql(entity_type),
+        [
+            user_id.into(),
+            (i64::from(limit) + 1).into(),
+            i64::from(offset.max(0)).into(),
+        ],
+    );
+
+    let rows = UserTagVoteRow::find_by_statement(stmt)
+        .all(repo.conn())
+        .await?;
+
+    Ok(paginate_by_offset(
+        rows.into_iter()
+            .filter_map(UserTagVoteRow::into_vote)
+            .collect(),
+        offset,
+        limit,
+    ))
+}
+
+/// `$1` is the user, `$2` and `$3` the limit and offset
+fn user_votes_sql(entity_type: Option<EntityType>) -> String {
+    let selects = entity_type
+        .map_or_else(|| EntityType::ALL.to_vec(), |x| vec![x])
+        .into_iter()
+        .map(|entity_type| {
+            let kind = entity_type.as_str();
+            let vote_table = entity_type.vote_table_name();
+            let entity_table = entity_type.entity_table_name();
+            let entity_id_col = entity_type.entity_id_column();
+            let entity_name_col = entity_type.entity_name_column();
+            format!(
+                r"
+                SELECT
+                    '{kind}' AS entity_type,
+                    e.id AS entity_id,
+                    e.{entity_name_col} AS entity_name,
+                    t.id AS tag_id,
+                    t.name AS tag_name,
+                    v.score,
+                    v.voted_at
+                FROM {vote_table} v
+                JOIN {entity_table} e ON e.id = v.{entity_id_col}
+                JOIN tag t ON t.id = v.tag_id
+                WHERE v.user_id = $1"
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\nUNION ALL");
+
+    format!(
+        r"
+        SELECT * FROM ({selects}
+        ) AS vote
+        ORDER BY voted_at DESC, entity_type, entity_id, tag_id
+        LIMIT $2 OFFSET $3
+        "
+    )
+}
+
+/// Entities with at least one positive vote for the tag, most relevant first
+///
+/// The cursor is an offset, since the order is not by id
+pub async fn get_tagged_entities<R>(
+    repo: &R,
+    tag_id: i32,
+    entity_type: EntityType,
+    offset: i32,
+    limit: u32,
+) -> Result<Paginated<TaggedEntity>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let stmt = sea_orm::Statement::from_sql_and_values(
+        repo.conn().get_database_backend(),
+        tagged_entities_sql(entity_type),
+        [
+            tag_id.into(),
+            (i64::from(limit) + 1).into(),
+            i64::from(offset.max(0)).into(),
+        ],
+    );
+
+    let items = TaggedEntity::find_by_statement(stmt)
+        .all(repo.conn())
+        .await?;
+
+    Ok(paginate_by_offset(items, offset, limit))
+}
+
+/// `$1` is the tag, `$2` and `$3` the limit and offset
+fn tagged_entities_sql(entity_type: EntityType) -> String {
+    let vote_table = entity_type.vote_table_name();
+    let entity_table = entity_type.entity_table_name();
+    let entity_id_col = entity_type.entity_id_column();
+    let entity_name_col = entity_type.entity_name_column();
+
+    // Same relevance as `get_tags`
+    format!(
+        r"
+        SELECT
+            e.id,
+            e.{entity_name_col} AS name,
+            COUNT(*) AS count,
+            CAST(SUM(v.score) AS FLOAT) / COUNT(*) FILTER (WHERE v.score > 0)
+                AS relevance
+        FROM {vote_table} v
+        JOIN {entity_table} e ON e.id = v.{entity_id_col}
+        WHERE v.tag_id = $1
+        GROUP BY e.id
+        HAVING COUNT(*) FILTER (WHERE v.score > 0) > 0
+        ORDER BY relevance DESC, count DESC, e.id
+        LIMIT $2 OFFSET $3
+        "
+    )
+}
+
+/// Users with the most votes on the tag, across all entity types
+pub async fn get_top_voters<R>(
+    repo: &R,
+    tag_id: i32,
+    limit: u32,
+) -> Result<Vec<TagVoter>, DbErr>
+where
+    R: Connection,
+    R::Conn: ConnectionTrait,
+{
+    let stmt = sea_orm::Statement::from_sql_and_values(
+        repo.conn().get_database_backend(),
+        top_voters_sql(),
+        [tag_id.into(), i64::from(limit).into()],
+    );
+
+    TagVoter::find_by_statement(stmt).all(repo.conn()).await
+}
+
+/// `$1` is the tag, `$2` the limit
+fn top_voters_sql() -> String {
+    let votes = EntityType::ALL
+        .into_iter()
+        .map(|entity_type| {
+            format!(
+                "SELECT user_id, voted_at FROM {} WHERE tag_id = $1",
+                entity_type.vote_table_name()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n            UNION ALL\n            ");
+
+    format!(
+        r#"
+        SELECT
+            u.id AS user_id,
+            u.name,
+            COUNT(*) AS vote_count,
+            MAX(vote.voted_at) AS last_voted_at
+        FROM (
+            {votes}
+        ) AS vote
+        JOIN "user" u ON u.id = vote.user_id
+        GROUP BY u.id
+        ORDER BY vote_count DESC, last_voted_at DESC, u.id
+        LIMIT $2
+        "#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::squash_sql;
+
+    #[test]
+    fn user_votes_of_every_entity_type() {
+        let sql = squash_sql(&user_votes_sql(None));
+
+        for entity_type in EntityType::ALL {
+            let kind = entity_type.as_str();
+            assert!(
+                sql.contains(&format!(
+                    "'{kind}' AS entity_type, e.id AS entity_id, e.{} AS entity_name",
+                    entity_type.entity_name_column()
+                )),
+                "{kind}"
+            );
+            assert!(sql.contains(&format!(
+                "FROM {} v JOIN {} e ON e.id = v.{}",
+                entity_type.vote_table_name(),
+                entity_type.entity_table_name(),
+                entity_type.entity_id_column()
+            )));
+        }
+        assert_eq!(sql.matches("UNION ALL").count(), EntityType::ALL.len() - 1);
+        assert_eq!(sql.matches("WHERE v.user_id = $1").count(), 5);
+        assert!(sql.ends_with(
+            "ORDER BY voted_at DESC, entity_type, entity_id, tag_id LIMIT $2 OFFSET $3"
+        ));
+    }
+
+    #[test]
+    fn user_votes_of_one_entity_type() {
+        let sql = squash_sql(&user_votes_sql(Some(EntityType::Release)));
+
+        assert!(sql.contains("e.title AS entity_name"));
+        assert!(sql.contains(
+            "FROM release_tag_vote v JOIN release e ON e.id = v.release_id"
+        ));
+        assert!(!sql.contains("UNION"));
+    }
+
+    #[test]
+    fn tagged_entities_ranked_by_relevance() {
+        let sql = squash_sql(&tagged_entities_sql(EntityType::Artist));
+
+        assert!(sql.contains(
+            "CAST(SUM(v.score) AS FLOAT) / COUNT(*) FILTER (WHERE v.score > 0) AS relevance"
+        ));
+        assert!(sql.contains(
+            "FROM artist_tag_vote v JOIN artist e ON e.id = v.artist_id WHERE v.tag_id = $1"
+        ));
+        // Entities only downvoted for the tag are not tagged with it
+        assert!(sql.contains(
+            "GROUP BY e.id HAVING COUNT(*) FILTER (WHERE v.score > 0) > 0"
+        ));
+        assert!(sql.ends_with(
+            "ORDER BY relevance DESC, count DESC, e.id LIMIT $2 OFFSET $3"
+        ));
+    }
+
+    #[test]
+    fn top_voters_count_votes_on_every_entity_type() {
+        let sql = squash_sql(&top_voters_sql());
+
+        for entity_type in EntityType::ALL {
+            assert!(sql.contains(&format!(
+                "SELECT user_id, voted_at FROM {} WHERE tag_id = $1",
+                entity_type.vote_table_name()
+            )));
+        }
+        assert!(sql.contains(
+            "COUNT(*) AS vote_count, MAX(vote.voted_at) AS last_voted_at"
+        ));
+        assert!(sql.ends_with(
+            "GROUP BY u.id ORDER BY vote_count DESC, last_voted_at DESC, u.id LIMIT $2"
+        ));
+    }
+}
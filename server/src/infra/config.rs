@@ -1,6 +1,7 @@
 use flow::Pipe;
 use nestify::nest;
 use serde::Deserialize;
+use url::Url;
 
 nest! {
     #[derive(Clone, Deserialize)]*
@@ -9,6 +10,14 @@ nest! {
         pub redis_url: String,
         pub app: pub struct App {
             pub port: u16,
+            /// Base of absolute links to the site, e.g. in the sitemap
+            pub public_url: Url,
+        },
+        pub dump: pub struct Dump {
+            /// Hours between scheduled dumps, 0 disables them
+            pub interval_hours: u64,
+            /// Number of dumps kept on disk, older ones are removed
+            pub keep: usize,
         },
         pub email: pub struct Email {
             pub creds: pub struct EmailCreds {
@@ -27,6 +36,7 @@ nest! {
 }
 
 impl Copy for LimitMiddleware {}
+impl Copy for Dump {}
 
 impl Config {
     pub fn init() -> Self {
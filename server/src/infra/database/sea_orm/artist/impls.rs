@@ -1,5 +1,5 @@
 use chrono::NaiveDate;
-use entity::enums::{ArtistType, DatePrecision};
+use entity::enums::{ArtistType, DatePrecision, EntityType};
 use entity::{
     artist_alias, artist_alias_history, artist_history, artist_link,
     artist_link_history, artist_localized_name, artist_localized_name_history,
@@ -21,8 +21,9 @@ use url::Url;
 
 use crate::domain::artist::{NewArtist, NewMembership, Tenure};
 use crate::domain::shared::{
-    DateWithPrecision, EntityIdent, Location, NewLocalizedName,
+    DateWithPrecision, EntityIdent, Location, NewLocalizedName, normalize_links,
 };
+use crate::infra::database::sea_orm::external_id;
 
 pub async fn create_artist(
     data: &NewArtist,
@@ -64,6 +65,12 @@ pub async fn create_artist(
     try_join!(
         create_artist_alias(artist.id, data.aliases.as_deref(), conn),
         create_artist_link(artist.id, data.links.clone(), conn),
+        external_id::create(
+            EntityType::Artist,
+            artist.id,
+            data.external_ids.as_deref().unwrap_or_default(),
+            conn
+        ),
         create_artist_localized_name(
             artist.id,
             data.localized_names.as_deref(),
@@ -124,6 +131,12 @@ pub async fn create_artist_history(
             conn
         ),
         create_artist_link_history(artist_history.id, data.links.clone(), conn),
+        external_id::create_histories(
+            EntityType::Artist,
+            artist_history.id,
+            data.external_ids.as_deref().unwrap_or_default(),
+            conn
+        ),
         create_artist_localized_name_history(
             artist_history.id,
             data.localized_names.as_deref(),
@@ -243,10 +256,12 @@ async fn create_artist_link(
     db: &impl ConnectionTrait,
 ) -> Result<(), DbErr> {
     if let Some(links) = links {
-        let model = links.iter().map(|url| artist_link::ActiveModel {
-            id: NotSet,
-            artist_id: Set(artist_id),
-            url: Set(url.to_string()),
+        let model = normalize_links(&links).into_iter().map(|link| {
+            artist_link::ActiveModel {
+                id: NotSet,
+                artist_id: Set(artist_id),
+                url: Set(link.url),
+            }
         });
 
         artist_link::Entity::insert_many(model)
@@ -264,10 +279,12 @@ async fn create_artist_link_history(
     db: &impl ConnectionTrait,
 ) -> Result<(), DbErr> {
     if let Some(links) = links {
-        let model = links.iter().map(|url| artist_link_history::ActiveModel {
-            id: NotSet,
-            history_id: Set(artist_id),
-            url: Set(url.to_string()),
+        let model = normalize_links(&links).into_iter().map(|link| {
+            artist_link_history::ActiveModel {
+                id: NotSet,
+                history_id: Set(artist_id),
+                url: Set(link.url),
+            }
         });
 
         artist_link_history::Entity::insert_many(model)
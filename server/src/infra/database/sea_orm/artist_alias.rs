@@ -0,0 +1,42 @@
+use sea_orm::{ConnectionTrait, DbErr, FromQueryResult, Statement};
+
+#[derive(FromQueryResult)]
+struct IdRow {
+    id: i32,
+}
+
+/// All artists reachable from `artist_id` through `artist_alias`, including
+/// `artist_id` itself, sorted by id
+///
+/// Aliases are stored as unordered pairs, so both columns are followed.
+/// `UNION` deduplicates visited artists, which also ends the recursion.
+pub async fn find_alias_cluster(
+    db: &impl ConnectionTrait,
+    artist_id: i32,
+) -> Result<Vec<i32>, DbErr> {
+    let sql = r"
+        WITH RECURSIVE cluster(id) AS (
+            SELECT $1::int
+            UNION
+            SELECT CASE
+                WHEN a.first_id = cluster.id THEN a.second_id
+                ELSE a.first_id
+            END
+            FROM artist_alias a
+            JOIN cluster ON cluster.id IN (a.first_id, a.second_id)
+        )
+        SELECT id FROM cluster ORDER BY id";
+
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        sql,
+        [artist_id.into()],
+    );
+
+    Ok(IdRow::find_by_statement(stmt)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|row| row.id)
+        .collect())
+}
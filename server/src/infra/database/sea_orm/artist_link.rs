@@ -0,0 +1,128 @@
+//! Links of artists saved before canonicalization was added
+
+use std::collections::{HashMap, HashSet};
+
+use entity::{artist_link, artist_link_history};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DatabaseTransaction, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, TransactionTrait,
+};
+use url::Url;
+
+use crate::domain::shared::canonicalize;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Rewrite {
+    update: Vec<(i32, String)>,
+    delete: Vec<i32>,
+}
+
+/// Canonicalize `(id, owner, url)` rows ordered by id. Of the links of one
+/// owner that end up with the same url, the first is kept. Urls that don't
+/// parse are left alone.
+fn rewrite(rows: Vec<(i32, i32, String)>) -> Rewrite {
+    let mut seen: HashMap<i32, HashSet<String>> = HashMap::new();
+    let mut res = Rewrite::default();
+
+    for (id, owner, url) in rows {
+        let canonical = Url::parse(&url)
+            .map_or_else(|_| url.clone(), |x| canonicalize(&x).to_string());
+
+        if !seen.entry(owner).or_default().insert(canonical.clone()) {
+            res.delete.push(id);
+        } else if canonical != url {
+            res.update.push((id, canonical));
+        }
+    }
+
+    res
+}
+
+/// Rewrite the links of artists and of their history, returns the number
+/// of rows updated or deleted
+pub async fn canonicalize_stored(
+    db: &DatabaseConnection,
+) -> Result<u64, DbErr> {
+    let tx = db.begin().await?;
+
+    let links = canonicalize_table::<artist_link::Entity>(
+        &tx,
+        artist_link::Column::Id,
+        artist_link::Column::ArtistId,
+        artist_link::Column::Url,
+    )
+    .await?;
+    let history = canonicalize_table::<artist_link_history::Entity>(
+        &tx,
+        artist_link_history::Column::Id,
+        artist_link_history::Column::HistoryId,
+        artist_link_history::Column::Url,
+    )
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(links + history)
+}
+
+async fn canonicalize_table<E: EntityTrait>(
+    tx: &DatabaseTransaction,
+    id: E::Column,
+    owner: E::Column,
+    url: E::Column,
+) -> Result<u64, DbErr> {
+    let rows = E::find()
+        .select_only()
+        .columns([id, owner, url])
+        .order_by_asc(id)
+        .into_tuple()
+        .all(tx)
+        .await?;
+    let Rewrite { update, delete } = rewrite(rows);
+
+    let mut count = E::delete_many()
+        .filter(id.is_in(delete))
+        .exec(tx)
+        .await?
+        .rows_affected;
+
+    for (row, canonical) in update {
+        E::update_many()
+            .col_expr(url, canonical.into())
+            .filter(id.eq(row))
+            .exec(tx)
+            .await?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrite_links() {
+        let rows = [
+            (1, 1, "https://twitter.com/zun_info"),
+            (2, 1, "https://x.com/zun_info"),
+            (3, 1, "http://www16.big.or.jp/~zun/"),
+            (4, 2, "http://x.com/zun_info/"),
+            (5, 2, "not a url"),
+        ]
+        .map(|(id, owner, url)| (id, owner, url.to_owned()))
+        .to_vec();
+
+        assert_eq!(
+            rewrite(rows),
+            Rewrite {
+                update: vec![
+                    (1, "https://x.com/zun_info".to_owned()),
+                    (4, "https://x.com/zun_info".to_owned()),
+                ],
+                delete: vec![2],
+            }
+        );
+    }
+}
@@ -1,15 +1,19 @@
+use std::collections::HashMap;
+
 use entity::artist::{self};
-use entity::enums::ReleaseImageType;
+use entity::enums::{ReleaseImageType, ReleaseType};
 use entity::{
     credit_role, release, release_artist, release_credit, release_image,
     release_track, release_track_artist,
 };
 use itertools::{Itertools, izip};
 use libfp::FunctorExt;
-use sea_orm::JoinType::*;
 use sea_orm::prelude::*;
-use sea_orm::{ConnectionTrait, DbErr, QuerySelect, QueryTrait};
-use sea_query::{Cond, ExprTrait, IntoCondition, SimpleExpr};
+use sea_orm::{
+    ActiveEnum, ConnectionTrait, DbErr, FromQueryResult, QueryOrder,
+    QuerySelect, QueryTrait, Statement,
+};
+use sea_query::{Cond, ExprTrait, SimpleExpr};
 
 use crate::domain::artist_release::*;
 use crate::domain::credit_role::CreditRoleRef;
@@ -38,13 +42,37 @@ where
     R: Connection,
     R::Conn: ConnectionTrait,
 {
-    find_artist_releases(
-        appearance_select(query.artist_id),
+    let artist_ids =
+        find_artist_ids(repo.conn(), query.artist_id, query.include_aliases)
+            .await?;
+
+    let Paginated { items, next_cursor } = find_artist_releases(
+        appearance_select(&artist_ids),
         query.pagination,
         repo.conn(),
     )
-    .await
-    .map(|x| x.map_items(Into::into))
+    .await?;
+
+    let mut credited_as = if query.include_aliases {
+        let release_ids = items.iter().map(|x| x.release.id).collect_vec();
+        find_credited_as(
+            track_credited_as_select(&release_ids, &artist_ids),
+            repo.conn(),
+        )
+        .await?
+    } else {
+        HashMap::new()
+    };
+
+    let items = items
+        .into_iter()
+        .map(|ir| Appearance {
+            credited_as: credited_as.remove(&ir.release.id).unwrap_or_default(),
+            ..ir.into()
+        })
+        .collect();
+
+    Ok(Paginated { items, next_cursor })
 }
 
 pub(crate) async fn credit<R>(
@@ -55,8 +83,24 @@ where
     R: Connection,
     R::Conn: ConnectionTrait,
 {
+    let artist_ids =
+        find_artist_ids(repo.conn(), query.artist_id, query.include_aliases)
+            .await?;
+
+    let role_ids = match query.role_id {
+        Some(role_id) if query.include_sub_roles => {
+            let mut role_ids = super::hierarchy::CREDIT_ROLE
+                .find_descendant_ids(repo.conn(), role_id)
+                .await?;
+            role_ids.insert(role_id);
+            Some(role_ids.into_iter().collect_vec())
+        }
+        Some(role_id) => Some(vec![role_id]),
+        None => None,
+    };
+
     let releases_and_artists = find_artist_releases(
-        credit_select(query.artist_id),
+        credit_select(&artist_ids, role_ids.as_deref()),
         query.pagination,
         repo.conn(),
     )
@@ -77,8 +121,9 @@ where
 
     let release_credits = releases
         .load_many(
-            release_credit::Entity::find()
-                .filter(release_credit::Column::ArtistId.eq(query.artist_id)),
+            release_credit::Entity::find().filter(
+                release_credit::Column::ArtistId.is_in(artist_ids.clone()),
+            ),
             repo.conn(),
         )
         .await?;
@@ -94,6 +139,17 @@ where
         .all(repo.conn())
         .await?;
 
+    let mut credited_as = if query.include_aliases {
+        let release_ids = releases.iter().map(|x| x.id).collect_vec();
+        find_credited_as(
+            release_credited_as_select(&release_ids, &artist_ids),
+            repo.conn(),
+        )
+        .await?
+    } else {
+        HashMap::new()
+    };
+
     let credit_irs = izip!(releases, artists, cover_urls, release_credits)
         .map(|(release, artists, cover_url, release_credits)| CreditIR {
             release,
@@ -103,7 +159,8 @@ where
         })
         .collect_vec();
 
-    let items = into_artist_credits(credit_irs, &credit_roles);
+    let items =
+        into_artist_credits(credit_irs, &credit_roles, &mut credited_as);
 
     Ok(Paginated { items, next_cursor })
 }
@@ -116,14 +173,208 @@ where
     R: Connection,
     R::Conn: ConnectionTrait,
 {
+    let artist_ids =
+        find_artist_ids(repo.conn(), query.artist_id, query.include_aliases)
+            .await?;
+
     let select = release::Entity::find()
         .filter(release::Column::ReleaseType.eq(query.release_type))
-        .filter(release_artist::Column::ArtistId.eq(query.artist_id))
-        .left_join(release_artist::Entity);
+        .filter(release_artist_exists(&artist_ids))
+        .apply_if(query.collapse_groups.then_some(()), |select, ()| {
+            select.filter(not_later_edition(query.release_type, &artist_ids))
+        });
 
-    find_artist_releases(select, query.pagination, repo.conn())
-        .await
-        .map(|x| x.map_items(Into::into))
+    let releases =
+        find_artist_releases(select, query.pagination, repo.conn()).await?;
+
+    let release_groups = if query.collapse_groups {
+        find_release_groups(
+            &releases.items.iter().map(|x| x.release.id).collect_vec(),
+            repo.conn(),
+        )
+        .await?
+    } else {
+        HashMap::new()
+    };
+
+    Ok(releases.map_items(|ir| {
+        let release_group = release_groups.get(&ir.release.id).cloned();
+        let credited_as = if query.include_aliases {
+            ir.artists
+                .iter()
+                .filter(|artist| artist_ids.contains(&artist.id))
+                .cloned()
+                .map_into()
+                .collect()
+        } else {
+            vec![]
+        };
+
+        Discography {
+            credited_as,
+            release_group,
+            ..ir.into()
+        }
+    }))
+}
+
+/// Excludes releases with an edition of the same group that is also a
+/// release of the given type by the artists, and was entered before it
+fn not_later_edition(
+    release_type: ReleaseType,
+    artist_ids: &[i32],
+) -> SimpleExpr {
+    Expr::cust_with_values(
+        r#"NOT EXISTS (
+            SELECT 1
+            FROM release_group_release own
+            JOIN release_group_release other ON other.group_id = own.group_id
+            JOIN release edition ON edition.id = other.release_id
+            WHERE own.release_id = "release"."id"
+                AND other.release_id < "release"."id"
+                AND edition.release_type = $1::"ReleaseType"
+                AND EXISTS (
+                    SELECT 1
+                    FROM release_artist
+                    WHERE release_artist.release_id = edition.id
+                        AND release_artist.artist_id = ANY($2)
+                )
+        )"#,
+        [
+            sea_orm::Value::from(release_type.to_value()),
+            artist_ids.to_vec().into(),
+        ],
+    )
+}
+
+#[derive(FromQueryResult)]
+struct ReleaseGroupRow {
+    release_id: i32,
+    id: i32,
+    title: String,
+    edition_count: i32,
+}
+
+/// The groups of the given releases, keyed by release id
+async fn find_release_groups(
+    release_ids: &[i32],
+    db: &impl ConnectionTrait,
+) -> Result<HashMap<i32, CollapsedReleaseGroup>, DbErr> {
+    if release_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let sql = r"
+        SELECT
+            own.release_id,
+            release_group.id,
+            release_group.title,
+            (
+                SELECT count(*)::int
+                FROM release_group_release edition
+                WHERE edition.group_id = release_group.id
+            ) AS edition_count
+        FROM release_group_release own
+        JOIN release_group ON release_group.id = own.group_id
+        WHERE own.release_id = ANY($1)
+    ";
+
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        sql,
+        [release_ids.to_vec().into()],
+    );
+
+    Ok(ReleaseGroupRow::find_by_statement(stmt)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|row| {
+            (
+                row.release_id,
+                CollapsedReleaseGroup {
+                    id: row.id,
+                    title: row.title,
+                    edition_count: row.edition_count,
+                },
+            )
+        })
+        .collect())
+}
+
+/// The artist itself, or its whole alias cluster
+async fn find_artist_ids(
+    db: &impl ConnectionTrait,
+    artist_id: i32,
+    include_aliases: bool,
+) -> Result<Vec<i32>, DbErr> {
+    if include_aliases {
+        super::artist_alias::find_alias_cluster(db, artist_id).await
+    } else {
+        Ok(vec![artist_id])
+    }
+}
+
+/// The members of the alias cluster named on each release, keyed by
+/// release id. `select` yields `(release_id, artist_id, name)` rows.
+async fn find_credited_as<E: EntityTrait>(
+    select: Select<E>,
+    db: &impl ConnectionTrait,
+) -> Result<HashMap<i32, Vec<ArtistReleaseArtist>>, DbErr> {
+    Ok(select
+        .into_tuple::<(i32, i32, String)>()
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|(release_id, id, name)| {
+            (release_id, ArtistReleaseArtist { id, name })
+        })
+        .into_group_map())
+}
+
+/// Cluster members credited on a track of the releases
+fn track_credited_as_select(
+    release_ids: &[i32],
+    artist_ids: &[i32],
+) -> Select<release_track_artist::Entity> {
+    release_track_artist::Entity::find()
+        .select_only()
+        .column(release_track::Column::ReleaseId)
+        .column(artist::Column::Id)
+        .column(artist::Column::Name)
+        .inner_join(release_track::Entity)
+        .inner_join(artist::Entity)
+        .filter(
+            release_track::Column::ReleaseId.is_in(release_ids.iter().copied()),
+        )
+        .filter(
+            release_track_artist::Column::ArtistId
+                .is_in(artist_ids.iter().copied()),
+        )
+        .distinct()
+        .order_by_asc(artist::Column::Id)
+}
+
+/// Cluster members with a credit on the releases
+fn release_credited_as_select(
+    release_ids: &[i32],
+    artist_ids: &[i32],
+) -> Select<release_credit::Entity> {
+    release_credit::Entity::find()
+        .select_only()
+        .column(release_credit::Column::ReleaseId)
+        .column(artist::Column::Id)
+        .column(artist::Column::Name)
+        .inner_join(artist::Entity)
+        .filter(
+            release_credit::Column::ReleaseId
+                .is_in(release_ids.iter().copied()),
+        )
+        .filter(
+            release_credit::Column::ArtistId.is_in(artist_ids.iter().copied()),
+        )
+        .distinct()
+        .order_by_asc(artist::Column::Id)
 }
 
 async fn find_artist_releases(
@@ -204,6 +455,8 @@ impl From<ArtistReleaseIR> for Discography {
             release_date,
             release_type: release.release_type,
             cover_url,
+            credited_as: vec![],
+            release_group: None,
         }
     }
 }
@@ -239,6 +492,7 @@ fn into_credit_roles(
 fn into_artist_credits(
     ir: Vec<CreditIR>,
     credit_roles: &[credit_role::Model],
+    credited_as: &mut HashMap<i32, Vec<ArtistReleaseArtist>>,
 ) -> Vec<Credit> {
     ir.into_iter()
         .map(
@@ -248,6 +502,9 @@ fn into_artist_credits(
                  cover_url,
                  release_credits,
              }| {
+                let credited_as =
+                    credited_as.remove(&release.id).unwrap_or_default();
+
                 let roles = into_credit_roles(release_credits, credit_roles);
 
                 let artist = artists.fmap_into();
@@ -262,13 +519,14 @@ fn into_artist_credits(
                     release_type: release.release_type,
                     roles,
                     cover_url,
+                    credited_as,
                 }
             },
         )
         .collect_vec()
 }
 
-fn appearance_select(artist_id: i32) -> Select<release::Entity> {
+fn appearance_select(artist_ids: &[i32]) -> Select<release::Entity> {
     let release_track_artist_subquery = release_track_artist::Entity::find()
         .select_only()
         .expr(1)
@@ -280,31 +538,50 @@ fn appearance_select(artist_id: i32) -> Select<release::Entity> {
             )),
             Expr::col((release::Entity, release::Column::Id)),
         ))
-        .filter(release_track_artist::Column::ArtistId.eq(artist_id));
+        .filter(
+            release_track_artist::Column::ArtistId
+                .is_in(artist_ids.iter().copied()),
+        );
 
     release::Entity::find().filter(
         Cond::all()
-            .add(not_release_artist(artist_id))
+            .add(not_release_artist(artist_ids))
             .add(Expr::exists(release_track_artist_subquery.into_query())),
     )
 }
 
-fn credit_select(artist_id: i32) -> Select<release::Entity> {
-    release::Entity::find()
-        .join(
-            InnerJoin,
-            release_credit::Relation::Release.def().rev().on_condition(
-                move |_, _| {
-                    release_credit::Column::ArtistId
-                        .eq(artist_id)
-                        .into_condition()
-                },
-            ),
+/// `role_ids` restricts the matched credits to the given roles
+fn credit_select(
+    artist_ids: &[i32],
+    role_ids: Option<&[i32]>,
+) -> Select<release::Entity> {
+    let release_credit_subquery = release_credit::Entity::find()
+        .select_only()
+        .expr(1)
+        .filter(Expr::eq(
+            Expr::col((
+                release_credit::Entity,
+                release_credit::Column::ReleaseId,
+            )),
+            Expr::col((release::Entity, release::Column::Id)),
+        ))
+        .filter(
+            release_credit::Column::ArtistId.is_in(artist_ids.iter().copied()),
         )
-        .filter(not_release_artist(artist_id))
+        .apply_if(role_ids, |select, role_ids| {
+            select.filter(
+                release_credit::Column::RoleId.is_in(role_ids.iter().copied()),
+            )
+        });
+
+    release::Entity::find().filter(
+        Cond::all()
+            .add(not_release_artist(artist_ids))
+            .add(Expr::exists(release_credit_subquery.into_query())),
+    )
 }
 
-fn not_release_artist(artist_id: i32) -> SimpleExpr {
+fn release_artist_exists(artist_ids: &[i32]) -> SimpleExpr {
     let subquery = release_artist::Entity::find()
         .select_only()
         .expr(1)
@@ -315,9 +592,38 @@ fn not_release_artist(artist_id: i32) -> SimpleExpr {
             )),
             Expr::col((release::Entity, release::Column::Id)),
         ))
-        .filter(release_artist::Column::ArtistId.eq(artist_id))
+        .filter(
+            release_artist::Column::ArtistId.is_in(artist_ids.iter().copied()),
+        )
         .into_query();
 
+    Expr::exists(subquery)
+}
+
+fn not_release_artist(artist_ids: &[i32]) -> SimpleExpr {
     // TODO: replace with Expr::not after pr merged
-    ExprTrait::not(Expr::exists(subquery))
+    ExprTrait::not(release_artist_exists(artist_ids))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn track_credited_as_of_alias_cluster() {
+        let query = track_credited_as_select(&[10, 11], &[1, 2, 3]);
+        assert_eq!(
+            query.build(sea_orm::DatabaseBackend::Postgres).to_string(),
+            r#"SELECT DISTINCT "release_track"."release_id", "artist"."id", "artist"."name" FROM "release_track_artist" INNER JOIN "release_track" ON "release_track_artist"."track_id" = "release_track"."id" INNER JOIN "artist" ON "release_track_artist"."artist_id" = "artist"."id" WHERE "release_track"."release_id" IN (10, 11) AND "release_track_artist"."artist_id" IN (1, 2, 3) ORDER BY "artist"."id" ASC"#,
+        );
+    }
+
+    #[test]
+    fn release_credited_as_of_alias_cluster() {
+        let query = release_credited_as_select(&[10], &[1, 2, 3]);
+        assert_eq!(
+            query.build(sea_orm::DatabaseBackend::Postgres).to_string(),
+            r#"SELECT DISTINCT "release_credit"."release_id", "artist"."id", "artist"."name" FROM "release_credit" INNER JOIN "artist" ON "release_credit"."artist_id" = "artist"."id" WHERE "release_credit"."release_id" IN (10) AND "release_credit"."artist_id" IN (1, 2, 3) ORDER BY "artist"."id" ASC"#,
+        );
+    }
 }
@@ -17,11 +17,14 @@ use crate::domain::correction::{
 };
 use crate::domain::credit_role::TxRepo as _;
 use crate::domain::event::TxRepo as _;
+use crate::domain::event_series::TxRepo as _;
 use crate::domain::label::TxRepo as _;
 use crate::domain::model::CorrectionApprover;
 use crate::domain::release::TxRepo as _;
+use crate::domain::release_group::TxRepo as _;
 use crate::domain::song::TxRepo as _;
 use crate::domain::song_lyrics::TxRepo as _;
+use crate::domain::source_work::TxRepo as _;
 use crate::domain::tag::TxRepo as _;
 use crate::infra;
 
@@ -173,6 +176,8 @@ impl TxRepo for SeaOrmTxRepo {
 
         let correction = correction_active_model.update(self.conn()).await?;
 
+        super::external_id::apply_update(&correction, self.conn()).await?;
+
         match correction.entity_type {
             EntityType::Artist => {
                 context.artist_repo().apply_update(correction).await?;
@@ -198,6 +203,18 @@ impl TxRepo for SeaOrmTxRepo {
             EntityType::CreditRole => {
                 context.credit_role_repo().apply_update(correction).await?;
             }
+            EntityType::SourceWork => {
+                context.source_work_repo().apply_update(correction).await?;
+            }
+            EntityType::ReleaseGroup => {
+                context
+                    .release_group_repo()
+                    .apply_update(correction)
+                    .await?;
+            }
+            EntityType::EventSeries => {
+                context.event_series_repo().apply_update(correction).await?;
+            }
         }
 
         Ok(())
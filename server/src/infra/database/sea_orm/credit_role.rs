@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+
+use entity::enums::EntityType;
 use entity::{
     correction_revision, credit_role, credit_role_history,
     credit_role_inheritance, credit_role_inheritance_history,
@@ -9,8 +12,8 @@ use sea_orm::{
 };
 
 use crate::domain::Connection;
-use crate::domain::credit_role::{NewCreditRole, TxRepo};
-use crate::infra::database::sea_orm::SeaOrmTxRepo;
+use crate::domain::credit_role::{NewCreditRole, TxRepo, validate_super_roles};
+use crate::infra::database::sea_orm::{SeaOrmTxRepo, external_id};
 
 impl TxRepo for SeaOrmTxRepo {
     async fn create(
@@ -50,6 +53,14 @@ impl TxRepo for SeaOrmTxRepo {
                 .await?;
         }
 
+        external_id::create(
+            EntityType::CreditRole,
+            credit_role.id,
+            data.external_ids.as_deref().unwrap_or_default(),
+            self.conn(),
+        )
+        .await?;
+
         Ok(credit_role.id)
     }
 
@@ -94,9 +105,26 @@ impl TxRepo for SeaOrmTxRepo {
             .await?;
         }
 
+        external_id::create_histories(
+            EntityType::CreditRole,
+            credit_role_history.id,
+            data.external_ids.as_deref().unwrap_or_default(),
+            self.conn(),
+        )
+        .await?;
+
         Ok(credit_role_history.id)
     }
 
+    async fn find_descendant_ids(
+        &self,
+        role_id: i32,
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(super::hierarchy::CREDIT_ROLE
+            .find_descendant_ids(self.conn(), role_id)
+            .await?)
+    }
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
@@ -116,6 +144,21 @@ impl TxRepo for SeaOrmTxRepo {
                 .await?
                 .expect("Credit role history not found, this shouldn't happen");
 
+        // The hierarchy may have changed since the correction was submitted
+        let super_ids = credit_role_inheritance_history::Entity::find()
+            .filter(
+                credit_role_inheritance_history::Column::HistoryId
+                    .eq(revision.entity_history_id),
+            )
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .map(|inheritance| inheritance.super_id);
+        let descendant_ids = super::hierarchy::CREDIT_ROLE
+            .find_descendant_ids(self.conn(), correction.entity_id)
+            .await?;
+        validate_super_roles(correction.entity_id, super_ids, &descendant_ids)?;
+
         // Update main credit_role table with history data
         credit_role::ActiveModel {
             id: Set(correction.entity_id),
@@ -0,0 +1,182 @@
+//! Full-text search over the generated `search_vector` columns.
+//!
+//! See migration `m20251215_000000_create_description_search` for the text
+//! search configuration and the CJK segmentation.
+
+use std::collections::HashMap;
+
+use sea_orm::{ConnectionTrait, DbErr, FromQueryResult, Statement};
+
+use crate::domain::shared::Highlight;
+
+const HIGHLIGHT_START: char = '\u{2}';
+const HIGHLIGHT_STOP: char = '\u{3}';
+
+/// Headlines are built with control characters as delimiters, so that the
+/// text can be escaped before the `<mark>` tags are inserted
+const HEADLINE_OPTIONS: &str = "StartSel=\u{2}, StopSel=\u{3}, \
+    MaxWords=35, MinWords=15, MaxFragments=2, FragmentDelimiter=\" … \"";
+
+#[derive(FromQueryResult)]
+struct Row {
+    id: i32,
+    short_description: String,
+    description: String,
+}
+
+/// Returns matching ids ordered by rank, along with their highlights
+///
+/// `table` must have `id`, `short_description`, `description` and
+/// `search_vector` columns
+pub async fn find_ids(
+    db: &impl ConnectionTrait,
+    table: &str,
+    query: &str,
+    limit: u64,
+) -> Result<(Vec<i32>, HashMap<i32, Highlight>), DbErr> {
+    let sql = format!(
+        r"
+        WITH hit AS (
+            SELECT t.id, ts_rank(t.search_vector, q) AS rank, q
+            FROM {table} t, thcdb_to_tsquery($1) AS q
+            WHERE t.search_vector @@ q
+            ORDER BY rank DESC, t.id
+            LIMIT $3
+        )
+        SELECT
+            t.id,
+            ts_headline('thcdb_multilingual', thcdb_cjk_segment(t.short_description), hit.q, $2)
+                AS short_description,
+            ts_headline('thcdb_multilingual', thcdb_cjk_segment(t.description), hit.q, $2)
+                AS description
+        FROM hit
+        JOIN {table} t ON t.id = hit.id
+        ORDER BY hit.rank DESC, t.id
+        "
+    );
+
+    let stmt = Statement::from_sql_and_values(
+        db.get_database_backend(),
+        sql,
+        [query.into(), HEADLINE_OPTIONS.into(), limit.into()],
+    );
+
+    let rows = Row::find_by_statement(stmt).all(db).await?;
+
+    let ids = rows.iter().map(|row| row.id).collect();
+    let highlights = rows
+        .into_iter()
+        .filter_map(|row| {
+            let highlight = Highlight {
+                short_description: render_headline(&row.short_description),
+                description: render_headline(&row.description),
+            };
+            (highlight != Highlight::default()).then_some((row.id, highlight))
+        })
+        .collect();
+
+    Ok((ids, highlights))
+}
+
+/// Undo the CJK segmentation, escape the text and replace the delimiters
+/// with `<mark>` tags. Returns `None` if nothing in the headline matched.
+fn render_headline(headline: &str) -> Option<String> {
+    if !headline.contains(HIGHLIGHT_START) {
+        return None;
+    }
+
+    let text = strip_cjk_segmentation(headline);
+    let mut rendered = String::with_capacity(text.len());
+
+    for ch in text.trim().chars() {
+        match ch {
+            HIGHLIGHT_START => rendered.push_str("<mark>"),
+            HIGHLIGHT_STOP => rendered.push_str("</mark>"),
+            '&' => rendered.push_str("&amp;"),
+            '<' => rendered.push_str("&lt;"),
+            '>' => rendered.push_str("&gt;"),
+            '"' => rendered.push_str("&quot;"),
+            '\'' => rendered.push_str("&#39;"),
+            _ => rendered.push(ch),
+        }
+    }
+
+    Some(rendered)
+}
+
+/// Remove the space `thcdb_cjk_segment` put on each side of a CJK character
+fn strip_cjk_segmentation(text: &str) -> String {
+    let chars = text.chars().collect::<Vec<_>>();
+    let mut keep = vec![true; chars.len()];
+    let is_delimiter =
+        |ch: char| matches!(ch, HIGHLIGHT_START | HIGHLIGHT_STOP);
+
+    for (idx, &ch) in chars.iter().enumerate() {
+        if !is_cjk(ch) {
+            continue;
+        }
+
+        if let Some(left) = (0..idx).rev().find(|&i| !is_delimiter(chars[i]))
+            && chars[left] == ' '
+        {
+            keep[left] = false;
+        }
+
+        if let Some(right) =
+            (idx + 1..chars.len()).find(|&i| !is_delimiter(chars[i]))
+            && chars[right] == ' '
+        {
+            keep[right] = false;
+        }
+    }
+
+    chars
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(ch, keep)| keep.then_some(ch))
+        .collect()
+}
+
+/// Must match the ranges in `thcdb_cjk_segment`
+const fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_match_no_highlight() {
+        assert_eq!(render_headline("nothing to see"), None);
+    }
+
+    #[test]
+    fn escape_before_marking() {
+        assert_eq!(
+            render_headline("a <b> & \u{2}café\u{3}").as_deref(),
+            Some("a &lt;b&gt; &amp; <mark>café</mark>")
+        );
+    }
+
+    #[test]
+    fn undo_cjk_segmentation() {
+        assert_eq!(
+            render_headline(" 東  方  \u{2}紅\u{3}  \u{2}魔\u{3}  郷  Project")
+                .as_deref(),
+            Some("東方<mark>紅</mark><mark>魔</mark>郷 Project")
+        );
+    }
+
+    #[test]
+    fn keep_original_spaces_between_cjk() {
+        assert_eq!(strip_cjk_segmentation(" 東   方 "), "東 方");
+    }
+}
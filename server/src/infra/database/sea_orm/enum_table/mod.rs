@@ -4,7 +4,9 @@ use sea_orm::{
 use strum::IntoEnumIterator;
 
 use crate::domain::model::UserRoleEnum;
-use crate::infra::database::sea_orm::utils::upsert_admin_acc;
+use crate::infra::database::sea_orm::utils::{
+    upsert_admin_acc, upsert_import_bot_acc,
+};
 
 mod user_role;
 
@@ -19,6 +21,7 @@ pub async fn sync_enum_table(db: &DatabaseConnection) -> Result<(), DbErr> {
     UserRoleEnum::check_and_sync(db).await?;
 
     upsert_admin_acc(db).await;
+    upsert_import_bot_acc(db).await;
 
     Ok(())
 }
@@ -1,17 +1,22 @@
-use entity::sea_orm_active_enums::AlternativeNameType;
+use entity::sea_orm_active_enums::{AlternativeNameType, EntityType};
 use entity::{
     correction_revision, event, event_alternative_name,
-    event_alternative_name_history, event_history,
+    event_alternative_name_history, event_circle, event_circle_history,
+    event_history, event_link, event_link_history, event_series,
 };
 use sea_orm::ActiveValue::NotSet;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbErr, EntityTrait,
-    IntoActiveValue, ModelTrait, QueryFilter, QueryOrder, Set,
+    IntoActiveValue, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
 };
 use snafu::ResultExt;
+use url::Url;
 
 use crate::domain::Connection;
-use crate::domain::event::{NewEvent, TxRepo};
+use crate::domain::event::{Booth, NewEvent, NewEventCircle, TxRepo};
+use crate::domain::shared::normalize_links;
+use crate::infra::database::sea_orm::external_id;
 
 impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
     async fn create(
@@ -34,6 +39,33 @@ impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
             .boxed()
     }
 
+    async fn series_exists(
+        &self,
+        series_id: i32,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        event_series::Entity::find_by_id(series_id)
+            .count(self.conn())
+            .await
+            .map(|count| count > 0)
+            .boxed()
+    }
+
+    async fn find_series_edition(
+        &self,
+        series_id: i32,
+        ordinal: i32,
+    ) -> Result<Option<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        event::Entity::find()
+            .select_only()
+            .column(event::Column::Id)
+            .filter(event::Column::SeriesId.eq(series_id))
+            .filter(event::Column::SeriesOrdinal.eq(ordinal))
+            .into_tuple()
+            .one(self.conn())
+            .await
+            .boxed()
+    }
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
@@ -84,6 +116,16 @@ async fn create_event_and_relations(
             .as_ref()
             .and_then(|l| l.city.clone())
             .into_active_value(),
+        series_id: data
+            .series
+            .as_ref()
+            .map(|x| x.series_id)
+            .into_active_value(),
+        series_ordinal: data
+            .series
+            .as_ref()
+            .and_then(|x| x.ordinal)
+            .into_active_value(),
     };
 
     let event = event_model.insert(tx).await?;
@@ -92,6 +134,18 @@ async fn create_event_and_relations(
         create_alt_names(event.id, alt_names, tx).await?;
     }
 
+    if let Some(links) = &data.links {
+        create_links(event.id, links, tx).await?;
+    }
+
+    if let Some(ids) = &data.external_ids {
+        external_id::create(EntityType::Event, event.id, ids, tx).await?;
+    }
+
+    if let Some(circles) = &data.circles {
+        create_circles(event.id, circles, tx).await?;
+    }
+
     Ok(event)
 }
 
@@ -137,6 +191,16 @@ async fn create_event_history_and_relations(
             .as_ref()
             .and_then(|l| l.city.clone())
             .into_active_value(),
+        series_id: data
+            .series
+            .as_ref()
+            .map(|x| x.series_id)
+            .into_active_value(),
+        series_ordinal: data
+            .series
+            .as_ref()
+            .and_then(|x| x.ordinal)
+            .into_active_value(),
     };
 
     let history = history_model.insert(tx).await?;
@@ -145,6 +209,19 @@ async fn create_event_history_and_relations(
         create_alt_names_history(history.id, alt_names, tx).await?;
     }
 
+    if let Some(links) = &data.links {
+        create_links_history(history.id, links, tx).await?;
+    }
+
+    if let Some(ids) = &data.external_ids {
+        external_id::create_histories(EntityType::Event, history.id, ids, tx)
+            .await?;
+    }
+
+    if let Some(circles) = &data.circles {
+        create_circles_history(history.id, circles, tx).await?;
+    }
+
     Ok(history)
 }
 
@@ -201,6 +278,98 @@ async fn create_alt_names_history(
     Ok(())
 }
 
+async fn create_links(
+    event_id: i32,
+    links: &[Url],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let models = normalize_links(links).into_iter().map(|link| {
+        event_link::ActiveModel {
+            id: NotSet,
+            event_id: Set(event_id),
+            url: Set(link.url),
+        }
+    });
+
+    event_link::Entity::insert_many(models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_links_history(
+    history_id: i32,
+    links: &[Url],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let models = normalize_links(links).into_iter().map(|link| {
+        event_link_history::ActiveModel {
+            id: NotSet,
+            history_id: Set(history_id),
+            url: Set(link.url),
+        }
+    });
+
+    event_link_history::Entity::insert_many(models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_circles(
+    event_id: i32,
+    circles: &[NewEventCircle],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let models = circles.iter().map(|circle| {
+        let (booth_day, booth_hall, booth_space) =
+            circle.booth.clone().into_columns();
+        event_circle::ActiveModel {
+            event_id: Set(event_id),
+            artist_id: Set(circle.artist_id),
+            booth_day: Set(booth_day),
+            booth_hall: Set(booth_hall),
+            booth_space: Set(booth_space),
+        }
+    });
+
+    event_circle::Entity::insert_many(models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_circles_history(
+    history_id: i32,
+    circles: &[NewEventCircle],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let models = circles.iter().map(|circle| {
+        let (booth_day, booth_hall, booth_space) =
+            circle.booth.clone().into_columns();
+        event_circle_history::ActiveModel {
+            history_id: Set(history_id),
+            artist_id: Set(circle.artist_id),
+            booth_day: Set(booth_day),
+            booth_hall: Set(booth_hall),
+            booth_space: Set(booth_space),
+        }
+    });
+
+    event_circle_history::Entity::insert_many(models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
 async fn apply_correction(
     correction: entity::correction::Model,
     tx: &DatabaseTransaction,
@@ -232,6 +401,8 @@ async fn apply_correction(
         location_country: Set(history.location_country),
         location_province: Set(history.location_province),
         location_city: Set(history.location_city),
+        series_id: Set(history.series_id),
+        series_ordinal: Set(history.series_ordinal),
     };
 
     active_model.update(tx).await?;
@@ -239,6 +410,8 @@ async fn apply_correction(
     let event_id = correction.entity_id;
 
     update_alt_names(event_id, revision.entity_history_id, tx).await?;
+    update_links(event_id, revision.entity_history_id, tx).await?;
+    update_circles(event_id, revision.entity_history_id, tx).await?;
 
     Ok(())
 }
@@ -286,3 +459,62 @@ async fn update_alt_names(
 
     Ok(())
 }
+
+async fn update_links(
+    event_id: i32,
+    history_id: i32,
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    event_link::Entity::delete_many()
+        .filter(event_link::Column::EventId.eq(event_id))
+        .exec(tx)
+        .await?;
+
+    let links = event_link_history::Entity::find()
+        .filter(event_link_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(event_link_history::Column::Id)
+        .all(tx)
+        .await?;
+
+    let models = links.into_iter().map(|link| event_link::ActiveModel {
+        id: NotSet,
+        event_id: Set(event_id),
+        url: Set(link.url),
+    });
+
+    event_link::Entity::insert_many(models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn update_circles(
+    event_id: i32,
+    history_id: i32,
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    event_circle::Entity::delete_many()
+        .filter(event_circle::Column::EventId.eq(event_id))
+        .exec(tx)
+        .await?;
+
+    let circles = event_circle_history::Entity::find()
+        .filter(event_circle_history::Column::HistoryId.eq(history_id))
+        .all(tx)
+        .await?
+        .into_iter()
+        .map(|circle| NewEventCircle {
+            artist_id: circle.artist_id,
+            booth: Booth::from_columns(
+                circle.booth_day,
+                circle.booth_hall,
+                circle.booth_space,
+            )
+            .unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+
+    create_circles(event_id, &circles, tx).await
+}
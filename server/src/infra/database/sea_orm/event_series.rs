@@ -0,0 +1,99 @@
+use entity::enums::EntityType;
+use entity::{correction_revision, event_series, event_series_history};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::{
+    ActiveModelTrait, ConnectionTrait, DbErr, EntityTrait, ModelTrait,
+    QueryOrder,
+};
+use snafu::ResultExt;
+
+use super::{SeaOrmTxRepo, external_id};
+use crate::domain::Connection;
+use crate::domain::event_series::{NewEventSeries, TxRepo};
+
+impl TxRepo for SeaOrmTxRepo {
+    async fn create(
+        &self,
+        data: &NewEventSeries,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        let series = event_series::ActiveModel {
+            id: NotSet,
+            name: Set(data.name.to_string()),
+            description: Set(data.description.clone().unwrap_or_default()),
+        }
+        .insert(self.conn())
+        .await?;
+
+        external_id::create(
+            EntityType::EventSeries,
+            series.id,
+            data.external_ids.as_deref().unwrap_or_default(),
+            self.conn(),
+        )
+        .await?;
+
+        Ok(series.id)
+    }
+
+    async fn create_history(
+        &self,
+        data: &NewEventSeries,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        let history = event_series_history::ActiveModel {
+            id: NotSet,
+            name: Set(data.name.to_string()),
+            description: Set(data.description.clone().unwrap_or_default()),
+        }
+        .insert(self.conn())
+        .await?;
+
+        external_id::create_histories(
+            EntityType::EventSeries,
+            history.id,
+            data.external_ids.as_deref().unwrap_or_default(),
+            self.conn(),
+        )
+        .await?;
+
+        Ok(history.id)
+    }
+
+    async fn apply_update(
+        &self,
+        correction: entity::correction::Model,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        apply_update_impl(correction, self.conn()).await.boxed()
+    }
+}
+
+async fn apply_update_impl(
+    correction: entity::correction::Model,
+    conn: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    let revision = correction
+        .find_related(correction_revision::Entity)
+        .order_by_desc(correction_revision::Column::EntityHistoryId)
+        .one(conn)
+        .await?
+        .ok_or_else(|| {
+            DbErr::Custom("Correction revision not found".to_string())
+        })?;
+
+    let history =
+        event_series_history::Entity::find_by_id(revision.entity_history_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| {
+                DbErr::Custom("Event series history not found".to_string())
+            })?;
+
+    event_series::ActiveModel {
+        id: Set(correction.entity_id),
+        name: Set(history.name),
+        description: Set(history.description),
+    }
+    .update(conn)
+    .await?;
+
+    Ok(())
+}
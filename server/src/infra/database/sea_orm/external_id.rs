@@ -0,0 +1,151 @@
+//! External ids of every entity type live in the same tables, keyed by
+//! entity type and entity or history id.
+//!
+//! Entities write them along with their other relations when they create
+//! an entity or a history. Applying a correction is handled here once for
+//! all entity types, see [`apply_update`].
+
+use std::collections::HashMap;
+
+use entity::enums::EntityType;
+use entity::{
+    correction, correction_revision, external_id, external_id_history,
+};
+use itertools::Itertools;
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+};
+
+use crate::domain::shared::{ExternalId, NewExternalId};
+
+pub async fn create(
+    entity_type: EntityType,
+    entity_id: i32,
+    ids: &[NewExternalId],
+    db: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let models = ids.iter().unique().map(|id| external_id::ActiveModel {
+        id: NotSet,
+        entity_type: Set(entity_type),
+        entity_id: Set(entity_id),
+        source: Set(id.source),
+        value: Set(id.value.clone()),
+    });
+
+    external_id::Entity::insert_many(models).exec(db).await?;
+
+    Ok(())
+}
+
+pub async fn create_histories(
+    entity_type: EntityType,
+    history_id: i32,
+    ids: &[NewExternalId],
+    db: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let models =
+        ids.iter()
+            .unique()
+            .map(|id| external_id_history::ActiveModel {
+                id: NotSet,
+                entity_type: Set(entity_type),
+                history_id: Set(history_id),
+                source: Set(id.source),
+                value: Set(id.value.clone()),
+            });
+
+    external_id_history::Entity::insert_many(models)
+        .exec(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Replace the external ids of the corrected entity with the ones of the
+/// latest revision
+pub async fn apply_update(
+    correction: &correction::Model,
+    db: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    let revision = correction_revision::Entity::find()
+        .filter(correction_revision::Column::CorrectionId.eq(correction.id))
+        .order_by_desc(correction_revision::Column::EntityHistoryId)
+        .one(db)
+        .await?
+        .ok_or_else(|| {
+            DbErr::Custom("Correction revision not found".to_owned())
+        })?;
+
+    external_id::Entity::delete_many()
+        .filter(external_id::Column::EntityType.eq(correction.entity_type))
+        .filter(external_id::Column::EntityId.eq(correction.entity_id))
+        .exec(db)
+        .await?;
+
+    let histories =
+        find_histories(correction.entity_type, revision.entity_history_id, db)
+            .await?;
+
+    if histories.is_empty() {
+        return Ok(());
+    }
+
+    let models =
+        histories
+            .into_iter()
+            .map(|history| external_id::ActiveModel {
+                id: NotSet,
+                entity_type: Set(correction.entity_type),
+                entity_id: Set(correction.entity_id),
+                source: Set(history.source),
+                value: Set(history.value),
+            });
+
+    external_id::Entity::insert_many(models).exec(db).await?;
+
+    Ok(())
+}
+
+pub async fn find_histories(
+    entity_type: EntityType,
+    history_id: i32,
+    db: &impl ConnectionTrait,
+) -> Result<Vec<external_id_history::Model>, DbErr> {
+    external_id_history::Entity::find()
+        .filter(external_id_history::Column::EntityType.eq(entity_type))
+        .filter(external_id_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(external_id_history::Column::Id)
+        .all(db)
+        .await
+}
+
+/// External ids of each entity, entities without any are left out
+pub async fn find_by_entity_ids(
+    entity_type: EntityType,
+    entity_ids: impl IntoIterator<Item = i32>,
+    db: &impl ConnectionTrait,
+) -> Result<HashMap<i32, Vec<ExternalId>>, DbErr> {
+    Ok(external_id::Entity::find()
+        .filter(external_id::Column::EntityType.eq(entity_type))
+        .filter(external_id::Column::EntityId.is_in(entity_ids))
+        .order_by_asc(external_id::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|model| {
+            (
+                model.entity_id,
+                ExternalId::from_stored(entity_type, model.source, model.value),
+            )
+        })
+        .into_group_map())
+}
@@ -0,0 +1,271 @@
+//! Traversal of the hierarchies of tags, labels and credit roles.
+//!
+//! Each one is stored as a table of relations pointing from an entity to
+//! the one above it. The traversals either use `UNION` rather than
+//! `UNION ALL` or track the visited path, so that a cycle in the data
+//! can't make the recursion run forever.
+
+use std::collections::HashSet;
+
+use sea_orm::{ConnectionTrait, DbErr, FromQueryResult, Statement};
+
+/// Guards against pathological hierarchies, real ones are a few levels deep
+const MAX_DEPTH: i32 = 32;
+
+pub struct Hierarchy {
+    /// Table of the entity
+    entity: &'static str,
+    /// Table of the relations
+    relation: &'static str,
+    /// Column of the relation referencing the entity below
+    child: &'static str,
+    /// Column of the relation referencing the entity above
+    parent: &'static str,
+    /// Column of the relation holding its type, if any
+    relation_type: Option<&'static str>,
+    /// Condition on the relations `r` that put an entity in the subtree of
+    /// the one above it, all of them if `None`
+    subtree_condition: Option<&'static str>,
+}
+
+/// `Inherit` and `Derive` relations are treated the same way
+pub const TAG: Hierarchy = Hierarchy {
+    entity: "tag",
+    relation: "tag_relation",
+    child: "tag_id",
+    parent: "related_tag_id",
+    relation_type: Some("type"),
+    subtree_condition: None,
+};
+
+/// A label doesn't own the labels it distributes, so only parent and
+/// imprint relations make up a subtree. Walks follow all relations.
+pub const LABEL: Hierarchy = Hierarchy {
+    entity: "label",
+    relation: "label_relation",
+    child: "label_id",
+    parent: "related_label_id",
+    relation_type: Some("type"),
+    subtree_condition: Some("r.type IN ('Parent', 'Imprint')"),
+};
+
+pub const CREDIT_ROLE: Hierarchy = Hierarchy {
+    entity: "credit_role",
+    relation: "credit_role_inheritance",
+    child: "role_id",
+    parent: "super_id",
+    relation_type: None,
+    subtree_condition: None,
+};
+
+#[derive(Clone, Copy)]
+pub enum Direction {
+    Ancestors,
+    Descendants,
+}
+
+#[derive(FromQueryResult)]
+struct IdRow {
+    id: i32,
+}
+
+impl Hierarchy {
+    /// Ids of all entities below `id`, not including `id` itself unless the
+    /// hierarchy already contains a cycle
+    pub async fn find_descendant_ids(
+        &self,
+        db: &impl ConnectionTrait,
+        id: i32,
+    ) -> Result<HashSet<i32>, DbErr> {
+        let stmt = Statement::from_sql_and_values(
+            db.get_database_backend(),
+            self.descendants_sql(),
+            [id.into()],
+        );
+
+        Ok(IdRow::find_by_statement(stmt)
+            .all(db)
+            .await?
+            .into_iter()
+            .map(|row| row.id)
+            .collect())
+    }
+
+    /// SQL selecting the `id` of the entities selected by `roots` and of
+    /// all of their descendants
+    pub fn subtree_sql(&self, roots: &str) -> String {
+        self.closure_sql(roots)
+    }
+
+    /// Parameters:
+    /// - `$1`: id of the entity
+    fn descendants_sql(&self) -> String {
+        let Self {
+            relation,
+            child,
+            parent,
+            ..
+        } = self;
+
+        self.closure_sql(&format!(
+            "SELECT r.{child} FROM {relation} r WHERE r.{parent} = $1{}",
+            self.and_subtree_condition()
+        ))
+    }
+
+    fn closure_sql(&self, anchor: &str) -> String {
+        let Self {
+            relation,
+            child,
+            parent,
+            ..
+        } = self;
+
+        format!(
+            r"
+            WITH RECURSIVE subtree(id) AS (
+                {anchor}
+                UNION
+                SELECT r.{child}
+                FROM {relation} r
+                JOIN subtree ON r.{parent} = subtree.id{}
+            )
+            SELECT id FROM subtree",
+            self.and_subtree_condition()
+        )
+    }
+
+    fn and_subtree_condition(&self) -> String {
+        self.subtree_condition
+            .map(|condition| format!(" AND {condition}"))
+            .unwrap_or_default()
+    }
+
+    /// Entities reached from `id` in `direction`, once per relation they are
+    /// reached through. Each row has the `id` of the entity, the
+    /// `reached_from` entity one step closer to `id`, the `relation_type`
+    /// as text if the relations have one, the lowest `depth` starting at 1,
+    /// and the given `columns` of the entity table.
+    pub async fn walk<T: FromQueryResult>(
+        &self,
+        db: &impl ConnectionTrait,
+        id: i32,
+        direction: Direction,
+        columns: &str,
+    ) -> Result<Vec<T>, DbErr> {
+        let stmt = Statement::from_sql_and_values(
+            db.get_database_backend(),
+            self.walk_sql(direction, columns),
+            [id.into(), MAX_DEPTH.into()],
+        );
+
+        T::find_by_statement(stmt).all(db).await
+    }
+
+    /// Parameters:
+    /// - `$1`: id of the root entity
+    /// - `$2`: max depth
+    ///
+    /// `path` holds the visited entities of each branch, so cycles in the
+    /// data stop the recursion instead of looping until the depth limit
+    fn walk_sql(&self, direction: Direction, columns: &str) -> String {
+        let Self {
+            entity, relation, ..
+        } = self;
+
+        // (column of the entity we come from, column of the entity we reach)
+        let (from, to) = match direction {
+            Direction::Ancestors => (self.child, self.parent),
+            Direction::Descendants => (self.parent, self.child),
+        };
+        let relation_type = self
+            .relation_type
+            .map_or_else(|| "NULL".to_owned(), |column| format!("r.{column}"));
+
+        format!(
+            r"
+            WITH RECURSIVE walk(reached_from, id, relation_type, depth, path) AS (
+                SELECT r.{from}, r.{to}, {relation_type}, 1, ARRAY[r.{from}, r.{to}]
+                FROM {relation} r
+                WHERE r.{from} = $1
+                UNION ALL
+                SELECT r.{from}, r.{to}, {relation_type}, walk.depth + 1, walk.path || r.{to}
+                FROM walk
+                JOIN {relation} r ON r.{from} = walk.id
+                WHERE r.{to} <> ALL(walk.path) AND walk.depth < $2
+            )
+            SELECT
+                {entity}.id,
+                {columns},
+                walk.reached_from,
+                walk.relation_type::text AS relation_type,
+                MIN(walk.depth) AS depth
+            FROM walk
+            JOIN {entity} ON {entity}.id = walk.id
+            GROUP BY {entity}.id, walk.reached_from, walk.relation_type
+            ORDER BY depth, {entity}.id, walk.reached_from
+            "
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::squash_sql;
+
+    #[test]
+    fn descendants_of_tag() {
+        assert_eq!(
+            squash_sql(&TAG.descendants_sql()),
+            "WITH RECURSIVE subtree(id) AS ( SELECT r.tag_id FROM tag_relation r WHERE r.related_tag_id = $1 UNION SELECT r.tag_id FROM tag_relation r JOIN subtree ON r.related_tag_id = subtree.id ) SELECT id FROM subtree"
+        );
+    }
+
+    #[test]
+    fn label_subtree_skips_distributed_labels() {
+        let descendants = squash_sql(&LABEL.descendants_sql());
+        assert!(descendants.contains(
+            "WHERE r.related_label_id = $1 AND r.type IN ('Parent', 'Imprint') UNION"
+        ));
+        assert!(descendants.contains(
+            "JOIN subtree ON r.related_label_id = subtree.id AND r.type IN ('Parent', 'Imprint') )"
+        ));
+
+        assert!(
+            squash_sql(&LABEL.subtree_sql("SELECT $1::int")).starts_with(
+                "WITH RECURSIVE subtree(id) AS ( SELECT $1::int UNION"
+            )
+        );
+    }
+
+    #[test]
+    fn walk_credit_roles() {
+        let sql = squash_sql(
+            &CREDIT_ROLE.walk_sql(Direction::Ancestors, "credit_role.name"),
+        );
+
+        assert!(sql.starts_with(
+            "WITH RECURSIVE walk(reached_from, id, relation_type, depth, path) AS ( SELECT r.role_id, r.super_id, NULL, 1, ARRAY[r.role_id, r.super_id] FROM credit_role_inheritance r WHERE r.role_id = $1 UNION ALL"
+        ));
+        assert!(sql.contains(
+            "JOIN credit_role_inheritance r ON r.role_id = walk.id WHERE r.super_id <> ALL(walk.path) AND walk.depth < $2"
+        ));
+        assert!(sql.contains(
+            "SELECT credit_role.id, credit_role.name, walk.reached_from"
+        ));
+    }
+
+    #[test]
+    fn walk_label_descendants() {
+        let sql =
+            squash_sql(&LABEL.walk_sql(Direction::Descendants, "label.name"));
+
+        assert!(sql.contains(
+            "SELECT r.related_label_id, r.label_id, r.type, 1, ARRAY[r.related_label_id, r.label_id] FROM label_relation r WHERE r.related_label_id = $1"
+        ));
+        assert!(sql.ends_with(
+            "GROUP BY label.id, walk.reached_from, walk.relation_type ORDER BY depth, label.id, walk.reached_from"
+        ));
+    }
+}
@@ -4,11 +4,13 @@ use sea_orm::{
 };
 
 use super::*;
+use crate::domain::label::validate_related_labels;
+use crate::infra::database::sea_orm::hierarchy;
 
 pub async fn apply_update(
     correction: entity::correction::Model,
     tx: &DatabaseTransaction,
-) -> Result<(), DbErr> {
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let revision = correction
         .find_related(correction_revision::Entity)
         .order_by_desc(correction_revision::Column::EntityHistoryId)
@@ -41,6 +43,8 @@ pub async fn apply_update(
     // Update related entities
     update_founders(label_id, history_id, tx).await?;
     update_localized_names(label_id, history_id, tx).await?;
+    update_links(label_id, history_id, tx).await?;
+    update_relations(label_id, history_id, tx).await?;
 
     Ok(())
 }
@@ -113,3 +117,76 @@ async fn update_localized_names(
 
     Ok(())
 }
+
+async fn update_links(
+    label_id: i32,
+    history_id: i32,
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    label_link::Entity::delete_many()
+        .filter(label_link::Column::LabelId.eq(label_id))
+        .exec(tx)
+        .await?;
+
+    let links = label_link_history::Entity::find()
+        .filter(label_link_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(label_link_history::Column::Id)
+        .all(tx)
+        .await?;
+
+    let models = links.into_iter().map(|link| label_link::ActiveModel {
+        id: NotSet,
+        label_id: Set(label_id),
+        url: Set(link.url),
+    });
+
+    label_link::Entity::insert_many(models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn update_relations(
+    label_id: i32,
+    history_id: i32,
+    tx: &DatabaseTransaction,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let relations = label_relation_history::Entity::find()
+        .filter(label_relation_history::Column::HistoryId.eq(history_id))
+        .all(tx)
+        .await?;
+
+    // The hierarchy may have changed since the correction was submitted
+    let descendant_ids =
+        hierarchy::LABEL.find_descendant_ids(tx, label_id).await?;
+    validate_related_labels(
+        label_id,
+        relations
+            .iter()
+            .map(|relation| (relation.related_label_id, relation.r#type)),
+        &descendant_ids,
+    )?;
+
+    label_relation::Entity::delete_many()
+        .filter(label_relation::Column::LabelId.eq(label_id))
+        .exec(tx)
+        .await?;
+
+    let models =
+        relations
+            .into_iter()
+            .map(|relation| label_relation::ActiveModel {
+                label_id: Set(label_id),
+                related_label_id: Set(relation.related_label_id),
+                r#type: Set(relation.r#type),
+            });
+
+    label_relation::Entity::insert_many(models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
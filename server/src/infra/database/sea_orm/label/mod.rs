@@ -1,16 +1,22 @@
+use std::collections::HashSet;
+
+use entity::enums::EntityType;
 use entity::{
-    label, label_founder, label_founder_history, label_history,
-    label_localized_name, label_localized_name_history,
+    label, label_founder, label_founder_history, label_history, label_link,
+    label_link_history, label_localized_name, label_localized_name_history,
+    label_relation, label_relation_history,
 };
 use sea_orm::ActiveValue::{NotSet, Set};
 use sea_orm::{
     ActiveModelTrait, DatabaseTransaction, DbErr, EntityTrait, IntoActiveValue,
 };
 use snafu::ResultExt;
+use url::Url;
 
 use crate::domain::Connection;
-use crate::domain::label::{NewLabel, TxRepo};
-use crate::domain::shared::NewLocalizedName;
+use crate::domain::label::{NewLabel, NewLabelRelation, TxRepo};
+use crate::domain::shared::{NewLocalizedName, normalize_links};
+use crate::infra::database::sea_orm::external_id;
 
 mod impls;
 
@@ -36,11 +42,21 @@ impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
             .boxed()
     }
 
+    async fn find_descendant_ids(
+        &self,
+        label_id: i32,
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        super::hierarchy::LABEL
+            .find_descendant_ids(self.conn(), label_id)
+            .await
+            .boxed()
+    }
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        impls::apply_update(correction, self.conn()).await.boxed()
+        impls::apply_update(correction, self.conn()).await
     }
 }
 
@@ -75,6 +91,18 @@ async fn save_label_and_link_relations(
         create_localized_names(label.id, names, tx).await?;
     }
 
+    if let Some(links) = &data.links {
+        create_links(label.id, links, tx).await?;
+    }
+
+    if let Some(ids) = &data.external_ids {
+        external_id::create(EntityType::Label, label.id, ids, tx).await?;
+    }
+
+    if let Some(relations) = &data.relations {
+        create_relations(label.id, relations, tx).await?;
+    }
+
     Ok(label)
 }
 
@@ -109,6 +137,19 @@ async fn save_label_history_and_link_relations(
         create_localized_name_histories(history.id, names, tx).await?;
     }
 
+    if let Some(links) = &data.links {
+        create_link_histories(history.id, links, tx).await?;
+    }
+
+    if let Some(ids) = &data.external_ids {
+        external_id::create_histories(EntityType::Label, history.id, ids, tx)
+            .await?;
+    }
+
+    if let Some(relations) = &data.relations {
+        create_relation_histories(history.id, relations, tx).await?;
+    }
+
     Ok(history)
 }
 
@@ -207,3 +248,89 @@ async fn create_localized_name_histories(
 
     Ok(())
 }
+
+async fn create_links(
+    label_id: i32,
+    links: &[Url],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let active_models = normalize_links(links).into_iter().map(|link| {
+        label_link::ActiveModel {
+            id: NotSet,
+            label_id: Set(label_id),
+            url: Set(link.url),
+        }
+    });
+
+    label_link::Entity::insert_many(active_models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_link_histories(
+    history_id: i32,
+    links: &[Url],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let active_models = normalize_links(links).into_iter().map(|link| {
+        label_link_history::ActiveModel {
+            id: NotSet,
+            history_id: Set(history_id),
+            url: Set(link.url),
+        }
+    });
+
+    label_link_history::Entity::insert_many(active_models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_relations(
+    label_id: i32,
+    relations: &[NewLabelRelation],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let active_models =
+        relations
+            .iter()
+            .map(|relation| label_relation::ActiveModel {
+                label_id: Set(label_id),
+                related_label_id: Set(relation.related_label_id),
+                r#type: Set(relation.r#type),
+            });
+
+    label_relation::Entity::insert_many(active_models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_relation_histories(
+    history_id: i32,
+    relations: &[NewLabelRelation],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    let active_models =
+        relations
+            .iter()
+            .map(|relation| label_relation_history::ActiveModel {
+                history_id: Set(history_id),
+                related_label_id: Set(relation.related_label_id),
+                r#type: Set(relation.r#type),
+            });
+
+    label_relation_history::Entity::insert_many(active_models)
+        .on_empty_do_nothing()
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+//! Filters on the label hierarchy
+
+use sea_query::{Expr, SimpleExpr};
+
+use super::hierarchy;
+
+/// Condition matching releases with a catalog number of `label_id`, or of
+/// one of its descendants if `include_child_labels` is set. The `release`
+/// table must be in the `FROM` clause of the query.
+pub fn released_by(label_id: i32, include_child_labels: bool) -> SimpleExpr {
+    let root = "SELECT $1::int";
+    let label_set = if include_child_labels {
+        hierarchy::LABEL.subtree_sql(root)
+    } else {
+        root.to_owned()
+    };
+
+    Expr::cust_with_values(
+        format!(
+            r#""release"."id" IN (
+                SELECT c.release_id
+                FROM release_catalog_number c
+                WHERE c.label_id IN ({label_set})
+            )"#
+        ),
+        [label_id],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use entity::release;
+    use sea_orm::{EntityTrait, QueryFilter, QuerySelect, QueryTrait};
+
+    use super::*;
+    use crate::utils::squash_sql;
+
+    fn release_sql(include_child_labels: bool) -> String {
+        release::Entity::find()
+            .select_only()
+            .column(release::Column::Id)
+            .filter(released_by(1, include_child_labels))
+            .build(sea_orm::DatabaseBackend::Postgres)
+            .to_string()
+    }
+
+    #[test]
+    fn released_by_label_or_its_subtree() {
+        let sql = squash_sql(&release_sql(true));
+
+        assert!(
+            sql.contains("WITH RECURSIVE subtree(id) AS ( SELECT 1::int UNION")
+        );
+        assert!(sql.contains(
+            "JOIN subtree ON r.related_label_id = subtree.id AND r.type IN ('Parent', 'Imprint') )"
+        ));
+
+        assert_eq!(
+            squash_sql(&release_sql(false)),
+            r#"SELECT "release"."id" FROM "release" WHERE "release"."id" IN ( SELECT c.release_id FROM release_catalog_number c WHERE c.label_id IN (SELECT 1::int) )"#
+        );
+    }
+}
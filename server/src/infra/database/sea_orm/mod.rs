@@ -11,23 +11,33 @@ use crate::domain::model::UserRoleEnum;
 use crate::domain::{Connection, Transaction, TransactionManager};
 
 mod artist;
+pub(crate) mod artist_alias;
 mod artist_image_queue;
+pub(crate) mod artist_link;
 pub(crate) mod artist_release;
 pub(crate) mod cache;
 mod correction;
 mod credit_role;
+pub(crate) mod description_search;
 pub mod enum_table;
 mod event;
+mod event_series;
 pub mod ext;
+pub(crate) mod external_id;
+pub(crate) mod hierarchy;
 mod image;
 mod image_queue;
 mod label;
+pub(crate) mod label_hierarchy;
 pub(crate) mod release;
+mod release_group;
 mod release_image;
 mod release_image_queue;
 mod song;
 mod song_lyrics;
+mod source_work;
 mod tag;
+pub(crate) mod tag_hierarchy;
 mod user;
 pub mod utils;
 
@@ -4,11 +4,11 @@ use itertools::Itertools;
 use super::RelatedEntities;
 use crate::domain;
 use crate::domain::credit_role::CreditRoleRef;
-use crate::domain::event::SimpleEvent;
+use crate::domain::event::Booth;
 use crate::domain::label::SimpleLabel;
 use crate::domain::release::{
     CatalogNumber, Release, ReleaseArtist, ReleaseCredit, ReleaseDisc,
-    ReleaseTrack,
+    ReleaseEvent, ReleaseTrack,
 };
 use crate::domain::shared::DateWithPrecision;
 use crate::domain::song::SongRef;
@@ -47,6 +47,11 @@ pub(super) fn conv_to_domain_model(
             related.localized_titles.get(index).map_or(&[], |v| v),
             related.languages,
         ),
+        external_ids: related
+            .external_ids
+            .get(&release_model.id)
+            .cloned()
+            .unwrap_or_default(),
         discs: conv_discs(related.discs.get(index).map_or(&[], |v| v)),
         tracks: conv_tracks(
             related.tracks.get(index).map_or(&[], |v| v),
@@ -59,7 +64,10 @@ pub(super) fn conv_to_domain_model(
             &related.credit_artists,
             &related.credit_roles,
         ),
-        events: conv_events(related.events.get(index).map_or(&[], |v| v)),
+        events: conv_events(
+            related.events.get(index).map_or(&[], |v| v),
+            related.event_booths.get(index).map_or(&[], |v| v),
+        ),
         cover_art_url: related
             .cover_arts
             .get(index)
@@ -218,12 +226,22 @@ fn conv_discs(discs: &[entity::release_disc::Model]) -> Vec<ReleaseDisc> {
         .collect()
 }
 
-fn conv_events(events: &[entity::event::Model]) -> Vec<SimpleEvent> {
+fn conv_events(
+    events: &[entity::event::Model],
+    booths: &[entity::release_event::Model],
+) -> Vec<ReleaseEvent> {
     events
         .iter()
-        .map(|e| SimpleEvent {
+        .map(|e| ReleaseEvent {
             id: e.id,
             name: e.name.clone(),
+            booth: booths.iter().find(|x| x.event_id == e.id).and_then(|x| {
+                Booth::from_columns(
+                    x.booth_day,
+                    x.booth_hall.clone(),
+                    x.booth_space.clone(),
+                )
+            }),
         })
         .collect()
 }
@@ -1,13 +1,15 @@
 use entity::{release_event, release_event_history};
-// placeholder if future collection utilities needed
 use sea_orm::ActiveValue::Set;
 use sea_orm::{
     ColumnTrait, DatabaseTransaction, DbErr, EntityTrait, QueryFilter,
 };
 
+use crate::domain::event::Booth;
+use crate::domain::release::NewReleaseEvent;
+
 pub(crate) async fn create_release_event(
     release_id: i32,
-    events: &[i32],
+    events: &[NewReleaseEvent],
     db: &DatabaseTransaction,
 ) -> Result<(), DbErr> {
     if events.is_empty() {
@@ -16,9 +18,16 @@ pub(crate) async fn create_release_event(
 
     let models = events
         .iter()
-        .map(|event_id| release_event::ActiveModel {
-            release_id: Set(release_id),
-            event_id: Set(*event_id),
+        .map(|event| {
+            let (booth_day, booth_hall, booth_space) =
+                event.booth.clone().into_columns();
+            release_event::ActiveModel {
+                release_id: Set(release_id),
+                event_id: Set(event.event_id),
+                booth_day: Set(booth_day),
+                booth_hall: Set(booth_hall),
+                booth_space: Set(booth_space),
+            }
         })
         .collect::<Vec<_>>();
 
@@ -29,7 +38,7 @@ pub(crate) async fn create_release_event(
 
 pub(crate) async fn create_release_event_history(
     history_id: i32,
-    events: &[i32],
+    events: &[NewReleaseEvent],
     db: &DatabaseTransaction,
 ) -> Result<(), DbErr> {
     if events.is_empty() {
@@ -38,9 +47,16 @@ pub(crate) async fn create_release_event_history(
 
     let models = events
         .iter()
-        .map(|event_id| release_event_history::ActiveModel {
-            history_id: Set(history_id),
-            event_id: Set(*event_id),
+        .map(|event| {
+            let (booth_day, booth_hall, booth_space) =
+                event.booth.clone().into_columns();
+            release_event_history::ActiveModel {
+                history_id: Set(history_id),
+                event_id: Set(event.event_id),
+                booth_day: Set(booth_day),
+                booth_hall: Set(booth_hall),
+                booth_space: Set(booth_space),
+            }
         })
         .collect::<Vec<_>>();
 
@@ -68,7 +84,15 @@ pub(crate) async fn update_release_event(
         .all(db)
         .await?
         .into_iter()
-        .map(|x| x.event_id)
+        .map(|x| NewReleaseEvent {
+            event_id: x.event_id,
+            booth: Booth::from_columns(
+                x.booth_day,
+                x.booth_hall,
+                x.booth_space,
+            )
+            .unwrap_or_default(),
+        })
         .collect::<Vec<_>>();
 
     if events.is_empty() {
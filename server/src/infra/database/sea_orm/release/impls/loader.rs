@@ -1,14 +1,18 @@
-use entity::enums::ReleaseImageType;
+use std::collections::HashMap;
+
+use entity::enums::{EntityType, ReleaseImageType};
 use entity::release;
 use itertools::Itertools;
 use sea_orm::{
     ColumnTrait, ConnectionTrait, DbErr, EntityTrait, LoaderTrait, QueryFilter,
 };
 
+use crate::domain::shared::ExternalId;
 use crate::infra::database::sea_orm::cache::{
     LANGUAGE_CACHE, LanguageCacheMap,
 };
 use crate::infra::database::sea_orm::ext::maybe_loader::MaybeLoader;
+use crate::infra::database::sea_orm::external_id;
 
 pub(super) struct RelatedEntities {
     pub(super) artists: Vec<Vec<entity::artist::Model>>,
@@ -26,7 +30,9 @@ pub(super) struct RelatedEntities {
     pub(super) credit_roles: Vec<entity::credit_role::Model>,
     pub(super) cover_arts: Vec<Option<entity::image::Model>>,
     pub(super) events: Vec<Vec<entity::event::Model>>,
+    pub(super) event_booths: Vec<Vec<entity::release_event::Model>>,
     pub(super) labels: Vec<entity::label::Model>,
+    pub(super) external_ids: HashMap<i32, Vec<ExternalId>>,
 }
 
 struct BaseEntities {
@@ -37,6 +43,7 @@ struct BaseEntities {
     tracks: Vec<Vec<entity::release_track::Model>>,
     credits: Vec<Vec<entity::release_credit::Model>>,
     events: Vec<Vec<entity::event::Model>>,
+    event_booths: Vec<Vec<entity::release_event::Model>>,
 }
 
 struct TrackDetails {
@@ -58,6 +65,7 @@ impl RelatedEntities {
             tracks,
             credits,
             events,
+            event_booths,
         } = Self::load_base_entities(releases, db).await?;
         let (credit_artists, credit_roles) =
             Self::load_credit_details(&credits, &artists, db).await?;
@@ -68,6 +76,12 @@ impl RelatedEntities {
         } = Self::load_track_details(&tracks, db).await?;
         let cover_arts = Self::load_cover_arts(releases, db).await?;
         let languages = LANGUAGE_CACHE.get_or_init(db).await?;
+        let external_ids = external_id::find_by_entity_ids(
+            EntityType::Release,
+            releases.iter().map(|release| release.id),
+            db,
+        )
+        .await?;
 
         // Load labels used by catalog numbers
         let labels = {
@@ -97,7 +111,9 @@ impl RelatedEntities {
             credit_roles,
             cover_arts,
             events,
+            event_booths,
             labels,
+            external_ids,
         })
     }
 
@@ -113,6 +129,7 @@ impl RelatedEntities {
             tracks,
             credits,
             events,
+            event_booths,
         ) = tokio::try_join!(
             releases.load_many_to_many(
                 entity::artist::Entity,
@@ -129,6 +146,7 @@ impl RelatedEntities {
                 entity::release_event::Entity,
                 db
             ),
+            releases.load_many(entity::release_event::Entity, db),
         )?;
 
         Ok(BaseEntities {
@@ -139,6 +157,7 @@ impl RelatedEntities {
             tracks,
             credits,
             events,
+            event_booths,
         })
     }
 
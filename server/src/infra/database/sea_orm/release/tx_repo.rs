@@ -1,3 +1,4 @@
+use entity::enums::EntityType;
 use entity::{release, release_history};
 use sea_orm::ActiveValue::Set;
 use sea_orm::{
@@ -7,6 +8,7 @@ use sea_orm::{
 use super::impls::*;
 use crate::domain::Connection;
 use crate::domain::release::{NewRelease, TxRepo};
+use crate::infra::database::sea_orm::external_id;
 
 impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
     async fn create(
@@ -29,6 +31,12 @@ impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
                 release.id,
                 &data.localized_titles,
                 self.conn()
+            ),
+            external_id::create(
+                EntityType::Release,
+                release.id,
+                &data.external_ids,
+                self.conn()
             )
         )?;
 
@@ -69,6 +77,12 @@ impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
                 history.id,
                 &data.localized_titles,
                 self.conn()
+            ),
+            external_id::create_histories(
+                EntityType::Release,
+                history.id,
+                &data.external_ids,
+                self.conn()
             )
         )?;
 
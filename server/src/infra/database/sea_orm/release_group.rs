@@ -0,0 +1,221 @@
+use entity::enums::EntityType;
+use entity::{
+    correction_revision, release_group, release_group_history,
+    release_group_release, release_group_release_history,
+};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    ModelTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+};
+use snafu::ResultExt;
+
+use super::{SeaOrmTxRepo, external_id};
+use crate::domain::Connection;
+use crate::domain::release_group::{NewReleaseGroup, TxRepo};
+
+impl TxRepo for SeaOrmTxRepo {
+    async fn create(
+        &self,
+        data: &NewReleaseGroup,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        create_impl(data, self.conn()).await.boxed()
+    }
+
+    async fn create_history(
+        &self,
+        data: &NewReleaseGroup,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        create_history_impl(data, self.conn()).await.boxed()
+    }
+
+    async fn apply_update(
+        &self,
+        correction: entity::correction::Model,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        apply_update_impl(correction, self.conn()).await.boxed()
+    }
+
+    async fn find_grouped_elsewhere(
+        &self,
+        group_id: Option<i32>,
+        release_ids: &[i32],
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        if release_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        release_group_release::Entity::find()
+            .select_only()
+            .column(release_group_release::Column::ReleaseId)
+            .filter(
+                release_group_release::Column::ReleaseId
+                    .is_in(release_ids.iter().copied()),
+            )
+            .apply_if(group_id, |select, id| {
+                select.filter(release_group_release::Column::GroupId.ne(id))
+            })
+            .into_tuple()
+            .all(self.conn())
+            .await
+            .boxed()
+    }
+}
+
+async fn create_impl(
+    data: &NewReleaseGroup,
+    conn: &impl ConnectionTrait,
+) -> Result<i32, DbErr> {
+    let group = release_group::ActiveModel {
+        id: NotSet,
+        title: Set(data.title.to_string()),
+        description: Set(data.description.clone().unwrap_or_default()),
+    }
+    .insert(conn)
+    .await?;
+
+    if let Some(editions) = &data.editions
+        && !editions.is_empty()
+    {
+        let models =
+            editions
+                .iter()
+                .map(|edition| release_group_release::ActiveModel {
+                    group_id: Set(group.id),
+                    release_id: Set(edition.release_id),
+                    edition_type: Set(edition.edition_type),
+                    notes: Set(edition.notes.clone().unwrap_or_default()),
+                });
+
+        release_group_release::Entity::insert_many(models)
+            .exec(conn)
+            .await?;
+    }
+
+    external_id::create(
+        EntityType::ReleaseGroup,
+        group.id,
+        data.external_ids.as_deref().unwrap_or_default(),
+        conn,
+    )
+    .await?;
+
+    Ok(group.id)
+}
+
+async fn create_history_impl(
+    data: &NewReleaseGroup,
+    conn: &impl ConnectionTrait,
+) -> Result<i32, DbErr> {
+    let history = release_group_history::ActiveModel {
+        id: NotSet,
+        title: Set(data.title.to_string()),
+        description: Set(data.description.clone().unwrap_or_default()),
+    }
+    .insert(conn)
+    .await?;
+
+    if let Some(editions) = &data.editions
+        && !editions.is_empty()
+    {
+        let models = editions.iter().map(|edition| {
+            release_group_release_history::ActiveModel {
+                history_id: Set(history.id),
+                release_id: Set(edition.release_id),
+                edition_type: Set(edition.edition_type),
+                notes: Set(edition.notes.clone().unwrap_or_default()),
+            }
+        });
+
+        release_group_release_history::Entity::insert_many(models)
+            .exec(conn)
+            .await?;
+    }
+
+    external_id::create_histories(
+        EntityType::ReleaseGroup,
+        history.id,
+        data.external_ids.as_deref().unwrap_or_default(),
+        conn,
+    )
+    .await?;
+
+    Ok(history.id)
+}
+
+async fn apply_update_impl(
+    correction: entity::correction::Model,
+    conn: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    let revision = correction
+        .find_related(correction_revision::Entity)
+        .order_by_desc(correction_revision::Column::EntityHistoryId)
+        .one(conn)
+        .await?
+        .ok_or_else(|| {
+            DbErr::Custom("Correction revision not found".to_string())
+        })?;
+
+    let history =
+        release_group_history::Entity::find_by_id(revision.entity_history_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| {
+                DbErr::Custom("Release group history not found".to_string())
+            })?;
+
+    release_group::ActiveModel {
+        id: Set(correction.entity_id),
+        title: Set(history.title),
+        description: Set(history.description),
+    }
+    .update(conn)
+    .await?;
+
+    update_editions(correction.entity_id, history.id, conn).await
+}
+
+async fn update_editions(
+    group_id: i32,
+    history_id: i32,
+    conn: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    release_group_release::Entity::delete_many()
+        .filter(release_group_release::Column::GroupId.eq(group_id))
+        .exec(conn)
+        .await?;
+
+    let editions = release_group_release_history::Entity::find()
+        .filter(release_group_release_history::Column::HistoryId.eq(history_id))
+        .all(conn)
+        .await?;
+
+    if editions.is_empty() {
+        return Ok(());
+    }
+
+    // A release may have joined another group since the correction was
+    // submitted, in which case it is moved to this one
+    release_group_release::Entity::delete_many()
+        .filter(
+            release_group_release::Column::ReleaseId
+                .is_in(editions.iter().map(|edition| edition.release_id)),
+        )
+        .exec(conn)
+        .await?;
+
+    let models = editions.into_iter().map(|edition| {
+        release_group_release::ActiveModel {
+            group_id: Set(group_id),
+            release_id: Set(edition.release_id),
+            edition_type: Set(edition.edition_type),
+            notes: Set(edition.notes),
+        }
+    });
+
+    release_group_release::Entity::insert_many(models)
+        .exec(conn)
+        .await?;
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use entity::{
     correction_revision, song, song_artist, song_artist_history, song_credit,
     song_credit_history, song_history, song_language, song_language_history,
-    song_localized_title, song_localized_title_history,
+    song_localized_title, song_localized_title_history, song_source_track,
+    song_source_track_history,
 };
+use itertools::Itertools;
 use sea_orm::ActiveValue::{NotSet, Set};
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbErr, EntityTrait,
@@ -43,6 +45,7 @@ pub async fn apply_update(
     update_credits(song_id, history_id, tx).await?;
     update_languages(song_id, history_id, tx).await?;
     update_localized_titles(song_id, history_id, tx).await?;
+    update_source_tracks(song_id, history_id, tx).await?;
 
     Ok(())
 }
@@ -186,3 +189,42 @@ async fn update_localized_titles(
 
     Ok(())
 }
+
+async fn update_source_tracks(
+    song_id: i32,
+    history_id: i32,
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    // First delete existing source tracks
+    song_source_track::Entity::delete_many()
+        .filter(song_source_track::Column::SongId.eq(song_id))
+        .exec(tx)
+        .await?;
+
+    // Get history source track records
+    let source_tracks = song_source_track_history::Entity::find()
+        .filter(song_source_track_history::Column::HistoryId.eq(history_id))
+        .all(tx)
+        .await?;
+
+    // Tracks removed from their source work since the revision are dropped
+    let models = source_tracks
+        .iter()
+        .filter_map(|source_track| source_track.source_track_id)
+        .map(|source_track_id| song_source_track::ActiveModel {
+            song_id: Set(song_id),
+            source_track_id: Set(source_track_id),
+        })
+        .collect_vec();
+
+    if models.is_empty() {
+        return Ok(());
+    }
+
+    // Insert new models
+    song_source_track::Entity::insert_many(models)
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
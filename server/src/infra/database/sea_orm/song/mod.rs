@@ -1,18 +1,25 @@
+use std::collections::HashSet;
+
+use entity::enums::EntityType;
 use entity::{
     song, song_artist, song_artist_history, song_credit, song_credit_history,
     song_history, song_language, song_language_history, song_localized_title,
-    song_localized_title_history,
+    song_localized_title_history, song_source_track, song_source_track_history,
+    source_work_track,
 };
 use impls::apply_update;
+use itertools::Itertools;
 use sea_orm::ActiveValue::{NotSet, Set};
 use sea_orm::{
-    ActiveModelTrait, DatabaseTransaction, DbErr, EntityTrait, IntoActiveValue,
+    ActiveModelTrait, ColumnTrait, DatabaseTransaction, DbErr, EntityTrait,
+    IntoActiveValue, QueryFilter, QueryOrder, QuerySelect,
 };
 use snafu::ResultExt;
 
 use crate::domain::Connection;
 use crate::domain::shared::NewLocalizedName;
 use crate::domain::song::{NewSong, NewSongCredit, TxRepo};
+use crate::infra::database::sea_orm::external_id;
 
 mod impls;
 
@@ -36,6 +43,25 @@ impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
             .boxed()
     }
 
+    async fn find_existing_source_tracks(
+        &self,
+        ids: &[i32],
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        if ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        Ok(source_work_track::Entity::find()
+            .select_only()
+            .column(source_work_track::Column::Id)
+            .filter(source_work_track::Column::Id.is_in(ids.iter().copied()))
+            .into_tuple::<i32>()
+            .all(self.conn())
+            .await?
+            .into_iter()
+            .collect())
+    }
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
@@ -72,6 +98,14 @@ async fn create_song_and_relations(
         create_localized_titles(song.id, localized_titles, tx).await?;
     }
 
+    if let Some(ids) = &data.external_ids {
+        external_id::create(EntityType::Song, song.id, ids, tx).await?;
+    }
+
+    if let Some(source_tracks) = &data.source_tracks {
+        create_source_tracks(song.id, source_tracks, tx).await?;
+    }
+
     Ok(song)
 }
 
@@ -104,6 +138,15 @@ async fn create_song_history_and_relations(
             .await?;
     }
 
+    if let Some(ids) = &data.external_ids {
+        external_id::create_histories(EntityType::Song, history.id, ids, tx)
+            .await?;
+    }
+
+    if let Some(source_tracks) = &data.source_tracks {
+        create_source_track_histories(history.id, source_tracks, tx).await?;
+    }
+
     Ok(history)
 }
 
@@ -291,3 +334,66 @@ async fn create_localized_title_histories(
 
     Ok(())
 }
+
+async fn create_source_tracks(
+    song_id: i32,
+    source_tracks: &[i32],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    if source_tracks.is_empty() {
+        return Ok(());
+    }
+
+    let models = source_tracks.iter().unique().map(|source_track_id| {
+        song_source_track::ActiveModel {
+            song_id: song_id.into_active_value(),
+            source_track_id: (*source_track_id).into_active_value(),
+        }
+    });
+
+    song_source_track::Entity::insert_many(models)
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
+
+async fn create_source_track_histories(
+    history_id: i32,
+    source_tracks: &[i32],
+    tx: &DatabaseTransaction,
+) -> Result<(), DbErr> {
+    if source_tracks.is_empty() {
+        return Ok(());
+    }
+
+    // Titles are kept so that the revision still reads the same after the
+    // track is removed from its source work
+    let tracks = source_work_track::Entity::find()
+        .filter(
+            source_work_track::Column::Id
+                .is_in(source_tracks.iter().unique().copied()),
+        )
+        .order_by_asc(source_work_track::Column::Id)
+        .all(tx)
+        .await?;
+
+    if tracks.is_empty() {
+        return Ok(());
+    }
+
+    let models = tracks.into_iter().map(|track| {
+        song_source_track_history::ActiveModel {
+            id: NotSet,
+            history_id: Set(history_id),
+            source_track_id: Set(Some(track.id)),
+            title: Set(track.title),
+        }
+    });
+
+    song_source_track_history::Entity::insert_many(models)
+        .exec(tx)
+        .await?;
+
+    Ok(())
+}
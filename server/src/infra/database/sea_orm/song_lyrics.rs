@@ -1,3 +1,4 @@
+use entity::enums::EntityType;
 use entity::{correction_revision, song_lyrics, song_lyrics_history};
 use sea_orm::ActiveValue::{NotSet, Set};
 use sea_orm::{
@@ -6,7 +7,7 @@ use sea_orm::{
 };
 use snafu::ResultExt;
 
-use super::SeaOrmTxRepo;
+use super::{SeaOrmTxRepo, external_id};
 use crate::domain::Connection;
 use crate::domain::song_lyrics::{NewSongLyrics, TxRepo};
 
@@ -69,6 +70,15 @@ async fn create_lyrics_impl(
     };
 
     let result = model.insert(conn).await?;
+
+    external_id::create(
+        EntityType::SongLyrics,
+        result.id,
+        lyrics.external_ids.as_deref().unwrap_or_default(),
+        conn,
+    )
+    .await?;
+
     Ok(result.id)
 }
 
@@ -86,6 +96,15 @@ async fn create_history_impl(
     };
 
     let result = model.insert(conn).await?;
+
+    external_id::create_histories(
+        EntityType::SongLyrics,
+        result.id,
+        lyrics.external_ids.as_deref().unwrap_or_default(),
+        conn,
+    )
+    .await?;
+
     Ok(result.id)
 }
 
@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+
+use entity::enums::EntityType;
+use entity::{
+    correction_revision, source_work, source_work_history, source_work_track,
+    source_work_track_history,
+};
+use sea_orm::ActiveValue::{NotSet, Set};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    IntoActiveValue, ModelTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use snafu::ResultExt;
+
+use super::{SeaOrmTxRepo, external_id};
+use crate::domain::Connection;
+use crate::domain::shared::{DatePrecision, DateWithPrecision};
+use crate::domain::source_work::{NewSourceWork, NewSourceWorkTrack, TxRepo};
+
+impl TxRepo for SeaOrmTxRepo {
+    async fn create(
+        &self,
+        data: &NewSourceWork,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        create_impl(data, self.conn()).await.boxed()
+    }
+
+    async fn create_history(
+        &self,
+        data: &NewSourceWork,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        create_history_impl(data, self.conn()).await.boxed()
+    }
+
+    async fn apply_update(
+        &self,
+        correction: entity::correction::Model,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        apply_update_impl(correction, self.conn()).await.boxed()
+    }
+
+    async fn find_track_ids(
+        &self,
+        id: i32,
+    ) -> Result<Vec<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        source_work_track::Entity::find()
+            .select_only()
+            .column(source_work_track::Column::Id)
+            .filter(source_work_track::Column::SourceWorkId.eq(id))
+            .into_tuple()
+            .all(self.conn())
+            .await
+            .boxed()
+    }
+}
+
+async fn create_impl(
+    data: &NewSourceWork,
+    conn: &impl ConnectionTrait,
+) -> Result<i32, DbErr> {
+    let (release_date, release_date_precision) =
+        data.release_date.map(DateWithPrecision::destruct).unzip();
+
+    let model = source_work::ActiveModel {
+        id: NotSet,
+        title: Set(data.title.to_string()),
+        kind: Set(data.kind),
+        release_date: Set(release_date),
+        release_date_precision: Set(
+            release_date_precision.unwrap_or(DatePrecision::Day)
+        ),
+        description: Set(data.description.clone().unwrap_or_default()),
+    };
+
+    let source_work = model.insert(conn).await?;
+
+    if let Some(tracks) = &data.tracks
+        && !tracks.is_empty()
+    {
+        let models =
+            tracks.iter().map(|track| source_work_track::ActiveModel {
+                id: NotSet,
+                source_work_id: Set(source_work.id),
+                track_number: track.track_number.clone().into_active_value(),
+                title: Set(track.title.clone()),
+            });
+
+        source_work_track::Entity::insert_many(models)
+            .exec(conn)
+            .await?;
+    }
+
+    external_id::create(
+        EntityType::SourceWork,
+        source_work.id,
+        data.external_ids.as_deref().unwrap_or_default(),
+        conn,
+    )
+    .await?;
+
+    Ok(source_work.id)
+}
+
+async fn create_history_impl(
+    data: &NewSourceWork,
+    conn: &impl ConnectionTrait,
+) -> Result<i32, DbErr> {
+    let (release_date, release_date_precision) =
+        data.release_date.map(DateWithPrecision::destruct).unzip();
+
+    let model = source_work_history::ActiveModel {
+        id: NotSet,
+        title: Set(data.title.to_string()),
+        kind: Set(data.kind),
+        release_date: Set(release_date),
+        release_date_precision: Set(
+            release_date_precision.unwrap_or(DatePrecision::Day)
+        ),
+        description: Set(data.description.clone().unwrap_or_default()),
+    };
+
+    let history = model.insert(conn).await?;
+
+    if let Some(tracks) = &data.tracks
+        && !tracks.is_empty()
+    {
+        let models = tracks.iter().map(|track: &NewSourceWorkTrack| {
+            source_work_track_history::ActiveModel {
+                id: NotSet,
+                history_id: Set(history.id),
+                track_id: Set(track.id),
+                track_number: track.track_number.clone().into_active_value(),
+                title: Set(track.title.clone()),
+            }
+        });
+
+        source_work_track_history::Entity::insert_many(models)
+            .exec(conn)
+            .await?;
+    }
+
+    external_id::create_histories(
+        EntityType::SourceWork,
+        history.id,
+        data.external_ids.as_deref().unwrap_or_default(),
+        conn,
+    )
+    .await?;
+
+    Ok(history.id)
+}
+
+async fn apply_update_impl(
+    correction: entity::correction::Model,
+    conn: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    let revision = correction
+        .find_related(correction_revision::Entity)
+        .order_by_desc(correction_revision::Column::EntityHistoryId)
+        .one(conn)
+        .await?
+        .ok_or_else(|| {
+            DbErr::Custom("Correction revision not found".to_string())
+        })?;
+
+    let history =
+        source_work_history::Entity::find_by_id(revision.entity_history_id)
+            .one(conn)
+            .await?
+            .ok_or_else(|| {
+                DbErr::Custom("Source work history not found".to_string())
+            })?;
+
+    source_work::ActiveModel {
+        id: Set(correction.entity_id),
+        title: Set(history.title),
+        kind: Set(history.kind),
+        release_date: Set(history.release_date),
+        release_date_precision: Set(history.release_date_precision),
+        description: Set(history.description),
+    }
+    .update(conn)
+    .await?;
+
+    update_tracks(correction.entity_id, history.id, conn).await
+}
+
+/// Tracks are updated in place rather than recreated, since songs link to
+/// them by id
+async fn update_tracks(
+    source_work_id: i32,
+    history_id: i32,
+    conn: &impl ConnectionTrait,
+) -> Result<(), DbErr> {
+    let tracks = source_work_track_history::Entity::find()
+        .filter(source_work_track_history::Column::HistoryId.eq(history_id))
+        .order_by_asc(source_work_track_history::Column::Id)
+        .all(conn)
+        .await?;
+
+    let kept_ids: HashSet<i32> =
+        tracks.iter().filter_map(|track| track.track_id).collect();
+
+    // Tracks removed by the correction, along with their song links
+    source_work_track::Entity::delete_many()
+        .filter(source_work_track::Column::SourceWorkId.eq(source_work_id))
+        .filter(source_work_track::Column::Id.is_not_in(kept_ids))
+        .exec(conn)
+        .await?;
+
+    for track in tracks {
+        let model = source_work_track::ActiveModel {
+            id: track.track_id.map_or(NotSet, Set),
+            source_work_id: Set(source_work_id),
+            track_number: Set(track.track_number),
+            title: Set(track.title),
+        };
+
+        if track.track_id.is_some() {
+            model.update(conn).await?;
+        } else {
+            model.insert(conn).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -110,6 +110,19 @@ async fn update_relations(
         return Ok(());
     }
 
+    // The hierarchy may have changed since the correction was submitted
+    let descendant_ids = super::super::hierarchy::TAG
+        .find_descendant_ids(tx, tag_id)
+        .await?;
+    if relations.iter().any(|relation| {
+        relation.related_tag_id == tag_id
+            || descendant_ids.contains(&relation.related_tag_id)
+    }) {
+        return Err(DbErr::Custom(
+            "Tag relations would form a cycle".to_string(),
+        ));
+    }
+
     // Create new models from history
     let models = relations.iter().map(|relation| tag_relation::ActiveModel {
         tag_id: Set(tag_id),
@@ -1,3 +1,6 @@
+use std::collections::HashSet;
+
+use entity::enums::EntityType;
 use entity::{
     tag, tag_alternative_name, tag_alternative_name_history, tag_history,
     tag_relation, tag_relation_history,
@@ -10,6 +13,7 @@ use snafu::ResultExt;
 
 use crate::domain::Connection;
 use crate::domain::tag::{NewTag, NewTagRelation, TxRepo};
+use crate::infra::database::sea_orm::external_id;
 
 mod impls;
 use impls::*;
@@ -34,6 +38,16 @@ impl TxRepo for crate::infra::database::sea_orm::SeaOrmTxRepo {
             .boxed()
     }
 
+    async fn find_descendant_ids(
+        &self,
+        tag_id: i32,
+    ) -> Result<HashSet<i32>, Box<dyn std::error::Error + Send + Sync>> {
+        super::hierarchy::TAG
+            .find_descendant_ids(self.conn(), tag_id)
+            .await
+            .boxed()
+    }
+
     async fn apply_update(
         &self,
         correction: entity::correction::Model,
@@ -68,6 +82,10 @@ async fn create_tag_impl(
         create_alt_name(tag.id, alt_names, tx).await?;
     }
 
+    if let Some(ids) = &data.external_ids {
+        external_id::create(EntityType::Tag, tag.id, ids, tx).await?;
+    }
+
     if let Some(relations) = &data.relations {
         create_relation(tag.id, relations, tx).await?;
     }
@@ -101,6 +119,11 @@ async fn create_history_impl(
         create_alt_name_history(history.id, alt_names, tx).await?;
     }
 
+    if let Some(ids) = &data.external_ids {
+        external_id::create_histories(EntityType::Tag, history.id, ids, tx)
+            .await?;
+    }
+
     if let Some(relations) = &data.relations {
         create_relation_history(history.id, relations, tx).await?;
     }
@@ -0,0 +1,43 @@
+//! Filters on the tag hierarchy
+
+use itertools::Itertools;
+use sea_query::{Expr, SimpleExpr};
+
+use super::hierarchy;
+
+/// Condition matching entities with at least one positive vote on any of
+/// `tag_ids`, or on one of their subtags if `include_subtags` is set
+///
+/// - `entity_table`: table of the filtered entity, which must be in the
+///   `FROM` clause of the query
+/// - `vote_table` and `vote_entity_id_column`: the tag vote table of the
+///   entity and its column referencing the entity
+pub fn tagged_with(
+    entity_table: &str,
+    vote_table: &str,
+    vote_entity_id_column: &str,
+    tag_ids: &[i32],
+    include_subtags: bool,
+) -> SimpleExpr {
+    if tag_ids.is_empty() {
+        return Expr::cust("TRUE");
+    }
+
+    let roots =
+        format!("SELECT unnest(ARRAY[{}]::int[])", tag_ids.iter().join(","));
+    let tag_set = if include_subtags {
+        hierarchy::TAG.subtree_sql(&roots)
+    } else {
+        roots
+    };
+
+    Expr::cust(format!(
+        r"{entity_table}.id IN (
+            SELECT v.{vote_entity_id_column}
+            FROM {vote_table} v
+            WHERE v.tag_id IN ({tag_set})
+            GROUP BY v.{vote_entity_id_column}, v.tag_id
+            HAVING COUNT(*) FILTER (WHERE v.score > 0) > 0
+        )"
+    ))
+}
@@ -3,6 +3,7 @@ use std::future::Future;
 use std::{env, str};
 
 use entity::{user, user_role};
+use rand::Rng;
 use sea_orm::ActiveValue::*;
 use sea_orm::prelude::Expr;
 use sea_orm::sea_query::OnConflict;
@@ -12,7 +13,7 @@ use sea_orm::{
     TransactionTrait,
 };
 
-use crate::constant::ADMIN_USERNAME;
+use crate::constant::{ADMIN_USERNAME, IMPORT_BOT_USERNAME};
 use crate::domain::auth::hash_password;
 use crate::domain::model::UserRoleEnum;
 use crate::domain::shared::Paginated;
@@ -125,6 +126,49 @@ pub async fn upsert_admin_acc(db: &DatabaseConnection) {
     .expect("Failed to upsert admin account");
 }
 
+/// The bot only authors corrections, its password is random and never
+/// stored in plain text, so nobody can log in as it
+///
+/// Its name can't be signed up with, and unlike accounts that signed up it
+/// has no role. An account of that name with roles predates the
+/// reservation, and imports would be credited to its owner.
+pub async fn upsert_import_bot_acc(db: &DatabaseConnection) {
+    let existing = user::Entity::find()
+        .filter(user::Column::Name.eq(IMPORT_BOT_USERNAME))
+        .find_with_related(user_role::Entity)
+        .all(db)
+        .await
+        .expect("Failed to find import bot account");
+
+    if let Some((user, roles)) = existing.first() {
+        assert!(
+            roles.is_empty(),
+            "User {} is not the import bot, rename it and restart",
+            user.id
+        );
+        return;
+    }
+
+    let password = rand::rng()
+        .sample_iter(rand::distr::Alphanumeric)
+        .take(64)
+        .map(char::from)
+        .collect::<String>();
+
+    user::Entity::insert(user::ActiveModel {
+        id: NotSet,
+        name: Set(IMPORT_BOT_USERNAME.to_string()),
+        password: Set(hash_password(&password).unwrap()),
+        avatar_id: Set(None),
+        profile_banner_id: Set(None),
+        last_login: Set(chrono::Local::now().into()),
+        bio: Set(None),
+    })
+    .exec_without_returning(db)
+    .await
+    .expect("Failed to create import bot account");
+}
+
 pub fn sort_by_id_list<T>(
     mut items: Vec<T>,
     id_order: &[i32],
@@ -146,6 +190,22 @@ pub fn sort_by_id_list<T>(
     items
 }
 
+/// `items` must hold up to `limit + 1` rows starting at `offset`
+pub fn paginate_by_offset<T>(
+    mut items: Vec<T>,
+    offset: i32,
+    limit: u32,
+) -> Paginated<T> {
+    let next_cursor = if items.len() > limit as usize {
+        items.truncate(limit as usize);
+        Some(offset.max(0).saturating_add_unsigned(limit))
+    } else {
+        None
+    };
+
+    Paginated { items, next_cursor }
+}
+
 pub fn paginate_by_id<T>(
     items: Vec<T>,
     pagination: &PaginationQuery,
@@ -4,7 +4,7 @@ use std::sync::LazyLock;
 use argon2::Argon2;
 
 use super::config::Config;
-use crate::constant::{IMAGE_DIR, PUBLIC_DIR};
+use crate::constant::{DUMP_DIR, DUMP_STAGING_DIR, IMAGE_DIR, PUBLIC_DIR};
 
 pub static APP_CONFIG: LazyLock<Config> = LazyLock::new(Config::init);
 
@@ -12,3 +12,9 @@ pub static ARGON2_HASHER: LazyLock<Argon2> = LazyLock::new(Argon2::default);
 
 pub static FS_IMAGE_BASE_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| PathBuf::from_iter([PUBLIC_DIR, IMAGE_DIR]));
+
+pub static FS_DUMP_BASE_PATH: LazyLock<PathBuf> =
+    LazyLock::new(|| PathBuf::from_iter([PUBLIC_DIR, DUMP_DIR]));
+
+pub static FS_DUMP_STAGING_PATH: LazyLock<PathBuf> =
+    LazyLock::new(|| PathBuf::from_iter([PUBLIC_DIR, DUMP_STAGING_DIR]));
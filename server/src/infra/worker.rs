@@ -1,20 +1,63 @@
 use std::time::Duration;
 
 use fred::prelude::{Client, ClientLike, ListInterface, Options};
+use sea_orm::DatabaseConnection;
+use tokio::time::{Instant, MissedTickBehavior};
 
+use super::config::Dump;
+use super::singleton::{FS_DUMP_BASE_PATH, FS_DUMP_STAGING_PATH};
 use super::storage::file::REMOVE_FILE_FAIELD_KEY;
+use crate::feature::dump;
 use crate::utils::retry_async;
 
 pub struct Worker {
     pub redis_pool: fred::prelude::Pool,
+    pub database: DatabaseConnection,
+    pub dump: Dump,
 }
 
 impl Worker {
     pub fn init(self) {
         init_remove_file(self.redis_pool);
+        init_database_dump(self.database, self.dump);
     }
 }
 
+/// The first dump is taken one interval after startup, dumps can be taken
+/// on demand with the `dump` command
+fn init_database_dump(database: DatabaseConnection, config: Dump) {
+    if config.interval_hours == 0 {
+        return;
+    }
+
+    let period = Duration::from_secs(config.interval_hours * 60 * 60);
+
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval_at(Instant::now() + period, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        tracing::info!("Database dump worker started");
+
+        loop {
+            interval.tick().await;
+
+            match dump::export(
+                &database,
+                &FS_DUMP_BASE_PATH,
+                &FS_DUMP_STAGING_PATH,
+                config.keep,
+            )
+            .await
+            {
+                Ok(manifest) => {
+                    tracing::info!("Database dump written: {}", manifest.name);
+                }
+                Err(e) => tracing::error!("Database dump failed: {}", e),
+            }
+        }
+    });
+}
+
 fn init_remove_file(redis_pool: fred::prelude::Pool) {
     let client = Client::clone_new(redis_pool.next()).with_options(&Options {
         timeout: Duration::from_secs(0).into(),
@@ -42,8 +42,9 @@ mod utils;
 
 use std::sync::Arc;
 
+use infra::database::get_connection;
 use infra::logger::Logger;
-use infra::singleton::APP_CONFIG;
+use infra::singleton::{APP_CONFIG, FS_DUMP_BASE_PATH, FS_DUMP_STAGING_PATH};
 use infra::state::AppState;
 use snafu::{ResultExt, Whatever};
 
@@ -63,12 +64,20 @@ async fn main() -> Result<(), Whatever> {
     let _ = dotenvy::dotenv();
     Logger::init();
 
+    match std::env::args().nth(1).as_deref() {
+        Some("canonicalize-links") => return canonicalize_links().await,
+        Some("dump") => return dump().await,
+        _ => {}
+    }
+
     tracing::info!("Starting server");
 
     let state = AppState::init(&APP_CONFIG).await;
 
     Worker {
         redis_pool: state.redis_pool(),
+        database: state.database.clone(),
+        dump: APP_CONFIG.dump,
     }
     .init();
 
@@ -90,3 +99,39 @@ async fn main() -> Result<(), Whatever> {
 
     Ok(())
 }
+
+/// `thcdb_rs canonicalize-links` rewrites the artist links stored before
+/// they were canonicalized and exits
+async fn canonicalize_links() -> Result<(), Whatever> {
+    let conn = get_connection(&APP_CONFIG.database_url).await;
+
+    let count =
+        infra::database::sea_orm::artist_link::canonicalize_stored(&conn)
+            .await
+            .whatever_context("Failed to canonicalize artist links")?;
+
+    tracing::info!("{count} artist links rewritten or removed");
+
+    Ok(())
+}
+
+/// `thcdb_rs dump` writes a database dump and exits
+async fn dump() -> Result<(), Whatever> {
+    let conn = get_connection(&APP_CONFIG.database_url).await;
+
+    let manifest = feature::dump::export(
+        &conn,
+        &FS_DUMP_BASE_PATH,
+        &FS_DUMP_STAGING_PATH,
+        APP_CONFIG.dump.keep,
+    )
+    .await
+    .whatever_context("Failed to write database dump")?;
+
+    tracing::info!(
+        "Database dump written to {}",
+        FS_DUMP_BASE_PATH.join(&manifest.name).display()
+    );
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+pub mod natural_sort;
 pub mod openapi;
 pub mod validation;
 
@@ -28,3 +29,10 @@ where
         }
     }
 }
+
+/// Collapse the whitespace of a SQL template, so that tests can match its
+/// clauses whatever the indentation
+#[cfg(test)]
+pub fn squash_sql(sql: &str) -> String {
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}